@@ -13,12 +13,20 @@ use agglayer_prover_types::{
     },
 };
 use agglayer_storage::stores::{PendingCertificateReader, PendingCertificateWriter};
-use agglayer_types::{Height, LocalNetworkStateData, NetworkId, Proof};
+use agglayer_types::{
+    Certificate, Height, Keccak256Hasher, LocalNetworkStateData, NetworkId, Proof,
+};
 use bincode::Options as _;
 use futures::future::BoxFuture;
-use pessimistic_proof::{generate_pessimistic_proof, LocalNetworkState};
+use pessimistic_proof::{
+    generate_pessimistic_proof, multi_batch_header::MultiBatchHeader, LocalNetworkState,
+    PessimisticProofOutput,
+};
 use reth_primitives::Address;
-use sp1_sdk::{CpuProver, Prover, SP1ProofWithPublicValues, SP1VerificationError, SP1VerifyingKey};
+use sp1_sdk::{
+    CpuProver, HashableKey as _, Prover, SP1ProofWithPublicValues, SP1VerificationError,
+    SP1VerifyingKey,
+};
 use tonic::{codec::CompressionEncoding, transport::Channel};
 use tracing::{debug, error, info, warn};
 
@@ -65,6 +73,12 @@ impl<PendingStore, L1Rpc> CertifierClient<PendingStore, L1Rpc> {
         })
     }
 
+    /// Verification key of the pessimistic proof program, in the format
+    /// expected by the L1 verifier.
+    pub fn vkey(&self) -> String {
+        self.verifying_key.bytes32()
+    }
+
     fn verify_proof(
         verifier: Arc<CpuProver>,
         verifying_key: &SP1VerifyingKey,
@@ -125,49 +139,11 @@ where
         let verifier = self.verifier.clone();
         let verifying_key = self.verifying_key.clone();
         let l1_rpc = self.l1_rpc.clone();
-        let proof_signers = self.config.proof_signers.clone();
+        let config = self.config.clone();
 
         Ok(Box::pin(async move {
-            let signer = l1_rpc
-                .get_trusted_sequencer_address(*network_id, proof_signers)
-                .await
-                .map_err(|_| CertificationError::TrustedSequencerNotFound(network_id))?;
-
-            let l1_info_leaf_count = certificate.l1_info_tree_leaf_count();
-
-            let l1_info_root = l1_rpc
-                .get_l1_info_root(l1_info_leaf_count)
-                .await
-                .map_err(|_| {
-                    CertificationError::L1InfoRootNotFound(certificate_id, l1_info_leaf_count)
-                })?;
-
-            let declared_l1_info_root = certificate
-                .l1_info_root()
-                .map_err(|source| CertificationError::Types { source })?;
-
-            if let Some(declared) = declared_l1_info_root {
-                if declared != l1_info_root {
-                    return Err(CertificationError::Types {
-                        source: agglayer_types::Error::L1InfoRootIncorrect {
-                            declared: declared.into(),
-                            retrieved: l1_info_root.into(),
-                            leaf_count: l1_info_leaf_count,
-                        },
-                    });
-                }
-            }
-
-            let initial_state = LocalNetworkState::from(state.clone());
-
-            let signer = Address::new(*signer.as_fixed_bytes());
-            let multi_batch_header = state
-                .apply_certificate(&certificate, signer, l1_info_root)
-                .map_err(|source| CertificationError::Types { source })?;
-
-            // Perform the native PP execution
-            let _ = generate_pessimistic_proof(initial_state.clone(), &multi_batch_header)
-                .map_err(|source| CertificationError::NativeExecutionFailed { source })?;
+            let (initial_state, multi_batch_header, _) =
+                execute_pessimistic_proof(l1_rpc, &config, &mut state, &certificate).await?;
 
             info!(
                 "Successfully executed the native PP for the Certificate {}",
@@ -252,4 +228,115 @@ where
             }
         }))
     }
+    fn execute_natively(
+        &self,
+        mut state: LocalNetworkStateData,
+        certificate: Certificate,
+    ) -> BoxFuture<'static, Result<LocalNetworkStateData, CertificationError>> {
+        debug!(
+            "Re-executing natively the certificate {} of network {} at height {}",
+            certificate.hash(),
+            certificate.network_id,
+            certificate.height
+        );
+
+        let l1_rpc = self.l1_rpc.clone();
+        let config = self.config.clone();
+
+        Box::pin(async move {
+            apply_certificate(l1_rpc, &config, &mut state, &certificate).await?;
+
+            // Prune the SMTs of the state
+            state
+                .prune_stale_nodes()
+                .map_err(|e| CertificationError::InternalError(e.to_string()))?;
+
+            Ok(state)
+        })
+    }
+}
+
+/// Apply a Certificate on top of a local state and execute natively the
+/// pessimistic proof program for it.
+///
+/// The execution runs on a blocking thread so that it doesn't stall the async
+/// executor. Returns the initial state and the batch header given to the
+/// program, along with its output.
+pub async fn execute_pessimistic_proof<L1Rpc>(
+    l1_rpc: Arc<L1Rpc>,
+    config: &Config,
+    state: &mut LocalNetworkStateData,
+    certificate: &Certificate,
+) -> Result<
+    (
+        LocalNetworkState,
+        MultiBatchHeader<Keccak256Hasher>,
+        PessimisticProofOutput,
+    ),
+    CertificationError,
+>
+where
+    L1Rpc: RollupContract,
+{
+    let initial_state = LocalNetworkState::from(state.clone());
+
+    let multi_batch_header = apply_certificate(l1_rpc, config, state, certificate).await?;
+
+    tokio::task::spawn_blocking(move || {
+        generate_pessimistic_proof(initial_state.clone(), &multi_batch_header)
+            .map(|output| (initial_state, multi_batch_header, output))
+    })
+    .await
+    .map_err(|error| {
+        CertificationError::InternalError(format!("Native execution task failed: {error}"))
+    })?
+    .map_err(|source| CertificationError::NativeExecutionFailed { source })
+}
+
+/// Apply a Certificate on top of a local state, using the trusted sequencer
+/// and the L1 info root fetched from the L1.
+async fn apply_certificate<L1Rpc>(
+    l1_rpc: Arc<L1Rpc>,
+    config: &Config,
+    state: &mut LocalNetworkStateData,
+    certificate: &Certificate,
+) -> Result<MultiBatchHeader<Keccak256Hasher>, CertificationError>
+where
+    L1Rpc: RollupContract,
+{
+    let network_id = certificate.network_id;
+    let certificate_id = certificate.hash();
+
+    let signer = l1_rpc
+        .get_trusted_sequencer_address(*network_id, config.proof_signers.clone())
+        .await
+        .map_err(|_| CertificationError::TrustedSequencerNotFound(network_id))?;
+
+    let l1_info_leaf_count = certificate.l1_info_tree_leaf_count();
+
+    let l1_info_root = l1_rpc
+        .get_l1_info_root(l1_info_leaf_count)
+        .await
+        .map_err(|_| CertificationError::L1InfoRootNotFound(certificate_id, l1_info_leaf_count))?;
+
+    let declared_l1_info_root = certificate
+        .l1_info_root()
+        .map_err(|source| CertificationError::Types { source })?;
+
+    if let Some(declared) = declared_l1_info_root {
+        if declared != l1_info_root {
+            return Err(CertificationError::Types {
+                source: agglayer_types::Error::L1InfoRootIncorrect {
+                    declared: declared.into(),
+                    retrieved: l1_info_root.into(),
+                    leaf_count: l1_info_leaf_count,
+                },
+            });
+        }
+    }
+
+    let signer = Address::new(*signer.as_fixed_bytes());
+    state
+        .apply_certificate(certificate, signer, l1_info_root)
+        .map_err(|source| CertificationError::Types { source })
 }
@@ -204,6 +204,64 @@ async fn prover_timeout() {
     scenario.teardown();
 }
 
+#[rstest::rstest]
+#[test_log::test(tokio::test)]
+async fn execute_natively_rebuilds_the_state() {
+    let base_path = TempDBDir::new();
+    let mut config = Config::new(&base_path.path);
+
+    let pending_store = MockPendingStore::new();
+    let mut l1_rpc = MockL1Rpc::new();
+
+    let fake_prover = FakeProver::default();
+    let endpoint = next_available_addr();
+    config.prover_entrypoint = format!("http://{endpoint}");
+    let cancellation = CancellationToken::new();
+
+    FakeProver::spawn_at(fake_prover, endpoint, cancellation.clone())
+        .await
+        .unwrap();
+
+    tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+
+    let mut state = Forest::new(vec![]);
+    let local_state = state.state_b.clone();
+
+    let (certificate, signer) = state.apply_events(&[], &[]);
+
+    let signer: H160 = H160(**signer);
+
+    l1_rpc
+        .expect_get_l1_info_root()
+        .once()
+        .returning(move |_| Ok(Default::default()));
+
+    l1_rpc
+        .expect_get_trusted_sequencer_address()
+        .once()
+        .with(eq(*certificate.network_id), always())
+        .returning(move |_, _| Ok(signer));
+
+    let certifier = CertifierClient::try_new(
+        config.prover_entrypoint.clone(),
+        Arc::new(pending_store),
+        Arc::new(l1_rpc),
+        Arc::new(config),
+    )
+    .await
+    .unwrap();
+
+    // The proof isn't generated again, the pending store is left untouched.
+    let new_state = certifier
+        .execute_natively(local_state, certificate)
+        .await
+        .unwrap();
+
+    assert_eq!(new_state.get_roots(), state.state_b.get_roots());
+
+    cancellation.cancel();
+}
+
 mockall::mock! {
     L1Rpc {}
     #[async_trait::async_trait]
@@ -221,6 +279,7 @@ mockall::mock! {
     impl Settler for L1Rpc {
         type M = NonceManagerMiddleware<Provider<MockProvider>>;
 
+        fn client(&self) -> Arc<NonceManagerMiddleware<Provider<MockProvider>>>;
         fn decode_contract_revert(error: &ethers::contract::ContractError<NonceManagerMiddleware<Provider<MockProvider> > > ) -> Option<String>;
         fn build_verify_pessimistic_trusted_aggregator_call(
             &self,
@@ -1,5 +1,9 @@
 #![cfg_attr(feature = "coverage", feature(coverage_attribute))]
 
+use std::sync::OnceLock;
+
+use sp1_sdk::{HashableKey as _, MockProver, Prover as _};
+
 /// ELF of the pessimistic proof program
 const ELF: &[u8] =
     include_bytes!("../../pessimistic-proof-program/elf/riscv32im-succinct-zkvm-elf");
@@ -7,6 +11,21 @@ const ELF: &[u8] =
 mod certifier;
 mod packer;
 mod proof;
+mod settlement;
 
-pub use certifier::CertifierClient;
+pub use certifier::{execute_pessimistic_proof, CertifierClient};
 pub use packer::EpochPackerClient;
+
+/// Verification key of the pessimistic proof program, in the format expected
+/// by the L1 verifier.
+///
+/// The key is computed on the first call.
+pub fn pessimistic_proof_vkey() -> &'static str {
+    static VKEY: OnceLock<String> = OnceLock::new();
+
+    VKEY.get_or_init(|| {
+        let (_, verifying_key) = MockProver::new().setup(ELF);
+
+        verifying_key.bytes32()
+    })
+}
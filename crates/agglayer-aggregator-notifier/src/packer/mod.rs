@@ -1,6 +1,6 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
-use agglayer_certificate_orchestrator::{EpochPacker, Error};
+use agglayer_certificate_orchestrator::{EpochPacker, Error, ReorgFuture};
 use agglayer_config::outbound::OutboundRpcSettleConfig;
 use agglayer_contracts::Settler;
 use agglayer_storage::{
@@ -8,14 +8,19 @@ use agglayer_storage::{
     stores::{PerEpochReader, PerEpochWriter, StateReader, StateWriter},
 };
 use agglayer_types::{
-    CertificateHeader, CertificateId, CertificateIndex, CertificateStatus, NetworkId, Proof,
+    CertificateHeader, CertificateId, CertificateIndex, CertificateStatus, Hash, NetworkId, Proof,
 };
 use bincode::Options;
+use ethers::types::H256;
 use futures::future::BoxFuture;
 use pessimistic_proof::PessimisticProofOutput;
 use tracing::Instrument;
 use tracing::{debug, error, info, instrument, warn};
 
+use crate::settlement::{
+    MinedSettlement, RecentSettlements, SettlementError, SettlementManager, REORG_TRACKING_DEPTH,
+};
+
 #[cfg(test)]
 mod tests;
 
@@ -24,6 +29,8 @@ pub struct EpochPackerClient<StateStore, PerEpochStore, RollupManagerRpc> {
     state_store: Arc<StateStore>,
     config: Arc<OutboundRpcSettleConfig>,
     l1_rpc: Arc<RollupManagerRpc>,
+    /// Settlements mined recently, checked again when the L1 reorgs.
+    recent_settlements: Arc<Mutex<RecentSettlements>>,
     _phantom: std::marker::PhantomData<fn() -> PerEpochStore>,
 }
 
@@ -40,11 +47,181 @@ impl<StateStore, PerEpochStore, RollupManagerRpc>
             config,
             l1_rpc,
             state_store,
+            recent_settlements: Default::default(),
             _phantom: std::marker::PhantomData,
         })
     }
 }
 
+impl<StateStore, PerEpochStore, RollupManagerRpc>
+    EpochPackerClient<StateStore, PerEpochStore, RollupManagerRpc>
+where
+    StateStore: StateReader,
+    RollupManagerRpc: Settler,
+{
+    /// Record again the settlements mined in the recent L1 blocks before the
+    /// node restarted, so that they are checked on the next L1 reorg.
+    ///
+    /// The settled certificates of each network are walked back from the
+    /// latest one, using their persisted settlement transaction, until one is
+    /// buried deeper than the reorg tracking depth.
+    pub async fn recover_recent_settlements(&self) -> Result<(), Error> {
+        let settlement = SettlementManager::new(self.l1_rpc.client(), self.config.clone());
+
+        for (network_id, SettledCertificate(_, latest_height, _, _)) in
+            self.state_store.get_current_settled_height()?
+        {
+            let mut latest_block = None;
+
+            for height in (0..=latest_height).rev() {
+                let Some(CertificateHeader {
+                    certificate_id,
+                    epoch_number: Some(epoch_number),
+                    certificate_index: Some(certificate_index),
+                    status: CertificateStatus::Settled,
+                    settlement_tx_hash: Some(tx_hash),
+                    ..
+                }) = self
+                    .state_store
+                    .get_certificate_header_by_cursor(network_id, height)?
+                else {
+                    break;
+                };
+
+                let receipt = match settlement.mined_receipt(H256(tx_hash.0)).await {
+                    Ok(Some(receipt)) => receipt,
+                    Ok(None) => {
+                        warn!(
+                            hash = certificate_id.to_string(),
+                            "Settlement transaction {} of {} not found on the L1",
+                            tx_hash,
+                            certificate_id
+                        );
+
+                        break;
+                    }
+                    Err(error) => {
+                        error!(
+                            hash = certificate_id.to_string(),
+                            "Failed to recover the settlement of {}: {}", certificate_id, error
+                        );
+
+                        break;
+                    }
+                };
+                let (Some(block_number), Some(block_hash)) =
+                    (receipt.block_number, receipt.block_hash)
+                else {
+                    break;
+                };
+
+                let block_number = block_number.as_u64();
+                let latest_block = *latest_block.get_or_insert(block_number);
+                if latest_block.saturating_sub(block_number) > REORG_TRACKING_DEPTH {
+                    break;
+                }
+
+                self.recent_settlements.lock().unwrap().record(
+                    block_number,
+                    MinedSettlement {
+                        network_id,
+                        settled: SettledCertificate(
+                            certificate_id,
+                            height,
+                            epoch_number,
+                            certificate_index,
+                        ),
+                        tx_hash: receipt.transaction_hash,
+                        block_hash,
+                    },
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Expose the settlement transaction on the certificate header.
+fn record_settlement_tx_hash<StateStore: StateWriter>(
+    state_store: &StateStore,
+    certificate_id: CertificateId,
+    tx_hash: &Hash,
+) {
+    if let Err(error) =
+        state_store.update_certificate_header_settlement_tx_hash(&certificate_id, tx_hash)
+    {
+        error!(
+            hash = certificate_id.to_string(),
+            "Failed to record the settlement transaction {} of {}: {}",
+            tx_hash,
+            certificate_id,
+            error
+        );
+    }
+}
+
+/// Move a certificate whose settlement vanished in a reorg back to candidate.
+///
+/// The local network state is rolled back to the one preceding the
+/// settlement, and the latest settled certificate of the network is rewound
+/// to the previous one, the certificates settled after it being reverted
+/// first.
+fn revert_settlement<StateStore: StateReader + StateWriter>(
+    state_store: &StateStore,
+    network_id: NetworkId,
+    SettledCertificate(certificate_id, height, _, _): &SettledCertificate,
+) -> Result<(), Error> {
+    state_store.update_certificate_header_status(certificate_id, &CertificateStatus::Candidate)?;
+
+    if let Err(error) = state_store.rewind_local_network_state(&network_id, height) {
+        warn!(
+            hash = certificate_id.to_string(),
+            "Unable to rewind the local network state of network {} before {}: {}",
+            network_id,
+            certificate_id,
+            error
+        );
+    }
+
+    let is_latest = state_store
+        .get_latest_settled_certificate_per_network(&network_id)?
+        .is_some_and(|(_, SettledCertificate(latest, ..))| latest == *certificate_id);
+    if !is_latest {
+        return Ok(());
+    }
+
+    match height
+        .checked_sub(1)
+        .map(|previous| state_store.get_certificate_header_by_cursor(network_id, previous))
+        .transpose()?
+        .flatten()
+    {
+        Some(CertificateHeader {
+            certificate_id: previous_id,
+            height: previous,
+            epoch_number: Some(epoch_number),
+            certificate_index: Some(certificate_index),
+            status: CertificateStatus::Settled,
+            ..
+        }) => state_store.set_latest_settled_certificate_for_network(
+            &network_id,
+            &previous,
+            &previous_id,
+            &epoch_number,
+            &certificate_index,
+        )?,
+        _ => warn!(
+            hash = certificate_id.to_string(),
+            "Unable to rewind the latest settled certificate of network {} before {}",
+            network_id,
+            certificate_id
+        ),
+    }
+
+    Ok(())
+}
+
 type SettlementResult<'a> =
     Result<BoxFuture<'a, Result<(NetworkId, SettledCertificate), Error>>, Error>;
 
@@ -136,46 +313,91 @@ where
             output.display_to_hex()
         );
 
+        // The transactions sent before a restart are monitored again.
+        let sent = related_epoch
+            .get_settlement_tx_hashes(certificate_index)?
+            .into_iter()
+            .map(|tx_hash| H256(tx_hash.0))
+            .collect();
+
         let state_store = self.state_store.clone();
-        let config = self.config.clone();
+        let recent_settlements = self.recent_settlements.clone();
+        let settlement = SettlementManager::new(self.l1_rpc.client(), self.config.clone());
         // Call the Provider
         let fut = Box::pin(
             async move {
-                let _tx = contract_call
-                    .send()
+                let on_sent = |sent: &[H256]| {
+                    let tx_hashes = sent
+                        .iter()
+                        .map(|tx_hash| Hash(tx_hash.0))
+                        .collect::<Vec<_>>();
+
+                    if let Err(error) =
+                        related_epoch.set_settlement_tx_hashes(certificate_index, &tx_hashes)
+                    {
+                        error!(
+                            hash,
+                            "Failed to persist the settlement transactions of {}: {}",
+                            certificate_id,
+                            error
+                        );
+                    }
+                    if let Some(tx_hash) = tx_hashes.last() {
+                        record_settlement_tx_hash(state_store.as_ref(), certificate_id, tx_hash);
+                    }
+                };
+
+                let receipt = settlement
+                    .settle(contract_call.tx, sent, on_sent)
                     .await
-                    .inspect(|tx| info!(hash, "Inspect settle transaction: {:?}", tx))
-                    // .map_err(SettlementError::ContractError)?
-                    .map_err(|e| {
-                        let error_str =
-                            RollupManagerRpc::decode_contract_revert(&e).unwrap_or(e.to_string());
+                    .map_err(|error| {
+                        let error_str = match &error {
+                            SettlementError::Rejected(e) => {
+                                RollupManagerRpc::decode_contract_revert(e)
+                                    .unwrap_or_else(|| error.to_string())
+                            }
+                            _ => error.to_string(),
+                        };
 
                         error!(
-                        error_code = %e,
-                        error = error_str,
-                        hash,
-                            "Failed to settle the certificate {certificate_id}: {}", error_str);
+                            error = %error,
+                            hash,
+                            "Failed to settle the certificate {certificate_id}: {}", error_str
+                        );
 
                         Error::SettlementError {
                             certificate_id,
                             error: error_str,
                         }
-                    })?
-                    .interval(config.retry_interval)
-                    .retries(config.max_retries)
-                    .confirmations(config.confirmations)
-                    .await
-                    .map_err(|error| Error::SettlementError {
-                        certificate_id,
-                        error: error.to_string(),
-                    })?
-                    // If the result is `None`, it means the transaction is no longer
-                    // in the mempool.
-                    .ok_or(Error::SettlementError {
-                        certificate_id,
-                        error: "No receipt hash returned, transaction still in mempool".to_string(),
                     })?;
 
+                info!(
+                    hash,
+                    "Certificate {certificate_id} settled in the transaction {:?}",
+                    receipt.transaction_hash
+                );
+                record_settlement_tx_hash(
+                    state_store.as_ref(),
+                    certificate_id,
+                    &Hash(receipt.transaction_hash.0),
+                );
+
+                let settled =
+                    SettledCertificate(certificate_id, height, epoch_number, certificate_index);
+                if let (Some(block_number), Some(block_hash)) =
+                    (receipt.block_number, receipt.block_hash)
+                {
+                    recent_settlements.lock().unwrap().record(
+                        block_number.as_u64(),
+                        MinedSettlement {
+                            network_id,
+                            settled: settled.clone(),
+                            tx_hash: receipt.transaction_hash,
+                            block_hash,
+                        },
+                    );
+                }
+
                 if let Err(error) = state_store
                     .update_certificate_header_status(&certificate_id, &CertificateStatus::Settled)
                 {
@@ -204,10 +426,7 @@ where
                     );
                 }
 
-                Ok::<_, Error>((
-                    network_id,
-                    SettledCertificate(certificate_id, height, epoch_number, certificate_index),
-                ))
+                Ok::<_, Error>((network_id, settled))
             }
             .instrument(tracing::Span::current()),
         );
@@ -215,6 +434,98 @@ where
         Ok(fut)
     }
 
+    fn recheck_settlements(&self, from_block: u64) -> Result<ReorgFuture, Error> {
+        let mined = self
+            .recent_settlements
+            .lock()
+            .unwrap()
+            .take_from(from_block);
+        debug!(
+            "Checking {} settlements mined from the L1 block {} after a reorg",
+            mined.len(),
+            from_block
+        );
+
+        let state_store = self.state_store.clone();
+        let recent_settlements = self.recent_settlements.clone();
+        let settlement = SettlementManager::new(self.l1_rpc.client(), self.config.clone());
+
+        Ok(Box::pin(async move {
+            let mut reverted = Vec::new();
+
+            // The latest settlements are checked first, so that the latest settled
+            // certificate of a network is rewound one certificate at a time.
+            for (block_number, mined) in mined {
+                let certificate_id = mined.settled.0;
+
+                let receipt = match settlement.mined_receipt(mined.tx_hash).await {
+                    Ok(receipt) => receipt,
+                    Err(error) => {
+                        // Kept to be checked again on the next reorg.
+                        error!(
+                            hash = certificate_id.to_string(),
+                            "Failed to check the settlement of {} after a reorg: {}",
+                            certificate_id,
+                            error
+                        );
+                        recent_settlements
+                            .lock()
+                            .unwrap()
+                            .record(block_number, mined);
+
+                        continue;
+                    }
+                };
+
+                if let Some(receipt) = receipt {
+                    let block_hash = receipt.block_hash.unwrap_or(mined.block_hash);
+                    if block_hash != mined.block_hash {
+                        info!(
+                            hash = certificate_id.to_string(),
+                            "Settlement of {} mined again in the L1 block {:?}",
+                            certificate_id,
+                            block_hash
+                        );
+                    }
+
+                    recent_settlements.lock().unwrap().record(
+                        receipt
+                            .block_number
+                            .map_or(block_number, |number| number.as_u64()),
+                        MinedSettlement {
+                            block_hash,
+                            ..mined
+                        },
+                    );
+
+                    continue;
+                }
+
+                warn!(
+                    hash = certificate_id.to_string(),
+                    "Settlement transaction {:?} of {} removed from the L1 by a reorg",
+                    mined.tx_hash,
+                    certificate_id
+                );
+                if let Err(error) =
+                    revert_settlement(state_store.as_ref(), mined.network_id, &mined.settled)
+                {
+                    error!(
+                        hash = certificate_id.to_string(),
+                        "Failed to move {} back to candidate after a reorg: {:?}",
+                        certificate_id,
+                        error
+                    );
+
+                    continue;
+                }
+                reverted.push((mined.network_id, mined.settled));
+            }
+
+            Ok(reverted)
+        }))
+    }
+
     fn pack(
         &self,
         closing_epoch: Arc<Self::PerEpochStore>,
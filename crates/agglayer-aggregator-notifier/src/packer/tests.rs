@@ -3,24 +3,31 @@ use std::sync::Arc;
 use agglayer_certificate_orchestrator::EpochPacker;
 use agglayer_config::outbound::OutboundRpcSettleConfig;
 use agglayer_contracts::Settler;
-use agglayer_storage::tests::mocks::{MockPerEpochStore, MockStateStore};
-use agglayer_types::{Certificate, CertificateHeader, LocalNetworkStateData, Proof};
+use agglayer_storage::{
+    columns::latest_settled_certificate_per_network::SettledCertificate,
+    tests::mocks::{MockPerEpochStore, MockStateStore},
+};
+use agglayer_types::{
+    Certificate, CertificateHeader, CertificateStatus, LocalNetworkStateData, Proof,
+};
 use ethers::{
     contract::{ContractCall, ContractError},
     middleware::NonceManagerMiddleware,
     providers::{MockProvider, Provider},
-    types::H160,
+    types::{TransactionReceipt, H160, H256},
 };
 use mockall::predicate::eq;
 use rstest::rstest;
 
-use crate::EpochPackerClient;
+use super::revert_settlement;
+use crate::{settlement::MinedSettlement, EpochPackerClient};
 
 mockall::mock! {
     L1Rpc {}
     impl Settler for L1Rpc {
         type M = NonceManagerMiddleware<Provider<MockProvider>>;
 
+        fn client(&self) -> Arc<NonceManagerMiddleware<Provider<MockProvider>>>;
         fn decode_contract_revert(error: &ContractError<NonceManagerMiddleware<Provider<MockProvider>>>) -> Option<String>;
         fn build_verify_pessimistic_trusted_aggregator_call(
             &self,
@@ -57,6 +64,7 @@ fn epoch_packer_can_settle_one_certificate() {
                 new_local_exit_root: [0; 32].into(),
                 metadata: [0; 32].into(),
                 status: agglayer_types::CertificateStatus::Candidate,
+                settlement_tx_hash: None,
             }))
         });
 
@@ -101,3 +109,139 @@ fn epoch_packer_can_settle_one_certificate() {
         .settle_certificate(Arc::new(per_epoch_store), 0, certificate_id)
         .is_err());
 }
+
+#[rstest]
+fn reverted_settlement_rewinds_the_latest_settled_certificate() {
+    let network_id = 1.into();
+    let previous_id = Certificate::new_for_test(network_id, 0).hash();
+    let certificate_id = Certificate::new_for_test(network_id, 1).hash();
+
+    let mut state_store = MockStateStore::new();
+    state_store
+        .expect_update_certificate_header_status()
+        .once()
+        .with(eq(certificate_id), eq(CertificateStatus::Candidate))
+        .returning(|_, _| Ok(()));
+    state_store
+        .expect_rewind_local_network_state()
+        .once()
+        .with(eq(network_id), eq(1))
+        .returning(|_, _| Ok(()));
+    state_store
+        .expect_get_latest_settled_certificate_per_network()
+        .once()
+        .with(eq(network_id))
+        .returning(move |_| {
+            Ok(Some((
+                network_id,
+                SettledCertificate(certificate_id, 1, 1, 0),
+            )))
+        });
+    state_store
+        .expect_get_certificate_header_by_cursor()
+        .once()
+        .with(eq(network_id), eq(0))
+        .returning(move |_, _| {
+            Ok(Some(CertificateHeader {
+                network_id,
+                height: 0,
+                epoch_number: Some(0),
+                certificate_index: Some(2),
+                certificate_id: previous_id,
+                prev_local_exit_root: [0; 32].into(),
+                new_local_exit_root: [1; 32].into(),
+                metadata: [0; 32].into(),
+                status: CertificateStatus::Settled,
+                settlement_tx_hash: None,
+            }))
+        });
+    state_store
+        .expect_set_latest_settled_certificate_for_network()
+        .once()
+        .with(eq(network_id), eq(0), eq(previous_id), eq(0), eq(2))
+        .returning(|_, _, _, _, _| Ok(()));
+
+    revert_settlement(
+        &state_store,
+        network_id,
+        &SettledCertificate(certificate_id, 1, 1, 0),
+    )
+    .unwrap();
+}
+
+#[tokio::test]
+async fn recent_settlements_are_recovered_on_startup() {
+    let network_id = 1.into();
+    let certificate_id = move |height: u64| Certificate::new_for_test(network_id, height).hash();
+    let settled = move |height: u64| SettledCertificate(certificate_id(height), height, height, 0);
+
+    let mut state_store = MockStateStore::new();
+    state_store
+        .expect_get_current_settled_height()
+        .once()
+        .returning(move || Ok(vec![(network_id, settled(2))]));
+    state_store
+        .expect_get_certificate_header_by_cursor()
+        .times(3)
+        .returning(move |network_id, height| {
+            Ok(Some(CertificateHeader {
+                network_id,
+                height,
+                epoch_number: Some(height),
+                certificate_index: Some(0),
+                certificate_id: certificate_id(height),
+                prev_local_exit_root: [0; 32].into(),
+                new_local_exit_root: [1; 32].into(),
+                metadata: [0; 32].into(),
+                status: CertificateStatus::Settled,
+                settlement_tx_hash: Some([height as u8; 32].into()),
+            }))
+        });
+
+    // The settlement at height 0 is buried deeper than the reorg tracking depth
+    // under the latest one. The mocked responses are returned last in, first out.
+    let (provider, mock) = Provider::mocked();
+    for (height, block_number) in [(0u64, 600u64), (1, 900), (2, 1_000)] {
+        mock.push(TransactionReceipt {
+            transaction_hash: H256::repeat_byte(height as u8),
+            block_number: Some(block_number.into()),
+            block_hash: Some(H256::from_low_u64_be(block_number)),
+            status: Some(1.into()),
+            ..Default::default()
+        })
+        .unwrap();
+    }
+
+    let mut l1_rpc = MockL1Rpc::new();
+    l1_rpc
+        .expect_client()
+        .return_const(Arc::new(NonceManagerMiddleware::new(
+            provider,
+            H160::zero(),
+        )));
+
+    let epoch_packer = EpochPackerClient::<_, MockPerEpochStore, _>::try_new(
+        Arc::new(OutboundRpcSettleConfig::default()),
+        Arc::new(state_store),
+        Arc::new(l1_rpc),
+    )
+    .unwrap();
+
+    epoch_packer.recover_recent_settlements().await.unwrap();
+
+    let mined = |height: u64, block_number: u64| {
+        (
+            block_number,
+            MinedSettlement {
+                network_id,
+                settled: settled(height),
+                tx_hash: H256::repeat_byte(height as u8),
+                block_hash: H256::from_low_u64_be(block_number),
+            },
+        )
+    };
+    assert_eq!(
+        epoch_packer.recent_settlements.lock().unwrap().take_from(0),
+        vec![mined(2, 1_000), mined(1, 900)]
+    );
+}
@@ -0,0 +1,439 @@
+//! Submission of the settlement transactions to L1.
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+use agglayer_config::outbound::OutboundRpcSettleConfig;
+use agglayer_storage::columns::latest_settled_certificate_per_network::SettledCertificate;
+use agglayer_types::NetworkId;
+use ethers::{
+    contract::ContractError,
+    providers::Middleware,
+    types::{
+        transaction::eip2718::TypedTransaction, Address, Bytes, Transaction, TransactionReceipt,
+        H256, U256, U64,
+    },
+};
+use tokio::time::Instant;
+use tracing::{debug, warn};
+
+#[cfg(test)]
+mod tests;
+
+/// Number of L1 blocks during which a mined settlement is checked again when
+/// the L1 reorgs.
+pub(crate) const REORG_TRACKING_DEPTH: u64 = 256;
+
+/// Gas of the transfer replacing a settlement transaction which timed out.
+const CANCELLATION_GAS: u64 = 21_000;
+
+/// Errors of the settlement, classified by the outcome of the transaction.
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum SettlementError<M: Middleware> {
+    /// The transaction was rejected by the node, it never reached the mempool.
+    #[error("settlement transaction rejected: {0}")]
+    Rejected(ContractError<M>),
+    /// The transaction was mined but its execution reverted.
+    #[error("settlement transaction {0:?} reverted")]
+    Reverted(H256),
+    /// The nonce of the transaction was used by a transaction which isn't one
+    /// of its replacements.
+    #[error(
+        "settlement transaction with nonce {0} dropped, the nonce was used by another transaction"
+    )]
+    Dropped(U256),
+    /// The transaction was still pending at the settlement timeout and its
+    /// nonce was used by the transfer sent to cancel it.
+    #[error("settlement timed out after {}s", .0.as_secs())]
+    Timeout(Duration),
+    #[error("provider error: {0}")]
+    Provider(M::Error),
+}
+
+/// Submit a settlement transaction and follow it until it is mined.
+///
+/// A transaction still pending after the fee bump interval is replaced by one
+/// with the same nonce and bumped fees, up to the configured cap. Every
+/// replacement is tracked, as any of them can end up being mined.
+///
+/// A transaction still pending at the settlement timeout is cancelled by a
+/// zero-value transfer to the sender with the same nonce. The settlement only
+/// fails once the transfer is mined, the transactions sent before being
+/// monitored until then.
+pub(crate) struct SettlementManager<M> {
+    client: Arc<M>,
+    config: Arc<OutboundRpcSettleConfig>,
+}
+
+impl<M: Middleware> SettlementManager<M> {
+    pub(crate) fn new(client: Arc<M>, config: Arc<OutboundRpcSettleConfig>) -> Self {
+        Self { client, config }
+    }
+
+    /// Settle the transaction, cancelling it if still pending at the
+    /// settlement timeout.
+    ///
+    /// The transactions already sent for this settlement are monitored again
+    /// instead of sending a new one. `on_sent` is given every transaction sent
+    /// so far each time a new one is broadcast.
+    pub(crate) async fn settle(
+        &self,
+        mut tx: TypedTransaction,
+        mut sent: Vec<H256>,
+        on_sent: impl Fn(&[H256]) + Send + Sync,
+    ) -> Result<TransactionReceipt, SettlementError<M>> {
+        let timeout = self.config.settlement_timeout;
+        let deadline = Instant::now() + timeout;
+
+        let resumed = self.latest_sent(&sent).await?;
+        match &resumed {
+            Some(sent_tx) => resume(&mut tx, sent_tx),
+            // None of the transactions sent before is known by the node, none of
+            // them can be mined anymore.
+            None => sent.clear(),
+        }
+
+        self.client
+            .fill_transaction(&mut tx, None)
+            .await
+            .map_err(|error| {
+                SettlementError::Rejected(ContractError::from_middleware_error(error))
+            })?;
+
+        // The nonce is set once and for all, the replacements reuse it.
+        let nonce = tx.nonce().copied().unwrap_or_default();
+        let sender = tx.from().copied();
+
+        if resumed.is_some() {
+            debug!("Resuming the monitoring of the settlement transaction with nonce {nonce}");
+        } else {
+            let tx_hash = self
+                .client
+                .send_transaction(tx.clone(), None)
+                .await
+                .map_err(|error| {
+                    SettlementError::Rejected(ContractError::from_middleware_error(error))
+                })?
+                .tx_hash();
+            debug!("Settlement transaction {tx_hash:?} sent with nonce {nonce}");
+
+            sent.push(tx_hash);
+            on_sent(&sent);
+        }
+
+        let mut next_broadcast = Instant::now() + self.config.fee_bump_interval;
+        let mut timed_out = false;
+        // The transfer cancelling the settlement once timed out, along with the
+        // hashes of its broadcasts.
+        let mut cancellation: Option<(TypedTransaction, Vec<H256>)> = None;
+
+        loop {
+            tokio::time::sleep(self.config.retry_interval).await;
+
+            let polled = self.poll(&sent, sender, nonce).await;
+            // The nonce may have been used by the transfer cancelling the settlement.
+            if let (Err(SettlementError::Dropped(_)), Some((_, cancellations))) =
+                (&polled, &cancellation)
+            {
+                if self.any_mined(cancellations).await? {
+                    return Err(SettlementError::Timeout(timeout));
+                }
+            }
+            if let Some(receipt) = polled? {
+                return Ok(receipt);
+            }
+
+            if !timed_out && Instant::now() >= deadline {
+                timed_out = true;
+
+                match sender {
+                    Some(sender) => {
+                        warn!(
+                            "Settlement transaction with nonce {nonce} still pending after {}s, \
+                             cancelling it",
+                            timeout.as_secs()
+                        );
+                        cancellation = Some((cancellation_of(&tx, sender), Vec::new()));
+                        next_broadcast = Instant::now();
+                    }
+                    None => warn!(
+                        "Settlement transaction with nonce {nonce} still pending after {}s, its \
+                         sender is unknown so it is only monitored",
+                        timeout.as_secs()
+                    ),
+                }
+            }
+
+            if Instant::now() < next_broadcast {
+                continue;
+            }
+
+            let (replacement, cancellations) = match cancellation.as_mut() {
+                Some((cancellation, cancellations)) => (cancellation, Some(cancellations)),
+                None => (&mut tx, None),
+            };
+
+            // Once the cap is reached, the transaction is re-broadcast as is in
+            // case it was evicted from the mempool.
+            if !bump_fees(
+                replacement,
+                self.config.fee_bump_percentage,
+                self.config.max_fee_per_gas_cap.into(),
+            ) {
+                warn!("Settlement transaction with nonce {nonce} pending with fees at the cap");
+            }
+
+            match self
+                .client
+                .send_transaction(replacement.clone(), None)
+                .await
+            {
+                Ok(pending) => {
+                    let tx_hash = pending.tx_hash();
+
+                    match cancellations {
+                        Some(cancellations) => {
+                            debug!("Cancellation {tx_hash:?} broadcast with nonce {nonce}");
+
+                            if !cancellations.contains(&tx_hash) {
+                                cancellations.push(tx_hash);
+                            }
+                        }
+                        None => {
+                            debug!(
+                                "Settlement transaction {tx_hash:?} re-broadcast with nonce \
+                                 {nonce}"
+                            );
+
+                            if !sent.contains(&tx_hash) {
+                                sent.push(tx_hash);
+                                on_sent(&sent);
+                            }
+                        }
+                    }
+                }
+                // The node refuses the replacement when the transaction is already known
+                // or mined, the next poll tells.
+                Err(error) => warn!(
+                    "Failed to re-broadcast the settlement transaction with nonce {nonce}: {error}"
+                ),
+            }
+
+            next_broadcast = Instant::now() + self.config.fee_bump_interval;
+        }
+    }
+
+    /// Whether any of the transactions is mined.
+    async fn any_mined(&self, tx_hashes: &[H256]) -> Result<bool, SettlementError<M>> {
+        for tx_hash in tx_hashes {
+            let receipt = self
+                .client
+                .get_transaction_receipt(*tx_hash)
+                .await
+                .map_err(SettlementError::Provider)?;
+
+            if receipt.is_some_and(|receipt| receipt.block_number.is_some()) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Get the receipt of a settlement transaction if it is still mined
+    /// successfully.
+    pub(crate) async fn mined_receipt(
+        &self,
+        tx_hash: H256,
+    ) -> Result<Option<TransactionReceipt>, SettlementError<M>> {
+        let receipt = self
+            .client
+            .get_transaction_receipt(tx_hash)
+            .await
+            .map_err(SettlementError::Provider)?;
+
+        Ok(receipt
+            .filter(|receipt| receipt.status == Some(U64::one()) && receipt.block_number.is_some()))
+    }
+
+    /// Get the latest of the transactions sent which is known by the node.
+    async fn latest_sent(&self, sent: &[H256]) -> Result<Option<Transaction>, SettlementError<M>> {
+        for tx_hash in sent.iter().rev() {
+            if let Some(tx) = self
+                .client
+                .get_transaction(*tx_hash)
+                .await
+                .map_err(SettlementError::Provider)?
+            {
+                return Ok(Some(tx));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Look for the transaction mined among the ones sent with the nonce.
+    async fn poll(
+        &self,
+        sent: &[H256],
+        sender: Option<Address>,
+        nonce: U256,
+    ) -> Result<Option<TransactionReceipt>, SettlementError<M>> {
+        // The nonce is checked before the receipts, so that a transaction mined
+        // in between is found by the latter.
+        let nonce_used = match sender {
+            Some(sender) => {
+                self.client
+                    .get_transaction_count(sender, None)
+                    .await
+                    .map_err(SettlementError::Provider)?
+                    > nonce
+            }
+            None => false,
+        };
+
+        for tx_hash in sent.iter().rev() {
+            let Some(receipt) = self
+                .client
+                .get_transaction_receipt(*tx_hash)
+                .await
+                .map_err(SettlementError::Provider)?
+            else {
+                continue;
+            };
+            let Some(block_number) = receipt.block_number else {
+                continue;
+            };
+
+            let current_block = self
+                .client
+                .get_block_number()
+                .await
+                .map_err(SettlementError::Provider)?;
+            if current_block.as_u64() + 1 < block_number.as_u64() + self.config.confirmations as u64
+            {
+                return Ok(None);
+            }
+
+            return if receipt.status == Some(U64::one()) {
+                Ok(Some(receipt))
+            } else {
+                Err(SettlementError::Reverted(*tx_hash))
+            };
+        }
+
+        if nonce_used {
+            return Err(SettlementError::Dropped(nonce));
+        }
+
+        Ok(None)
+    }
+}
+
+/// Settlement mined on L1, along with the block it was mined in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct MinedSettlement {
+    pub(crate) network_id: NetworkId,
+    pub(crate) settled: SettledCertificate,
+    pub(crate) tx_hash: H256,
+    pub(crate) block_hash: H256,
+}
+
+/// Settlements mined in the recent L1 blocks, by block number.
+///
+/// The settlements are forgotten once buried deeper than the reorg tracking
+/// depth under the latest one.
+#[derive(Default)]
+pub(crate) struct RecentSettlements {
+    by_block: BTreeMap<u64, Vec<MinedSettlement>>,
+}
+
+impl RecentSettlements {
+    pub(crate) fn record(&mut self, block_number: u64, settlement: MinedSettlement) {
+        self.by_block
+            .entry(block_number)
+            .or_default()
+            .push(settlement);
+
+        if let Some(latest) = self.by_block.keys().next_back() {
+            self.by_block = self
+                .by_block
+                .split_off(&latest.saturating_sub(REORG_TRACKING_DEPTH));
+        }
+    }
+
+    /// Take the settlements mined from the block onwards, the latest first.
+    pub(crate) fn take_from(&mut self, block_number: u64) -> Vec<(u64, MinedSettlement)> {
+        self.by_block
+            .split_off(&block_number)
+            .into_iter()
+            .rev()
+            .flat_map(|(block_number, settlements)| {
+                settlements
+                    .into_iter()
+                    .rev()
+                    .map(move |settlement| (block_number, settlement))
+            })
+            .collect()
+    }
+}
+
+/// Make the transaction a replacement of a transaction already sent.
+fn resume(tx: &mut TypedTransaction, sent: &Transaction) {
+    tx.set_from(sent.from);
+    tx.set_nonce(sent.nonce);
+    tx.set_gas(sent.gas);
+
+    match tx {
+        TypedTransaction::Eip1559(tx) => {
+            tx.max_fee_per_gas = sent.max_fee_per_gas;
+            tx.max_priority_fee_per_gas = sent.max_priority_fee_per_gas;
+        }
+        tx => {
+            if let Some(gas_price) = sent.gas_price {
+                tx.set_gas_price(gas_price);
+            }
+        }
+    }
+}
+
+/// Zero-value transfer to the sender replacing a settlement transaction, with
+/// its nonce and fees.
+fn cancellation_of(tx: &TypedTransaction, sender: Address) -> TypedTransaction {
+    let mut cancellation = tx.clone();
+    cancellation.set_to(sender);
+    cancellation.set_value(U256::zero());
+    cancellation.set_data(Bytes::default());
+    cancellation.set_access_list(Default::default());
+    cancellation.set_gas(CANCELLATION_GAS);
+
+    cancellation
+}
+
+/// Bump the fees of the transaction by the percentage, up to the cap.
+///
+/// Returns `false` if the fees already reached the cap.
+fn bump_fees(tx: &mut TypedTransaction, percentage: u64, cap: U256) -> bool {
+    let bump = |fee: U256| (fee * U256::from(100 + percentage) / U256::from(100)).min(cap);
+
+    match tx {
+        TypedTransaction::Eip1559(tx) => {
+            let Some(max_fee_per_gas) = tx.max_fee_per_gas.filter(|fee| *fee < cap) else {
+                return false;
+            };
+            let max_fee_per_gas = bump(max_fee_per_gas);
+
+            tx.max_fee_per_gas = Some(max_fee_per_gas);
+            tx.max_priority_fee_per_gas = tx
+                .max_priority_fee_per_gas
+                .map(|fee| bump(fee).min(max_fee_per_gas));
+        }
+        tx => {
+            let Some(gas_price) = tx.gas_price().filter(|fee| *fee < cap) else {
+                return false;
+            };
+
+            tx.set_gas_price(bump(gas_price));
+        }
+    }
+
+    true
+}
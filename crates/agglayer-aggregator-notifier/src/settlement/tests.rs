@@ -0,0 +1,124 @@
+use agglayer_storage::columns::latest_settled_certificate_per_network::SettledCertificate;
+use ethers::types::{
+    transaction::eip2718::TypedTransaction, Address, Bytes, Eip1559TransactionRequest, Transaction,
+    TransactionRequest, H256, U256,
+};
+
+use super::{
+    bump_fees, cancellation_of, resume, MinedSettlement, RecentSettlements, CANCELLATION_GAS,
+    REORG_TRACKING_DEPTH,
+};
+
+fn eip1559(max_fee_per_gas: u64, max_priority_fee_per_gas: u64) -> TypedTransaction {
+    Eip1559TransactionRequest::new()
+        .max_fee_per_gas(max_fee_per_gas)
+        .max_priority_fee_per_gas(max_priority_fee_per_gas)
+        .into()
+}
+
+#[test]
+fn fees_are_bumped() {
+    let mut tx = eip1559(100, 10);
+
+    assert!(bump_fees(&mut tx, 20, U256::from(1_000)));
+    assert_eq!(tx, eip1559(120, 12));
+}
+
+#[test]
+fn fees_are_bumped_up_to_the_cap() {
+    let mut tx = eip1559(100, 100);
+
+    assert!(bump_fees(&mut tx, 20, U256::from(110)));
+    assert_eq!(tx, eip1559(110, 110));
+
+    assert!(!bump_fees(&mut tx, 20, U256::from(110)));
+    assert_eq!(tx, eip1559(110, 110));
+}
+
+#[test]
+fn legacy_gas_price_is_bumped() {
+    let mut tx: TypedTransaction = TransactionRequest::new().gas_price(100).into();
+
+    assert!(bump_fees(&mut tx, 20, U256::from(1_000)));
+    assert_eq!(tx.gas_price(), Some(U256::from(120)));
+}
+
+#[test]
+fn resumed_transaction_replaces_the_one_sent() {
+    let sent = Transaction {
+        from: Address::repeat_byte(1),
+        nonce: 7.into(),
+        gas: 100_000.into(),
+        max_fee_per_gas: Some(100.into()),
+        max_priority_fee_per_gas: Some(10.into()),
+        ..Default::default()
+    };
+    let mut tx = eip1559(1, 1);
+
+    resume(&mut tx, &sent);
+
+    let mut expected = eip1559(100, 10);
+    expected.set_from(sent.from);
+    expected.set_nonce(sent.nonce);
+    expected.set_gas(sent.gas);
+    assert_eq!(tx, expected);
+}
+
+#[test]
+fn cancellation_is_a_transfer_to_the_sender_with_the_same_nonce() {
+    let sender = Address::repeat_byte(1);
+    let mut tx = eip1559(100, 10);
+    tx.set_from(sender);
+    tx.set_to(Address::repeat_byte(2));
+    tx.set_nonce(7);
+    tx.set_value(1);
+    tx.set_data(Bytes::from(vec![1, 2, 3]));
+    tx.set_gas(1_000_000);
+
+    let cancellation = cancellation_of(&tx, sender);
+
+    let mut expected = eip1559(100, 10);
+    expected.set_from(sender);
+    expected.set_to(sender);
+    expected.set_nonce(7);
+    expected.set_value(0);
+    expected.set_data(Bytes::default());
+    expected.set_gas(CANCELLATION_GAS);
+    assert_eq!(cancellation, expected);
+}
+
+fn mined(height: u64) -> MinedSettlement {
+    MinedSettlement {
+        network_id: 1.into(),
+        settled: SettledCertificate([height as u8; 32].into(), height, 0, 0),
+        tx_hash: H256::repeat_byte(height as u8),
+        block_hash: H256::zero(),
+    }
+}
+
+#[test]
+fn reorged_settlements_are_taken_latest_first() {
+    let mut recent = RecentSettlements::default();
+    recent.record(10, mined(0));
+    recent.record(12, mined(1));
+    recent.record(12, mined(2));
+    recent.record(15, mined(3));
+
+    assert_eq!(
+        recent.take_from(11),
+        vec![(15, mined(3)), (12, mined(2)), (12, mined(1))]
+    );
+    assert_eq!(recent.take_from(0), vec![(10, mined(0))]);
+}
+
+#[test]
+fn buried_settlements_are_forgotten() {
+    let mut recent = RecentSettlements::default();
+    recent.record(10, mined(0));
+    recent.record(10 + REORG_TRACKING_DEPTH + 1, mined(1));
+
+    assert_eq!(
+        recent.take_from(0),
+        vec![(10 + REORG_TRACKING_DEPTH + 1, mined(1))]
+    );
+}
@@ -0,0 +1,49 @@
+use agglayer_types::{CertificateId, NetworkId};
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+
+use crate::NetworkTaskStatus;
+
+/// Command sent to the orchestrator by the admin interface.
+///
+/// Every command carries a oneshot sender used by the orchestrator to respond
+/// once the command has been applied.
+#[derive(Debug)]
+pub enum AdminCommand {
+    /// Stop the network task of a network, if any, and spawn a new one once
+    /// the stopped task completed.
+    RespawnNetworkTask {
+        network_id: NetworkId,
+        response: oneshot::Sender<Result<(), String>>,
+    },
+    /// Stop certifying the certificates of a network until it is resumed, the
+    /// network remaining paused across the restarts of the node.
+    PauseNetwork {
+        network_id: NetworkId,
+        response: oneshot::Sender<Result<(), String>>,
+    },
+    /// Resume the certification of a paused network.
+    ResumeNetwork {
+        network_id: NetworkId,
+        response: oneshot::Sender<Result<(), String>>,
+    },
+    /// Report the internal state of the orchestrator.
+    DumpState {
+        response: oneshot::Sender<OrchestratorState>,
+    },
+}
+
+/// Snapshot of the internal state of the orchestrator.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OrchestratorState {
+    /// Networks with a running network task.
+    pub spawned_network_tasks: Vec<NetworkId>,
+    /// Networks for which the certification is paused.
+    pub paused_networks: Vec<NetworkId>,
+    /// Certificates waiting for their settlement to be notified to their
+    /// network task.
+    pub settlement_notifiers: Vec<CertificateId>,
+    /// Supervision of the network tasks spawned since the start.
+    #[serde(default)]
+    pub network_tasks: Vec<NetworkTaskStatus>,
+}
@@ -33,4 +33,12 @@ pub trait Certifier: Unpin + Send + Sync + 'static {
         network_id: NetworkId,
         height: Height,
     ) -> CertifierResult;
+
+    /// Apply again a Certificate already proven on top of a local state, to
+    /// rebuild the resulting state without generating a new proof.
+    fn execute_natively(
+        &self,
+        full_state: LocalNetworkStateData,
+        certificate: Certificate,
+    ) -> BoxFuture<'static, Result<LocalNetworkStateData, CertificationError>>;
 }
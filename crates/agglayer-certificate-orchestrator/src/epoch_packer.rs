@@ -25,6 +25,15 @@ pub trait EpochPacker: Unpin + Send + Sync + 'static {
         certificate_index: CertificateIndex,
         certificate_id: CertificateId,
     ) -> Result<SettlementFuture, Error>;
+
+    /// Check again the settlements mined from the L1 block onwards, after a
+    /// reorg of the L1.
+    ///
+    /// Resolves to the certificates whose settlement vanished from the L1,
+    /// moved back to the candidate status to be settled again.
+    fn recheck_settlements(&self, from_block: u64) -> Result<ReorgFuture, Error>;
 }
 
 pub type SettlementFuture<'a> = BoxFuture<'a, Result<(NetworkId, SettledCertificate), Error>>;
+
+pub type ReorgFuture<'a> = BoxFuture<'a, Result<Vec<(NetworkId, SettledCertificate)>, Error>>;
@@ -1,60 +1,113 @@
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     future::Future,
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
+    time::Duration,
 };
 
 use agglayer_clock::{ClockRef, Event};
+use agglayer_config::certificate_orchestrator::CertificateOrchestrator as CertificateOrchestratorConfig;
 use agglayer_storage::{
     columns::{
         latest_proven_certificate_per_network::ProvenCertificate,
         latest_settled_certificate_per_network::SettledCertificate,
     },
     stores::{
-        EpochStoreReader, EpochStoreWriter, PendingCertificateReader, PendingCertificateWriter,
-        PerEpochReader, PerEpochWriter, StateReader, StateWriter,
+        EpochStoreReader, EpochStoreWriter, MetadataReader, MetadataWriter,
+        PendingCertificateReader, PendingCertificateWriter, PerEpochReader, PerEpochWriter,
+        StateReader, StateWriter,
     },
 };
-use agglayer_types::{CertificateId, CertificateIndex, Height, NetworkId};
+use agglayer_telemetry::{
+    settlement::{L1_REORGS, SETTLEMENTS_REORGED},
+    KeyValue,
+};
+use agglayer_types::{
+    Certificate, CertificateHeader, CertificateId, CertificateIndex, CertificateStatus,
+    EpochNumber, Height, NetworkId,
+};
 use arc_swap::ArcSwap;
 use futures_util::{stream::FuturesUnordered, FutureExt, Stream, StreamExt};
 use network_task::{NetworkTask, NewCertificate};
+use supervisor::Supervisor;
 use tokio::{
     sync::{
         mpsc::{self, Receiver},
-        oneshot,
+        oneshot, watch,
     },
     task::JoinHandle,
+    time::Sleep,
 };
 use tokio_util::sync::{CancellationToken, WaitForCancellationFutureOwned};
 use tracing::{debug, error, info, warn};
 
+mod admin;
 mod certifier;
 mod epoch_packer;
 mod error;
 mod network_task;
+mod scheduler;
+mod supervisor;
 
 #[cfg(test)]
 mod tests;
 
+pub use admin::{AdminCommand, OrchestratorState};
 pub use certifier::{CertificateInput, Certifier, CertifierOutput, CertifierResult};
-pub use epoch_packer::{EpochPacker, SettlementFuture};
+pub use epoch_packer::{EpochPacker, ReorgFuture, SettlementFuture};
 pub use error::{CertificationError, Error, PreCertificationError};
+pub use scheduler::ProverScheduler;
+pub use supervisor::{NetworkTaskState, NetworkTaskStatus};
 
 const MAX_POLL_READS: usize = 1_000;
 
 pub type EpochPackingTasks =
     FuturesUnordered<Pin<Box<dyn Future<Output = Result<(), Error>> + Send + 'static>>>;
 
-pub type NetworkTasks =
-    FuturesUnordered<Pin<Box<dyn Future<Output = Result<NetworkId, Error>> + Send + 'static>>>;
+pub type NetworkTasks = FuturesUnordered<
+    Pin<Box<dyn Future<Output = (NetworkId, u64, Result<(), Error>)> + Send + 'static>>,
+>;
+
+pub type RestartTimers =
+    FuturesUnordered<Pin<Box<dyn Future<Output = NetworkId> + Send + 'static>>>;
 
 pub type SettlementTasks = FuturesUnordered<
-    Pin<Box<dyn Future<Output = Result<(NetworkId, SettledCertificate), Error>> + Send + 'static>>,
+    Pin<
+        Box<
+            dyn Future<
+                    Output = (
+                        CertificateId,
+                        Result<(NetworkId, SettledCertificate), Error>,
+                    ),
+                > + Send
+                + 'static,
+        >,
+    >,
+>;
+
+pub type ReorgTasks = FuturesUnordered<
+    Pin<
+        Box<
+            dyn Future<Output = Result<Vec<(NetworkId, SettledCertificate)>, Error>>
+                + Send
+                + 'static,
+        >,
+    >,
 >;
 
+/// Handle on a running network task.
+struct NetworkTaskHandle {
+    /// Notifier of the new certificates of the network.
+    sender: mpsc::Sender<NewCertificate>,
+    /// Cancellation token of this network task only.
+    cancellation_token: CancellationToken,
+    /// Identifier of this network task, distinguishing it from the tasks it
+    /// replaced.
+    task_id: u64,
+}
+
 /// The Certificate orchestrator receives the certificates from CDKs.
 ///
 /// Each certificate reception triggers the generation of a pessimistic proof.
@@ -74,16 +127,25 @@ pub struct CertificateOrchestrator<
     epoch_packing_task_builder: Arc<E>,
     /// Certifier task builder.
     certifier_task_builder: Arc<CertifierClient>,
-    /// Clock stream to receive EpochEnded events.
+    /// Clock stream to receive EpochEnded and L1Reorg events.
     clock: Pin<Box<dyn Stream<Item = Event> + Send>>,
     clock_ref: ClockRef,
     /// Receiver for certificates coming from CDKs.
     data_receiver: Receiver<(NetworkId, Height, CertificateId)>,
+    /// Receiver for the commands coming from the admin interface.
+    admin_receiver: Option<Receiver<AdminCommand>>,
+    /// The configuration of the orchestrator.
+    config: CertificateOrchestratorConfig,
     /// Cancellation token future for graceful shutdown.
     cancellation_token_future: Pin<Box<WaitForCancellationFutureOwned>>,
 
     /// Cancellation token for graceful shutdown.
     cancellation_token: CancellationToken,
+    /// Maximum duration given to the work in flight to complete once
+    /// cancelled, the orchestrator stops right away if zero.
+    drain_timeout: Duration,
+    /// Deadline of the draining, set once cancelled.
+    drain_deadline: Option<Pin<Box<Sleep>>>,
 
     /// The state store to access data.
     state_store: Arc<StateStore>,
@@ -96,16 +158,42 @@ pub struct CertificateOrchestrator<
 
     /// Network tasks that are currently running, with their associated
     /// notifier.
-    spawned_network_tasks: BTreeMap<NetworkId, mpsc::Sender<NewCertificate>>,
+    spawned_network_tasks: BTreeMap<NetworkId, NetworkTaskHandle>,
+
+    /// Pause flag of each network, shared with its network task.
+    paused_networks: BTreeMap<NetworkId, watch::Sender<bool>>,
+
+    /// Certificates proven or candidate but not yet settled when the node
+    /// stopped, or whose settlement was reverted by an L1 reorg, handed to the
+    /// network task of their network when spawned.
+    recovered_certificates: BTreeMap<NetworkId, Vec<Certificate>>,
 
-    /// Notifiers for the settlement of the certificates.
+    /// Notifiers for the settlement of the certificates, a settlement being
+    /// awaited by every network task which requested it.
     settlement_notifier:
-        HashMap<CertificateId, oneshot::Sender<Result<SettledCertificate, String>>>,
+        HashMap<CertificateId, Vec<oneshot::Sender<Result<SettledCertificate, String>>>>,
+    /// Certificates which settlement task is running.
+    settlements_in_flight: BTreeSet<CertificateId>,
+    /// Notifiers of the certificates proven while draining, kept without
+    /// settling them: the certificates stay proven and are settled once
+    /// recovered on restart.
+    deferred_settlements: BTreeMap<NetworkId, oneshot::Sender<Result<SettledCertificate, String>>>,
 
     /// Network task future resolver.
     network_tasks: NetworkTasks,
+    /// Identifier of the next network task spawned.
+    next_network_task_id: u64,
+    /// Supervision of the network tasks.
+    supervisor: Supervisor,
+    /// Respawns requested from the admin interface or after an L1 reorg,
+    /// applied once the cancelled network task of the network completed.
+    pending_respawns: BTreeMap<NetworkId, Option<oneshot::Sender<Result<(), String>>>>,
+    /// Timers of the restarts of the failed network tasks.
+    restart_timers: RestartTimers,
     /// Certificate settlement task future resolver.
     settlement_tasks: SettlementTasks,
+    /// Resolver of the checks of the settlements after an L1 reorg.
+    reorg_tasks: ReorgTasks,
 
     /// Channel to receive notifications of proven certificatesa in order to
     /// settle them.
@@ -161,16 +249,29 @@ where
             epoch_packing_task_builder: Arc::new(epoch_packing_task_builder),
             certifier_task_builder: Arc::new(certifier_task_builder),
             data_receiver,
+            admin_receiver: None,
+            config: Default::default(),
             cancellation_token: cancellation_token.clone(),
             cancellation_token_future: Box::pin(cancellation_token.cancelled_owned()),
+            drain_timeout: Duration::ZERO,
+            drain_deadline: None,
             pending_store,
             epochs_store,
             current_epoch,
             state_store,
             spawned_network_tasks: Default::default(),
+            paused_networks: Default::default(),
+            recovered_certificates: Default::default(),
             network_tasks: FuturesUnordered::new(),
+            next_network_task_id: 0,
+            supervisor: Supervisor::new(Default::default()),
+            pending_respawns: Default::default(),
+            restart_timers: FuturesUnordered::new(),
             settlement_tasks: FuturesUnordered::new(),
+            reorg_tasks: FuturesUnordered::new(),
             settlement_notifier: Default::default(),
+            settlements_in_flight: Default::default(),
+            deferred_settlements: Default::default(),
             certification_notification,
             certification_notification_sender,
         })
@@ -193,7 +294,7 @@ where
     PendingStore: PendingCertificateReader + PendingCertificateWriter + 'static,
     EpochsStore: EpochStoreWriter<PerEpochStore = PerEpochStore> + EpochStoreReader + 'static,
     PerEpochStore: PerEpochWriter + PerEpochReader + 'static,
-    StateStore: StateReader + StateWriter + 'static,
+    StateStore: StateReader + StateWriter + MetadataReader + MetadataWriter + 'static,
 {
     /// Function that setups and starts the CertificateOrchestrator.
     ///
@@ -205,6 +306,13 @@ where
     /// - `cancellation_token`: Sets the cancellation token for graceful
     ///   shutdown.
     /// - `epoch_packing_builder`: Sets the task builder for epoch packing.
+    /// - `admin_receiver`: Optionally sets the receiver for the commands coming
+    ///   from the admin interface.
+    /// - `config`: Optionally sets the orchestrator configuration, the default
+    ///   one is used otherwise.
+    /// - `drain_timeout`: Optionally sets the maximum duration given to the
+    ///   certifications and settlements in flight to complete once cancelled,
+    ///   the orchestrator stops right away otherwise.
     /// - `start`: Starts the CertificateOrchestrator.
     ///
     /// # Errors
@@ -224,6 +332,9 @@ where
         epochs_store: Arc<EpochsStore>,
         current_epoch: ArcSwap<PerEpochStore>,
         state_store: Arc<StateStore>,
+        admin_receiver: Option<Receiver<AdminCommand>>,
+        config: Option<CertificateOrchestratorConfig>,
+        drain_timeout: Option<Duration>,
     ) -> anyhow::Result<JoinHandle<()>> {
         let mut orchestrator = Self::try_new(
             clock,
@@ -236,14 +347,22 @@ where
             current_epoch,
             state_store,
         )?;
+        orchestrator.admin_receiver = admin_receiver;
+        orchestrator.config = config.unwrap_or_default();
+        orchestrator.supervisor = Supervisor::new(orchestrator.config.supervisor.clone());
+        orchestrator.drain_timeout = drain_timeout.unwrap_or_default();
 
-        // Try to spawn the certifier tasks for the next height of each network
-        for ProvenCertificate(_, network_id, _height) in
-            pending_store.get_current_proven_height()?
-        {
-            orchestrator.spawn_network_task(network_id)?;
+        // The networks paused before the node stopped remain paused.
+        for network_id in orchestrator.state_store.get_paused_networks()? {
+            info!("Certification paused for network {}", network_id);
+            orchestrator
+                .paused_networks
+                .insert(network_id, watch::channel(true).0);
         }
 
+        // Try to spawn the certifier tasks for the next height of each network
+        orchestrator.recover_network_tasks()?;
+
         let handle = tokio::spawn(orchestrator);
 
         Ok(handle)
@@ -264,9 +383,136 @@ where
     E: EpochPacker<PerEpochStore = PerEpochStore>,
     PendingStore: PendingCertificateReader + PendingCertificateWriter + 'static,
     EpochsStore: EpochStoreWriter<PerEpochStore = PerEpochStore> + EpochStoreReader + 'static,
-    StateStore: StateReader + StateWriter + 'static,
+    StateStore: StateReader + StateWriter + MetadataReader + MetadataWriter + 'static,
     PerEpochStore: PerEpochWriter + PerEpochReader + 'static,
 {
+    /// Spawn the network tasks of the networks left with work to do when the
+    /// node stopped.
+    ///
+    /// A network has work left if its latest proven certificate isn't settled
+    /// yet, or if it has certificates waiting in the pending queue. The
+    /// network task resumes the settlement of the unsettled certificate and
+    /// is then notified of the next pending certificate.
+    fn recover_network_tasks(&mut self) -> Result<(), Error> {
+        let mut proven = self.proven_certificates()?;
+
+        let mut networks: BTreeSet<NetworkId> = proven.keys().copied().collect();
+        networks.extend(
+            self.pending_store
+                .get_networks_with_pending_certificates()?,
+        );
+
+        for network_id in networks {
+            self.recover_network_task(network_id, proven.remove(&network_id))?;
+        }
+
+        Ok(())
+    }
+
+    /// The latest proven certificate of every network.
+    fn proven_certificates(&self) -> Result<BTreeMap<NetworkId, (CertificateId, Height)>, Error> {
+        Ok(self
+            .pending_store
+            .get_current_proven_height()?
+            .into_iter()
+            .map(|ProvenCertificate(certificate_id, network_id, height)| {
+                (network_id, (certificate_id, height))
+            })
+            .collect())
+    }
+
+    /// Spawn the network task of a network, resuming the settlement of its
+    /// latest proven certificate if it isn't settled yet.
+    fn recover_network_task(
+        &mut self,
+        network_id: NetworkId,
+        proven: Option<(CertificateId, Height)>,
+    ) -> Result<(), Error> {
+        let settled_height = self
+            .state_store
+            .get_latest_settled_certificate_per_network(&network_id)?
+            .map(|(_network_id, SettledCertificate(_, height, _, _))| height);
+
+        let mut next_height = settled_height.map_or(0, |height| height + 1);
+
+        // The certificates whose settlement was reverted are settled again first.
+        if let Some(height) = self
+            .recovered_certificates
+            .get(&network_id)
+            .and_then(|certificates| certificates.last())
+            .map(|certificate| certificate.height)
+        {
+            next_height = height + 1;
+        } else if let Some((certificate_id, height)) =
+            proven.filter(|(_, height)| Some(*height) > settled_height)
+        {
+            if let Some(certificate) = self.get_unsettled_certificate(&certificate_id)? {
+                info!(
+                    hash = certificate_id.to_string(),
+                    "Recovering the certificate {certificate_id} for network {network_id} at \
+                     height {height}"
+                );
+
+                // The settlement may still be in flight when a failed network task is
+                // restarted, the recovered task then awaits it instead of settling the
+                // certificate again.
+                if self.settlements_in_flight.contains(&certificate_id) {
+                    info!(
+                        hash = certificate_id.to_string(),
+                        "Settlement of the certificate {certificate_id} still in flight, the \
+                         recovered network task awaits it"
+                    );
+                }
+
+                self.recovered_certificates
+                    .insert(network_id, vec![certificate]);
+                next_height = height + 1;
+            } else {
+                warn!(
+                    hash = certificate_id.to_string(),
+                    "Unable to recover the certificate {certificate_id} for network {network_id} \
+                     at height {height}"
+                );
+            }
+        }
+
+        self.spawn_network_task(network_id)?;
+
+        if let Some(certificate) = self
+            .pending_store
+            .get_certificate(network_id, next_height)?
+        {
+            self.receive_certificates([(network_id, next_height, certificate.hash())])?;
+        }
+
+        Ok(())
+    }
+
+    /// Get a certificate which is proven, from the pending store, or candidate,
+    /// from the epoch it was added to.
+    fn get_unsettled_certificate(
+        &self,
+        certificate_id: &CertificateId,
+    ) -> Result<Option<Certificate>, Error> {
+        match self.state_store.get_certificate_header(certificate_id)? {
+            Some(CertificateHeader {
+                status: CertificateStatus::Proven,
+                network_id,
+                height,
+                ..
+            }) => Ok(self.pending_store.get_certificate(network_id, height)?),
+            Some(CertificateHeader {
+                status: CertificateStatus::Candidate,
+                epoch_number: Some(epoch_number),
+                certificate_index: Some(certificate_index),
+                ..
+            }) => Ok(self
+                .epochs_store
+                .get_certificate_in_epoch(epoch_number, certificate_index)?),
+            _ => Ok(None),
+        }
+    }
+
     fn spawn_network_task(&mut self, network_id: NetworkId) -> Result<(), Error> {
         if self.spawned_network_tasks.contains_key(&network_id) {
             debug!("Network task already spawned for network {}", network_id);
@@ -276,7 +522,12 @@ where
 
         let (sender, receiver) =
             mpsc::channel(Self::DEFAULT_CERTIFICATION_NOTIFICATION_CHANNEL_SIZE);
-        let task = NetworkTask::new(
+        let paused = self
+            .paused_networks
+            .entry(network_id)
+            .or_insert_with(|| watch::channel(false).0)
+            .subscribe();
+        let mut task = NetworkTask::new(
             self.pending_store.clone(),
             self.state_store.clone(),
             self.certifier_task_builder.clone(),
@@ -284,16 +535,137 @@ where
             self.clock_ref.clone(),
             network_id,
             receiver,
+            paused,
+            self.config
+                .max_certificates_per_epoch_for(*network_id)
+                .get(),
         )?;
 
-        self.network_tasks
-            .push(task.run(self.cancellation_token.clone()).boxed());
+        for certificate in self
+            .recovered_certificates
+            .remove(&network_id)
+            .unwrap_or_default()
+        {
+            task = task.with_recovered_certificate(certificate);
+        }
+        if let Some(ttl) = self.config.pending_certificate_ttl {
+            task = task.with_pending_certificate_ttl(ttl.get());
+        }
 
-        self.spawned_network_tasks.insert(network_id, sender);
+        let cancellation_token = self.cancellation_token.child_token();
+        let task_id = self.next_network_task_id;
+        self.next_network_task_id += 1;
+        self.network_tasks.push(
+            task.run(cancellation_token.clone())
+                .map(move |result| (network_id, task_id, result.map(|_| ())))
+                .boxed(),
+        );
+
+        self.spawned_network_tasks.insert(
+            network_id,
+            NetworkTaskHandle {
+                sender,
+                cancellation_token,
+                task_id,
+            },
+        );
+        self.supervisor.started(network_id);
 
         Ok(())
     }
 
+    /// Function that handles the end of a network task.
+    /// A network task stopped by an error is restarted after a backoff.
+    fn handle_network_task_end(
+        &mut self,
+        network_id: NetworkId,
+        task_id: u64,
+        result: Result<(), Error>,
+    ) {
+        // A network task replaced by a respawned one is no longer supervised.
+        if !self
+            .spawned_network_tasks
+            .get(&network_id)
+            .is_some_and(|task| task.task_id == task_id)
+        {
+            debug!("Replaced network task for {} completed", network_id);
+
+            return;
+        }
+        _ = self.spawned_network_tasks.remove(&network_id);
+
+        if let Some(response) = self.pending_respawns.remove(&network_id) {
+            if let Err(error) = result {
+                warn!(
+                    "Network task for {} failed while being respawned: {:?}",
+                    network_id, error
+                );
+            }
+            self.supervisor.stopped(network_id);
+
+            let result = self.respawn_network_task(network_id);
+            match response {
+                Some(response) => _ = response.send(result.map_err(|error| error.to_string())),
+                None => {
+                    if let Err(error) = result {
+                        self.handle_network_task_failure(network_id, error);
+                    }
+                }
+            }
+
+            return;
+        }
+
+        match result {
+            Ok(()) => {
+                warn!("Network task for {} completed successfully", network_id);
+                self.supervisor.stopped(network_id);
+            }
+            Err(error) => self.handle_network_task_failure(network_id, error),
+        }
+    }
+
+    fn handle_network_task_failure(&mut self, network_id: NetworkId, error: Error) {
+        error!("Network task for {} failed: {:?}", network_id, error);
+
+        match self.supervisor.failed(network_id, &error) {
+            Some(backoff) => {
+                warn!(
+                    "Restarting the network task for network {} in {:?}",
+                    network_id, backoff
+                );
+                self.restart_timers
+                    .push(tokio::time::sleep(backoff).map(move |_| network_id).boxed());
+            }
+            None => error!(
+                "Network task for network {} failed too many times, it is no longer restarted",
+                network_id
+            ),
+        }
+    }
+
+    /// Restart the network task of a network once its backoff elapsed.
+    fn restart_network_task(&mut self, network_id: NetworkId) {
+        // The network task may have been respawned from the admin interface in
+        // the meantime.
+        if !self.supervisor.is_backing_off(network_id) {
+            return;
+        }
+
+        info!("Restarting the network task for network {}", network_id);
+        if let Err(error) = self.respawn_network_task(network_id) {
+            self.handle_network_task_failure(network_id, error);
+        }
+    }
+
+    /// Spawn again the network task of a network, resuming the settlement of
+    /// its latest proven certificate and dispatching its next pending one.
+    fn respawn_network_task(&mut self, network_id: NetworkId) -> Result<(), Error> {
+        let mut proven = self.proven_certificates()?;
+
+        self.recover_network_task(network_id, proven.remove(&network_id))
+    }
+
     /// Function that receives the certificates cursor pushed by the RPC module.
     /// This function is responsible for:
     /// - Updating the cursors for the proofs that have been generated so far.
@@ -303,10 +675,30 @@ where
         cursors: impl IntoIterator<Item = (NetworkId, Height, CertificateId)>,
     ) -> Result<(), Error> {
         for (network_id, height, certificate_id) in cursors {
+            if self.supervisor.is_suspended(network_id) {
+                debug!(
+                    hash = certificate_id.to_string(),
+                    "Network task for network {network_id} not running, the certificate \
+                     {certificate_id} is picked up once it is restarted"
+                );
+
+                continue;
+            }
+
+            if self.is_network_paused(network_id) {
+                debug!(
+                    hash = certificate_id.to_string(),
+                    "Certification paused for network {network_id}, the certificate \
+                     {certificate_id} is picked up once it is resumed"
+                );
+
+                continue;
+            }
+
             self.spawn_network_task(network_id)?;
 
-            if let Some(sender) = self.spawned_network_tasks.get(&network_id) {
-                if let Ok(sender) = sender.try_reserve() {
+            if let Some(task) = self.spawned_network_tasks.get(&network_id) {
+                if let Ok(sender) = task.sender.try_reserve() {
                     sender.send(NewCertificate {
                         certificate_id,
                         height,
@@ -361,6 +753,130 @@ where
     }
 
     fn handle_epoch_packing_result(&mut self) {}
+
+    /// Function that handles a reorg of the L1.
+    /// The settlements mined from the first replaced L1 block onwards are
+    /// checked again against the L1.
+    fn handle_l1_reorg(&mut self, from_block: u64) {
+        warn!("L1 reorg detected from block {from_block}, checking the recent settlements");
+        L1_REORGS.add(1, &[]);
+
+        let task = self.epoch_packing_task_builder.clone();
+        self.reorg_tasks
+            .push(async move { task.recheck_settlements(from_block)?.await }.boxed());
+    }
+
+    /// Function that applies a command received from the admin interface.
+    fn handle_admin_command(&mut self, command: AdminCommand) {
+        match command {
+            AdminCommand::RespawnNetworkTask {
+                network_id,
+                response,
+            } => {
+                info!("Respawning the network task for network {}", network_id);
+
+                // A running task is cancelled and only replaced once completed, so that
+                // its certification and settlement in flight aren't carried out twice.
+                let Some(task) = self.spawned_network_tasks.get(&network_id) else {
+                    let result = self
+                        .respawn_network_task(network_id)
+                        .map_err(|error| error.to_string());
+
+                    _ = response.send(result);
+
+                    return;
+                };
+
+                // A respawn following an L1 reorg is answered to the admin request.
+                if self
+                    .pending_respawns
+                    .get(&network_id)
+                    .is_some_and(Option::is_some)
+                {
+                    _ = response.send(Err(format!(
+                        "The network task for network {network_id} is already being respawned"
+                    )));
+
+                    return;
+                }
+
+                task.cancellation_token.cancel();
+                self.pending_respawns.insert(network_id, Some(response));
+            }
+            AdminCommand::PauseNetwork {
+                network_id,
+                response,
+            } => {
+                info!("Pausing the certification for network {}", network_id);
+                let result = self
+                    .set_network_paused(network_id, true)
+                    .map_err(|error| error.to_string());
+
+                _ = response.send(result);
+            }
+            AdminCommand::ResumeNetwork {
+                network_id,
+                response,
+            } => {
+                info!("Resuming the certification for network {}", network_id);
+                let result = self
+                    .set_network_paused(network_id, false)
+                    .and_then(|_| self.dispatch_resumed_network(network_id))
+                    .map_err(|error| error.to_string());
+
+                _ = response.send(result);
+            }
+            AdminCommand::DumpState { response } => {
+                _ = response.send(OrchestratorState {
+                    spawned_network_tasks: self.spawned_network_tasks.keys().copied().collect(),
+                    paused_networks: self
+                        .paused_networks
+                        .iter()
+                        .filter(|(_, paused)| *paused.borrow())
+                        .map(|(network_id, _)| *network_id)
+                        .collect(),
+                    settlement_notifiers: self.settlement_notifier.keys().copied().collect(),
+                    network_tasks: self.supervisor.statuses(),
+                });
+            }
+        }
+    }
+
+    /// Pause or resume the certification of a network, the flag being
+    /// persisted to survive the restarts of the node.
+    fn set_network_paused(&mut self, network_id: NetworkId, paused: bool) -> Result<(), Error> {
+        self.state_store.set_network_paused(network_id, paused)?;
+
+        self.paused_networks
+            .entry(network_id)
+            .or_insert_with(|| watch::channel(false).0)
+            .send_replace(paused);
+
+        Ok(())
+    }
+
+    fn is_network_paused(&self, network_id: NetworkId) -> bool {
+        self.paused_networks
+            .get(&network_id)
+            .is_some_and(|paused| *paused.borrow())
+    }
+
+    /// Dispatch a resumed network whose network task isn't running, as the
+    /// certificates received while it was paused were not dispatched.
+    ///
+    /// A running network task picks up the next certificate by itself once
+    /// resumed.
+    fn dispatch_resumed_network(&mut self, network_id: NetworkId) -> Result<(), Error> {
+        if self.spawned_network_tasks.contains_key(&network_id)
+            || self.supervisor.is_suspended(network_id)
+        {
+            return Ok(());
+        }
+
+        let mut proven = self.proven_certificates()?;
+
+        self.recover_network_task(network_id, proven.remove(&network_id))
+    }
 }
 
 // This block contains the logic applied to a Certificate.
@@ -421,13 +937,19 @@ where
     CertifierClient: Certifier,
     E: EpochPacker<PerEpochStore = PerEpochStore> + 'static,
     PendingStore: PendingCertificateReader + PendingCertificateWriter,
-    StateStore: StateReader + StateWriter,
+    EpochsStore: EpochStoreWriter<PerEpochStore = PerEpochStore>,
+    StateStore: StateReader + StateWriter + MetadataReader + MetadataWriter,
     PerEpochStore: PerEpochWriter + PerEpochReader + 'static,
 {
     fn handle_settlement_result(
         &mut self,
-        settlement_result: Result<(NetworkId, SettledCertificate), Error>,
+        (settling_id, settlement_result): (
+            CertificateId,
+            Result<(NetworkId, SettledCertificate), Error>,
+        ),
     ) -> Result<(), ()> {
+        self.settlements_in_flight.remove(&settling_id);
+
         match settlement_result {
             Ok((
                 network_id,
@@ -444,36 +966,14 @@ where
                      height {height}",
                 );
 
-                if let Some(notifier) = self.settlement_notifier.remove(&certificate_id) {
-                    if notifier.send(Ok(settled)).is_err() {
-                        warn!(
-                            "Unable to notify the settlement of the certificate {}",
-                            certificate_id
-                        );
-                    }
-                } else {
-                    warn!("No notifier found for network {}", network_id);
-                }
+                self.notify_settlement(certificate_id, Ok(settled));
             }
             Err(Error::SettlementError {
                 certificate_id,
                 error,
             }) => {
                 error!("Error during certificate settlement: {:?}", error);
-                if let Some(notifier) = self.settlement_notifier.remove(&certificate_id) {
-                    if notifier.send(Err(error)).is_err() {
-                        warn!(
-                            hash = certificate_id.to_string(),
-                            "Unable to notify the settlement error of the certificate {}",
-                            certificate_id
-                        );
-                    }
-                } else {
-                    warn!(
-                        hash = certificate_id.to_string(),
-                        "No notifier found for network {}", certificate_id
-                    );
-                }
+                self.notify_settlement(certificate_id, Err(error));
             }
             Err(error) => {
                 error!("Error during certificate settlement: {:?}", error);
@@ -483,6 +983,32 @@ where
         Ok(())
     }
 
+    /// Notify the result of the settlement of a certificate to every network
+    /// task awaiting it.
+    fn notify_settlement(
+        &mut self,
+        certificate_id: CertificateId,
+        result: Result<SettledCertificate, String>,
+    ) {
+        let Some(notifiers) = self.settlement_notifier.remove(&certificate_id) else {
+            warn!(
+                hash = certificate_id.to_string(),
+                "No notifier found for the certificate {}", certificate_id
+            );
+
+            return;
+        };
+
+        for notifier in notifiers {
+            if notifier.send(result.clone()).is_err() {
+                warn!(
+                    hash = certificate_id.to_string(),
+                    "Unable to notify the settlement of the certificate {}", certificate_id
+                );
+            }
+        }
+    }
+
     fn handle_proven_certificate(
         &mut self,
         (response, ProvenCertificate(certificate_id, network, height)): (
@@ -490,21 +1016,163 @@ where
             ProvenCertificate,
         ),
     ) {
-        let current_epoch = self.current_epoch.load_full();
-        if self
-            .settlement_notifier
-            .insert(certificate_id, response)
-            .is_some()
-        {
+        self.settlement_notifier
+            .entry(certificate_id)
+            .or_default()
+            .push(response);
+
+        // A settlement requested again, by a network task respawned meanwhile, is
+        // awaited instead of being sent twice.
+        if self.settlements_in_flight.contains(&certificate_id) {
             warn!(
                 hash = certificate_id.to_string(),
-                "Notification channel already assigned for Certificate {certificate_id} ..."
+                "Settlement of the certificate {certificate_id} already in flight, awaiting it"
             );
+
+            return;
+        }
+
+        let current_epoch = self.current_epoch.load_full();
+
+        match self.state_store.get_certificate_header(&certificate_id) {
+            // A certificate recovered as candidate already belongs to an epoch, only its
+            // settlement is resumed.
+            Ok(Some(CertificateHeader {
+                status: CertificateStatus::Candidate,
+                epoch_number: Some(epoch_number),
+                certificate_index: Some(certificate_index),
+                ..
+            })) => {
+                self.resume_settlement(
+                    current_epoch,
+                    epoch_number,
+                    certificate_index,
+                    certificate_id,
+                );
+
+                return;
+            }
+            // The settlement requested by a network task which failed meanwhile may
+            // have completed before the recovered task requested it again.
+            Ok(Some(CertificateHeader {
+                status: CertificateStatus::Settled,
+                epoch_number: Some(epoch_number),
+                certificate_index: Some(certificate_index),
+                ..
+            })) => {
+                info!(
+                    hash = certificate_id.to_string(),
+                    "Certificate {certificate_id} already settled, notifying its settlement"
+                );
+                self.notify_settlement(
+                    certificate_id,
+                    Ok(SettledCertificate(
+                        certificate_id,
+                        height,
+                        epoch_number,
+                        certificate_index,
+                    )),
+                );
+
+                return;
+            }
+            _ => {}
         }
 
         self.try_adding_certificate(current_epoch, network, height, certificate_id);
     }
 
+    /// Settle again the certificates whose settlement vanished from the L1 in
+    /// a reorg.
+    ///
+    /// The local network state being rolled back with the settlements, the
+    /// network task of each network is respawned from the rolled back state
+    /// and recovers the reverted certificates in height order.
+    fn handle_reorged_settlements(&mut self, reorged: Vec<(NetworkId, SettledCertificate)>) {
+        let mut reverted: BTreeMap<NetworkId, BTreeMap<Height, CertificateId>> = BTreeMap::new();
+
+        for (network_id, SettledCertificate(certificate_id, height, _, _)) in reorged {
+            warn!(
+                hash = certificate_id.to_string(),
+                "Settlement of the certificate {certificate_id} for network {network_id} at \
+                 height {height} removed by an L1 reorg, settling it again"
+            );
+            SETTLEMENTS_REORGED.add(1, &[KeyValue::new("network_id", network_id.to_string())]);
+
+            reverted
+                .entry(network_id)
+                .or_default()
+                .insert(height, certificate_id);
+        }
+
+        for (network_id, certificates) in reverted {
+            let mut recovered = Vec::new();
+            for (height, certificate_id) in certificates {
+                match self.get_unsettled_certificate(&certificate_id) {
+                    Ok(Some(certificate)) => recovered.push(certificate),
+                    Ok(None) => warn!(
+                        hash = certificate_id.to_string(),
+                        "Unable to recover the certificate {certificate_id} for network \
+                         {network_id} at height {height}"
+                    ),
+                    Err(error) => error!(
+                        hash = certificate_id.to_string(),
+                        "Failed to recover the certificate {certificate_id} for network \
+                         {network_id} at height {height}: {:?}",
+                        error
+                    ),
+                }
+            }
+            self.recovered_certificates.insert(network_id, recovered);
+
+            // A running task is cancelled and only replaced once completed, the same
+            // way as a respawn requested from the admin interface.
+            match self.spawned_network_tasks.get(&network_id) {
+                Some(task) => {
+                    task.cancellation_token.cancel();
+                    self.pending_respawns.entry(network_id).or_insert(None);
+                }
+                None => {
+                    if let Err(error) = self.respawn_network_task(network_id) {
+                        self.handle_network_task_failure(network_id, error);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Settle a certificate already added to an epoch.
+    fn resume_settlement(
+        &mut self,
+        current_epoch: Arc<PerEpochStore>,
+        epoch_number: EpochNumber,
+        certificate_index: CertificateIndex,
+        certificate_id: CertificateId,
+    ) {
+        let related_epoch = if current_epoch.get_epoch_number() == epoch_number {
+            Ok(current_epoch)
+        } else {
+            self.epochs_store.open(epoch_number).map(Arc::new)
+        };
+
+        let result = match related_epoch {
+            Ok(related_epoch) => {
+                self.settle_certificate(related_epoch, certificate_index, certificate_id)
+            }
+            Err(error) => Err(error.into()),
+        };
+
+        if let Err(error) = result {
+            error!(
+                hash = certificate_id.to_string(),
+                "Failed to resume the settlement of the certificate {} in epoch {}: {:?}",
+                certificate_id,
+                epoch_number,
+                error
+            );
+        }
+    }
+
     /// Try to add a certificate to the current epoch and settle it on L1.
     fn try_adding_certificate(
         &mut self,
@@ -541,6 +1209,15 @@ where
         certificate_index: CertificateIndex,
         certificate_id: CertificateId,
     ) -> Result<(), Error> {
+        if !self.settlements_in_flight.insert(certificate_id) {
+            warn!(
+                hash = certificate_id.to_string(),
+                "Settlement of the certificate {certificate_id} already in flight"
+            );
+
+            return Ok(());
+        }
+
         debug!(
             hash = certificate_id.to_string(),
             "Settling the certificate {certificate_id}"
@@ -552,6 +1229,7 @@ where
                 task.settle_certificate(related_epoch, certificate_index, certificate_id)?
                     .await
             }
+            .map(move |result| (certificate_id, result))
             .boxed(),
         );
 
@@ -559,6 +1237,128 @@ where
     }
 }
 
+impl<E, CertifierClient, PendingStore, EpochsStore, PerEpochStore, StateStore>
+    CertificateOrchestrator<
+        E,
+        CertifierClient,
+        PendingStore,
+        EpochsStore,
+        PerEpochStore,
+        StateStore,
+    >
+where
+    CertifierClient: Certifier,
+    E: EpochPacker<PerEpochStore = PerEpochStore>,
+    PendingStore: PendingCertificateReader + PendingCertificateWriter + 'static,
+    EpochsStore: EpochStoreWriter<PerEpochStore = PerEpochStore> + EpochStoreReader + 'static,
+    StateStore: StateReader + StateWriter + MetadataReader + MetadataWriter + 'static,
+    PerEpochStore: PerEpochWriter + PerEpochReader + 'static,
+{
+    /// Poll the orchestrator once cancelled.
+    ///
+    /// No certificate is received nor settled anymore: the network tasks
+    /// complete their certification in flight and the settlements in flight
+    /// are awaited, until everything completed or the drain deadline elapsed.
+    fn poll_drain(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        loop {
+            let mut progress = false;
+
+            // The certificates proven while draining stay proven, their settlement is
+            // resumed once the node restarts. The notifier is kept, so that the network
+            // task doesn't take the certificate for a failed settlement.
+            if let Poll::Ready(Some((
+                response,
+                ProvenCertificate(certificate_id, network_id, height),
+            ))) = self.certification_notification.poll_recv(cx)
+            {
+                info!(
+                    hash = certificate_id.to_string(),
+                    "Certificate {certificate_id} for network {network_id} at height {height} \
+                     proven while draining, its settlement is resumed on restart"
+                );
+                self.deferred_settlements.insert(network_id, response);
+                progress = true;
+            }
+
+            if let Poll::Ready(Some((network_id, task_id, result))) =
+                self.network_tasks.poll_next_unpin(cx)
+            {
+                if self
+                    .spawned_network_tasks
+                    .get(&network_id)
+                    .is_some_and(|task| task.task_id == task_id)
+                {
+                    _ = self.spawned_network_tasks.remove(&network_id);
+                    self.supervisor.stopped(network_id);
+                }
+
+                if let Err(error) = result {
+                    error!(
+                        "Network task for {} failed while draining: {:?}",
+                        network_id, error
+                    );
+                }
+                progress = true;
+            }
+
+            if let Poll::Ready(Some(settlement_result)) = self.settlement_tasks.poll_next_unpin(cx)
+            {
+                debug!("Certificate settlement task completed while draining");
+                _ = self.handle_settlement_result(settlement_result);
+                progress = true;
+            }
+
+            // The network tasks awaiting a deferred settlement are only stopped with
+            // the orchestrator.
+            let network_tasks_drained = self
+                .spawned_network_tasks
+                .keys()
+                .all(|network_id| self.deferred_settlements.contains_key(network_id));
+            if network_tasks_drained && self.settlement_tasks.is_empty() {
+                info!("Certificate orchestrator drained");
+
+                return Poll::Ready(());
+            }
+
+            if !progress {
+                break;
+            }
+        }
+
+        if let Some(deadline) = self.drain_deadline.as_mut() {
+            if deadline.as_mut().poll(cx).is_ready() {
+                self.report_abandoned();
+
+                return Poll::Ready(());
+            }
+        }
+
+        Poll::Pending
+    }
+
+    /// Report the work in flight abandoned when the drain deadline elapsed.
+    fn report_abandoned(&self) {
+        let networks = self
+            .spawned_network_tasks
+            .keys()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let certificates = self
+            .settlement_notifier
+            .keys()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        warn!(
+            "Drain deadline elapsed, abandoning the network tasks of the networks [{}] and {} \
+             settlement(s) in flight, awaited for the certificates [{}]",
+            networks.join(", "),
+            self.settlement_tasks.len(),
+            certificates.join(", ")
+        );
+    }
+}
+
 impl<E, A, PendingStore, EpochsStore, PerEpochStore, StateStore> Future
     for CertificateOrchestrator<E, A, PendingStore, EpochsStore, PerEpochStore, StateStore>
 where
@@ -566,17 +1366,31 @@ where
     E: EpochPacker<PerEpochStore = PerEpochStore>,
     PendingStore: PendingCertificateReader + PendingCertificateWriter + 'static,
     EpochsStore: EpochStoreWriter<PerEpochStore = PerEpochStore> + EpochStoreReader + 'static,
-    StateStore: StateReader + StateWriter + 'static,
+    StateStore: StateReader + StateWriter + MetadataReader + MetadataWriter + 'static,
     PerEpochStore: PerEpochWriter + PerEpochReader + 'static,
 {
     type Output = ();
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         // Check if the orchestrator has been cancelled and should shutdown.
-        if self.cancellation_token_future.as_mut().poll(cx).is_ready() {
+        if self.drain_deadline.is_none()
+            && self.cancellation_token_future.as_mut().poll(cx).is_ready()
+        {
             debug!("Certificate orchestrator cancelled by token");
 
-            return Poll::Ready(());
+            if self.drain_timeout.is_zero() {
+                return Poll::Ready(());
+            }
+
+            info!(
+                "Draining the certifications and settlements in flight for at most {:?}",
+                self.drain_timeout
+            );
+            self.drain_deadline = Some(Box::pin(tokio::time::sleep(self.drain_timeout)));
+        }
+
+        if self.drain_deadline.is_some() {
+            return self.poll_drain(cx);
         }
 
         match self.certification_notification.poll_recv(cx) {
@@ -587,22 +1401,19 @@ where
 
         // Poll the notification tasks to check for
         match self.network_tasks.poll_next_unpin(cx) {
-            Poll::Ready(Some(Ok(network_id))) => {
-                warn!("Network task for {} completed successfully", network_id);
-                _ = self.spawned_network_tasks.remove(&network_id);
-            }
-
-            Poll::Ready(Some(Err(error))) => {
-                warn!(
-                    "Network task Critical error during p-proof generation: {:?}",
-                    error
-                );
-                // TODO: Need to find a way to remove the task
+            Poll::Ready(Some((network_id, task_id, result))) => {
+                self.handle_network_task_end(network_id, task_id, result);
             }
             Poll::Ready(None) => {}
             Poll::Pending => {}
         }
 
+        if let Poll::Ready(Some(network_id)) = self.restart_timers.poll_next_unpin(cx) {
+            self.restart_network_task(network_id);
+
+            return self.poll(cx);
+        }
+
         match self.settlement_tasks.poll_next_unpin(cx) {
             Poll::Ready(Some(settlement_result)) => {
                 debug!("Certificate settlement task completed");
@@ -612,6 +1423,22 @@ where
             Poll::Pending => {}
         }
 
+        match self.reorg_tasks.poll_next_unpin(cx) {
+            Poll::Ready(Some(Ok(reorged))) => {
+                self.handle_reorged_settlements(reorged);
+
+                return self.poll(cx);
+            }
+            Poll::Ready(Some(Err(error))) => {
+                error!(
+                    "Failed to check the settlements after an L1 reorg: {:?}",
+                    error
+                )
+            }
+            Poll::Ready(None) => {}
+            Poll::Pending => {}
+        }
+
         // Poll the notification tasks to check if any have errored.
         match self.epoch_packing_tasks.poll_next_unpin(cx) {
             Poll::Ready(Some(Err(error))) => {
@@ -637,14 +1464,32 @@ where
             return self.poll(cx);
         }
 
-        if let Poll::Ready(Some(Event::EpochEnded(epoch))) = self.clock.poll_next_unpin(cx) {
-            debug!("Epoch change event received: {}", epoch);
+        let admin_command = match self.admin_receiver.as_mut() {
+            Some(admin_receiver) => admin_receiver.poll_recv(cx),
+            None => Poll::Pending,
+        };
+        if let Poll::Ready(Some(command)) = admin_command {
+            self.handle_admin_command(command);
+
+            return self.poll(cx);
+        }
+
+        match self.clock.poll_next_unpin(cx) {
+            Poll::Ready(Some(Event::EpochEnded(epoch))) => {
+                debug!("Epoch change event received: {}", epoch);
+
+                if let Err(error) = self.handle_epoch_end(epoch) {
+                    error!("Failed to handle the EpochEnded event: {:?}", error);
+                }
 
-            if let Err(error) = self.handle_epoch_end(epoch) {
-                error!("Failed to handle the EpochEnded event: {:?}", error);
+                return self.poll(cx);
             }
+            Poll::Ready(Some(Event::L1Reorg(from_block))) => {
+                self.handle_l1_reorg(from_block);
 
-            return self.poll(cx);
+                return self.poll(cx);
+            }
+            Poll::Ready(None) | Poll::Pending => {}
         }
 
         Poll::Pending
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::VecDeque, sync::Arc};
 
 use agglayer_clock::ClockRef;
 use agglayer_storage::{
@@ -9,10 +9,11 @@ use agglayer_storage::{
     stores::{PendingCertificateReader, PendingCertificateWriter, StateReader, StateWriter},
 };
 use agglayer_types::{
-    Certificate, CertificateId, CertificateStatus, CertificateStatusError, Hash, Height,
-    LocalNetworkStateData, NetworkId,
+    Certificate, CertificateId, CertificateStatus, CertificateStatusError, EpochNumber, Hash,
+    Height, LocalNetworkStateData, NetworkId,
 };
-use tokio::sync::{mpsc, oneshot};
+use futures_util::future::OptionFuture;
+use tokio::sync::{mpsc, oneshot, watch};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 
@@ -25,6 +26,19 @@ pub(crate) struct NewCertificate {
     pub(crate) height: Height,
 }
 
+/// Maximum number of certificates of a network proven ahead, while the
+/// previous certificate of the network is settling.
+const MAX_SPECULATIVE_CERTIFICATES: usize = 1;
+
+/// Certificate which settlement has been requested to the orchestrator.
+struct Settling {
+    /// The output of the certification, which new local state is applied
+    /// once the certificate is settled.
+    output: CertifierOutput,
+    /// The receiver of the settlement result.
+    receiver: oneshot::Receiver<Result<SettledCertificate, String>>,
+}
+
 /// Network task that is responsible to certify the certificates for a network.
 pub(crate) struct NetworkTask<CertifierClient, PendingStore, StateStore> {
     /// The network id for the network task.
@@ -45,13 +59,31 @@ pub(crate) struct NetworkTask<CertifierClient, PendingStore, StateStore> {
     /// The clock reference to subscribe to the epoch events and check for
     /// current epoch.
     clock_ref: ClockRef,
-    /// The pending local network state that should be applied on receiving
-    /// settlement response.
-    pending_state: Option<LocalNetworkStateData>,
+    /// The certificate waiting for its settlement.
+    settling: Option<Settling>,
+    /// The certificates proven on top of the settling one, in height order,
+    /// waiting for their settlement to be requested.
+    speculative: VecDeque<CertifierOutput>,
     /// The stream of new certificates to certify.
     certificate_stream: mpsc::Receiver<NewCertificate>,
-    /// Flag to indicate if the network is at capacity for the current epoch.
-    at_capacity_for_epoch: bool,
+    /// Number of certificates of the network proven during the current epoch.
+    certificates_in_epoch: u64,
+    /// Maximum number of certificates of the network per epoch.
+    max_certificates_per_epoch: u64,
+    /// Flag set by the orchestrator while the certification of the network is
+    /// paused.
+    paused: watch::Receiver<bool>,
+    /// Certificates proven or candidate but not yet settled when the node
+    /// stopped, or whose settlement was reverted by an L1 reorg, in height
+    /// order. Their settlement is resumed one after the other when the task
+    /// starts.
+    recovered_certificates: VecDeque<Certificate>,
+    /// Number of epochs after which a pending certificate of the network is
+    /// expired, if any.
+    pending_certificate_ttl: Option<u64>,
+    /// Cancellation token of the running task, no settlement being requested
+    /// once cancelled.
+    cancellation_token: CancellationToken,
 }
 
 impl<CertifierClient, PendingStore, StateStore>
@@ -73,6 +105,8 @@ where
         clock_ref: ClockRef,
         network_id: NetworkId,
         certificate_stream: mpsc::Receiver<NewCertificate>,
+        paused: watch::Receiver<bool>,
+        max_certificates_per_epoch: u64,
     ) -> Result<Self, Error> {
         info!("Creating a new network task for network {}", network_id);
 
@@ -93,17 +127,89 @@ where
             local_state,
             certification_notifier,
             clock_ref,
-            pending_state: None,
+            settling: None,
+            speculative: VecDeque::new(),
             certificate_stream,
-            at_capacity_for_epoch: false,
+            certificates_in_epoch: 0,
+            max_certificates_per_epoch,
+            paused,
+            recovered_certificates: VecDeque::new(),
+            pending_certificate_ttl: None,
+            cancellation_token: CancellationToken::new(),
         })
     }
 
+    /// Resume the settlement of a certificate proven or candidate before the
+    /// node stopped, after the ones already recovered.
+    pub(crate) fn with_recovered_certificate(mut self, certificate: Certificate) -> Self {
+        self.recovered_certificates.push_back(certificate);
+        self
+    }
+
+    /// Expire the pending certificates of the network after the given number
+    /// of epochs.
+    pub(crate) fn with_pending_certificate_ttl(mut self, ttl: u64) -> Self {
+        self.pending_certificate_ttl = Some(ttl);
+        self
+    }
+
+    /// Whether the network reached its number of certificates for the current
+    /// epoch.
+    fn at_capacity_for_epoch(&self) -> bool {
+        self.certificates_in_epoch >= self.max_certificates_per_epoch
+    }
+
+    /// Whether a new certificate can be proven on top of the certificates not
+    /// yet settled.
+    fn can_certify(&self) -> bool {
+        self.speculative.len() < MAX_SPECULATIVE_CERTIFICATES
+    }
+
+    /// The local state resulting from the latest certificate proven, on top
+    /// of which the next certificate is proven.
+    fn latest_state(&self) -> &LocalNetworkStateData {
+        self.speculative
+            .back()
+            .or(self.settling.as_ref().map(|settling| &settling.output))
+            .map_or(&self.local_state, |output| &output.new_state)
+    }
+
+    /// Count the certificates of the network settled in an epoch, walking back
+    /// from the latest settled height.
+    fn settled_certificates_in_epoch(
+        &self,
+        epoch: EpochNumber,
+        latest_settled_height: Height,
+    ) -> Result<u64, Error> {
+        let mut count = 0;
+        let mut height = Some(latest_settled_height);
+
+        while let Some(current_height) = height {
+            if count >= self.max_certificates_per_epoch {
+                break;
+            }
+
+            match self
+                .state_store
+                .get_certificate_header_by_cursor(self.network_id, current_height)?
+            {
+                Some(header) if header.epoch_number == Some(epoch) => {
+                    count += 1;
+                    height = current_height.checked_sub(1);
+                }
+                _ => break,
+            }
+        }
+
+        Ok(count)
+    }
+
     pub(crate) async fn run(
         mut self,
         cancellation_token: CancellationToken,
     ) -> Result<NetworkId, Error> {
         info!("Starting the network task for network {}", self.network_id);
+        self.cancellation_token = cancellation_token.clone();
 
         let mut stream_epoch = self.clock_ref.subscribe()?;
 
@@ -119,8 +225,12 @@ where
             if let Some(SettledCertificate(_, current_height, epoch, _)) = latest_settled {
                 debug!("Current network height is {}", current_height);
                 if epoch == current_epoch {
-                    debug!("Already settled for the epoch {current_epoch}");
-                    self.at_capacity_for_epoch = true;
+                    self.certificates_in_epoch =
+                        self.settled_certificates_in_epoch(current_epoch, current_height)?;
+                    debug!(
+                        "Already settled {} certificate(s) for the epoch {current_epoch}",
+                        self.certificates_in_epoch
+                    );
                 }
 
                 current_height + 1
@@ -129,44 +239,142 @@ where
                 0
             };
 
-        loop {
+        if let Some(certificate) = self.recovered_certificates.pop_front() {
             tokio::select! {
                 _ = cancellation_token.cancelled() => {
                     debug!("Network task for network {} has been cancelled", self.network_id);
                     return Ok(self.network_id);
                 }
 
-                result = self.make_progress(&mut stream_epoch, &mut next_expected_height) => {
-                    if let Err(error)= result {
-                        error!("Error during the certification process: {}", error);
+                result = self.recover_certificate(certificate, &mut next_expected_height) => {
+                    if let Err(error) = result {
+                        error!("Error during the recovery of the certification process: {}", error);
 
                         return Err(error)
                     }
                 }
             }
         }
+
+        loop {
+            // Once cancelled, the task only waits for the settlement of its settling
+            // certificate, so that the new local state of the network is persisted.
+            let draining = cancellation_token.is_cancelled();
+            if draining && self.settling.is_none() {
+                debug!(
+                    "Network task for network {} has been cancelled",
+                    self.network_id
+                );
+                return Ok(self.network_id);
+            }
+
+            let height = tokio::select! {
+                _ = cancellation_token.cancelled(), if !draining => continue,
+
+                result = self.next_height(&mut stream_epoch, &mut next_expected_height, draining) => {
+                    match result {
+                        Ok(Some(height)) => height,
+                        Ok(None) => continue,
+                        Err(error) => {
+                            error!("Error during the certification process: {}", error);
+
+                            return Err(error)
+                        }
+                    }
+                }
+            };
+
+            // A certification started is carried out even if the task is cancelled
+            // meanwhile, so that its proof isn't lost.
+            if let Err(error) = self.certify(height, &mut next_expected_height).await {
+                error!("Error during the certification process: {}", error);
+
+                return Err(error);
+            }
+        }
     }
 
+    #[cfg(test)]
     async fn make_progress(
         &mut self,
         stream_epoch: &mut tokio::sync::broadcast::Receiver<agglayer_clock::Event>,
         next_expected_height: &mut u64,
     ) -> Result<(), Error> {
+        match self
+            .next_height(stream_epoch, next_expected_height, false)
+            .await?
+        {
+            Some(height) => self.certify(height, next_expected_height).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Wait for the next height of the network to certify, handling the
+    /// settlement, epoch and pause events meanwhile.
+    ///
+    /// While draining, only the settlement of the settling certificate is
+    /// awaited.
+    async fn next_height(
+        &mut self,
+        stream_epoch: &mut tokio::sync::broadcast::Receiver<agglayer_clock::Event>,
+        next_expected_height: &mut u64,
+        draining: bool,
+    ) -> Result<Option<Height>, Error> {
+        let paused = *self.paused.borrow();
+        let can_certify = self.can_certify();
+        let settlement = OptionFuture::from(
+            self.settling
+                .as_mut()
+                .map(|settling| &mut settling.receiver),
+        );
         let height = tokio::select! {
-            Ok(agglayer_clock::Event::EpochEnded(epoch)) = stream_epoch.recv() => {
+            Some(result) = settlement => {
+                if let Err(error) = self.on_settlement(result, next_expected_height).await {
+                    error!(
+                        "Error during the settlement process for network {}: {:?}",
+                        self.network_id,
+                        error
+                    );
+                }
+
+                return Ok(None);
+            }
+            Ok(event) = stream_epoch.recv(), if !draining => {
+                let agglayer_clock::Event::EpochEnded(epoch) = event else {
+                    return Ok(None);
+                };
                 info!("Received an epoch event: {}", epoch);
 
                 let current_epoch = self.clock_ref.current_epoch();
                 if epoch != 0 && epoch < (current_epoch - 1) {
                     debug!("Received an epoch event for epoch {epoch} which is outdated, current epoch is {current_epoch}");
 
-                    return Ok(());
+                    return Ok(None);
+                }
+
+                if let Err(error) = self.expire_pending_certificates(epoch) {
+                    error!(
+                        "Failed to expire the pending certificates of network {}: {:?}",
+                        self.network_id,
+                        error
+                    );
+                }
+
+                self.certificates_in_epoch = 0;
+                if paused {
+                    debug!("Certification is paused for network {}", self.network_id);
+
+                    return Ok(None);
+                }
+
+                self.request_next_settlement().await;
+                if !self.can_certify() {
+                    return Ok(None);
                 }
 
-                self.at_capacity_for_epoch = false;
                 *next_expected_height
             }
-            Some(NewCertificate { certificate_id, height, .. }) = self.certificate_stream.recv(), if !self.at_capacity_for_epoch => {
+            Some(NewCertificate { certificate_id, height, .. }) = self.certificate_stream.recv(), if can_certify && !paused && !draining => {
                 info!(
                     hash = certificate_id.to_string(),
                     "Received a certificate event for {certificate_id} at height {height}"
@@ -177,16 +385,38 @@ where
                         hash = certificate_id.to_string(),
                         "Received a certificate event for the wrong height");
 
-                    return Ok(());
+                    return Ok(None);
+                }
+
+                *next_expected_height
+            }
+            Ok(()) = self.paused.changed(), if !draining => {
+                if *self.paused.borrow() {
+                    info!("Certification paused for network {}", self.network_id);
+
+                    return Ok(None);
+                }
+
+                info!("Certification resumed for network {}", self.network_id);
+                self.request_next_settlement().await;
+                if !self.can_certify() {
+                    return Ok(None);
                 }
 
                 *next_expected_height
             }
-            // Need to implement the cancellation token
-            // _ = cancellation_token.cancelled() => {
-            //     break;
-            // }
         };
+
+        Ok(Some(height))
+    }
+
+    /// Certify the certificate of the network at the given height and request
+    /// its settlement.
+    async fn certify(
+        &mut self,
+        height: Height,
+        next_expected_height: &mut u64,
+    ) -> Result<(), Error> {
         // Get the certificate the pending certificate for the network at the height
         let certificate = if let Some(certificate) = self
             .pending_store
@@ -219,15 +449,9 @@ where
             // It also means that the proof exists and thus we should redo the native
             // execution to update the local state.
             CertificateStatus::Proven | CertificateStatus::Candidate => {
-                // Redo native execution to get the new_state
-
-                error!(
-                    hash = certificate_id.to_string(),
-                    "CRITICAL: Certificate {certificate_id} is already proven or candidate but we \
-                     do not have the new_state anymore...",
-                );
-
-                return Ok(());
+                return self
+                    .recover_certificate(certificate, next_expected_height)
+                    .await;
             }
             CertificateStatus::InError { error } => {
                 warn!(
@@ -257,79 +481,69 @@ where
             height
         );
 
-        let result =
-            match self
-                .certifier_client
-                .certify(self.local_state.clone(), self.network_id, height)
-            {
-                Ok(certifier_task) => certifier_task.await,
-
-                // If we received a `CertificateNotFound` error, it means that the certificate was
-                // not found in the pending store. This can happen if we try to
-                // certify a certificate that has not been received yet. When
-                // received, the certificate will be stored in the pending store and
-                // the certifier task will be spawned again.
-                Err(PreCertificationError::CertificateNotFound(_network_id, _height)) => {
-                    return Ok(());
-                }
+        let result = match self.certifier_client.certify(
+            self.latest_state().clone(),
+            self.network_id,
+            height,
+        ) {
+            Ok(certifier_task) => certifier_task.await,
+
+            // If we received a `CertificateNotFound` error, it means that the certificate was
+            // not found in the pending store. This can happen if we try to
+            // certify a certificate that has not been received yet. When
+            // received, the certificate will be stored in the pending store and
+            // the certifier task will be spawned again.
+            Err(PreCertificationError::CertificateNotFound(_network_id, _height)) => {
+                return Ok(());
+            }
 
-                Err(PreCertificationError::ProofAlreadyExists(
+            // The certificate is still pending, the proof is a leftover of a certification
+            // done ahead of a settlement which never happened. The proof is removed so that
+            // the certificate is certified again on the next trigger.
+            Err(PreCertificationError::ProofAlreadyExists(network_id, height, certificate_id)) => {
+                warn!(
+                    hash = certificate_id.to_string(),
+                    "Received a proof certification error for a proof that already exists for \
+                     network {} at height {}, removing the proof",
                     network_id,
-                    height,
-                    certificate_id,
-                )) => {
-                    warn!(
-                        hash = certificate_id.to_string(),
-                        "Received a proof certification error for a proof that already exists for \
-                         network {} at height {}",
-                        network_id,
-                        height
-                    );
+                    height
+                );
 
-                    return Ok(());
-                }
-                Err(PreCertificationError::Storage(error)) => {
-                    warn!(
+                if let Err(error) = self.pending_store.remove_generated_proof(&certificate_id) {
+                    error!(
                         hash = certificate_id.to_string(),
-                        "Received a storage error while trying to certify the certificate for \
-                         network {} at height {}: {:?}",
-                        self.network_id,
-                        height,
-                        error
+                        "Failed to remove the generated proof of {certificate_id}: {:?}", error
                     );
-
-                    return Ok(());
                 }
-            };
+
+                return Ok(());
+            }
+            Err(PreCertificationError::Storage(error)) => {
+                warn!(
+                    hash = certificate_id.to_string(),
+                    "Received a storage error while trying to certify the certificate for network \
+                     {} at height {}: {:?}",
+                    self.network_id,
+                    height,
+                    error
+                );
+
+                return Ok(());
+            }
+        };
 
         match result {
-            Ok(CertifierOutput {
-                height,
-                certificate,
-                new_state,
-                ..
-            }) => {
+            Ok(output) => {
                 debug!(
                     hash = certificate_id.to_string(),
                     "Proof certification completed for {certificate_id} for network {}",
                     self.network_id
                 );
-                if let Err(error) = self
-                    .on_proven_certificate(height, certificate, new_state)
-                    .await
-                {
-                    error!(
-                        hash = certificate_id.to_string(),
-                        "Error during the certification process of {certificate_id} for network \
-                         {}: {:?}",
-                        self.network_id,
-                        error
-                    );
-                }
 
                 *next_expected_height += 1;
+                self.speculative.push_back(output);
+                self.request_next_settlement().await;
 
-                self.at_capacity_for_epoch = true;
                 debug!(
                     hash = certificate_id.to_string(),
                     "Certification process completed for {certificate_id} for network {}",
@@ -344,248 +558,513 @@ where
                     hash = certificate_id.to_string(),
                     "Error during certification process of {certificate_id}: {}", error
                 );
-                let error: CertificateStatusError = match error {
-                    CertificationError::TrustedSequencerNotFound(network) => {
-                        CertificateStatusError::TrustedSequencerNotFound(network)
-                    }
-                    CertificationError::ProofVerificationFailed { source } => source.into(),
-                    CertificationError::L1InfoRootNotFound(_certificate_id, l1_leaf_count) => {
-                        CertificateStatusError::L1InfoRootNotFound(l1_leaf_count)
-                    }
-
-                    CertificationError::ProverExecutionFailed { source } => {
-                        CertificateStatusError::ProofGenerationError {
-                            generation_type: agglayer_types::GenerationType::Prover,
-                            source,
-                        }
-                    }
-                    CertificationError::NativeExecutionFailed { source } => {
-                        CertificateStatusError::ProofGenerationError {
-                            generation_type: agglayer_types::GenerationType::Native,
-                            source,
-                        }
-                    }
-
-                    CertificationError::Types { source } => source.into(),
-
-                    CertificationError::Storage(error) => {
-                        let error = format!(
-                            "Storage error happened in the certification process of \
-                             {certificate_id}: {:?}",
-                            error
-                        );
-                        warn!(hash = certificate_id.to_string(), error);
-
-                        CertificateStatusError::InternalError(error)
-                    }
-                    CertificationError::Serialize { source } => {
-                        let error = format!(
-                            "Serialization error happened in the certification process of \
-                             {certificate_id}: {:?}",
-                            source
-                        );
-                        warn!(hash = certificate_id.to_string(), error);
-
-                        CertificateStatusError::InternalError(error)
-                    }
-                    CertificationError::Deserialize { source } => {
-                        let error = format!(
-                            "Deserialization error happened in the certification process of \
-                             {certificate_id}: {:?}",
-                            source
-                        );
-                        warn!(hash = certificate_id.to_string(), error);
-                        CertificateStatusError::InternalError(error)
-                    }
-                    CertificationError::InternalError(error) => {
-                        let error = format!(
-                            "Internal error happened in the certification process of \
-                             {certificate_id}: {}",
-                            error
-                        );
-                        warn!(hash = certificate_id.to_string(), error);
-
-                        CertificateStatusError::InternalError(error)
-                    }
-                };
+                self.set_in_error(certificate_id, error);
 
-                if self
-                    .state_store
-                    .update_certificate_header_status(
-                        &certificate_id,
-                        &CertificateStatus::InError { error },
-                    )
-                    .is_err()
-                {
-                    error!(
-                        hash = certificate_id.to_string(),
-                        "Certificate {certificate_id} in error and failed to update the \
-                         certificate header status"
-                    );
-                }
                 Ok(())
             }
         }
     }
-}
 
-impl<CertifierClient, PendingStore, StateStore>
-    NetworkTask<CertifierClient, PendingStore, StateStore>
-where
-    CertifierClient: Certifier,
-    PendingStore: PendingCertificateReader + PendingCertificateWriter,
-    StateStore: StateWriter,
-{
-    /// Context:
-    ///
-    /// At one point in time, there is at most one certifier task per network
-    /// running. The certifier task try to generate a proof based on
-    /// a certificate. The certifier task doesn't know about other tasks nor if
-    /// the certificate will be included in an epoch. The Orchestrator is
-    /// the one that is responsible to decide if a proof is valid and should
-    /// be included in an epoch.
-    ///
-    /// Based on the current context of the orchestrator, we can
-    /// determine the following:
-    ///
-    /// 1. If the state doesn't know the network and the height is 0, we update
-    ///    the state. This is the first certificate for this network.
-    /// 2. If the state knows the network and the height is the next one, we
-    ///    update the state. This is the next certificate for this network.
-    /// 3. If the state doesn't know the network and the height is not 0, we
-    ///    ignore the proof.
-    /// 4. If the state knows the network and the height is not the next
-    ///    expected one, we ignore the proof.
+    /// Resume the settlement of a certificate which was proven or candidate
+    /// when the node stopped.
     ///
-    /// When a generated proof is accepted:
-    /// - We update the cursor for the network.
-    /// - We update the latest proven certificate for the network.
-    /// - We do not remove the pending certificate. (as it needs to be included
-    ///   in an epoch)
-    /// - We spawn the next certificate for the network.
-    async fn on_proven_certificate(
+    /// The new local state of the network is only persisted once the
+    /// certificate is settled, it is rebuilt by executing natively the
+    /// certificate again on top of the current local state.
+    async fn recover_certificate(
         &mut self,
-        height: Height,
         certificate: Certificate,
-        new_state: LocalNetworkStateData,
+        next_expected_height: &mut u64,
     ) -> Result<(), Error> {
         let certificate_id = certificate.hash();
-        if let Err(error) = self
-            .pending_store
-            .set_latest_proven_certificate_per_network(&self.network_id, &height, &certificate_id)
-        {
-            error!(
+        let height = certificate.height;
+
+        if height != *next_expected_height {
+            warn!(
                 hash = certificate_id.to_string(),
-                "Failed to set the latest proven certificate per network: {:?}", error
+                "Unable to recover the certificate {certificate_id} at height {height} for \
+                 network {}, the expected height is {next_expected_height}",
+                self.network_id
             );
+
+            return Ok(());
         }
 
-        if let Err(error) = self
-            .state_store
-            .update_certificate_header_status(&certificate_id, &CertificateStatus::Proven)
-        {
-            error!(
+        let header =
+            if let Some(header) = self.state_store.get_certificate_header(&certificate_id)? {
+                header
+            } else {
+                error!(
+                    hash = certificate_id.to_string(),
+                    "Certificate header not found for {certificate_id}"
+                );
+
+                return Ok(());
+            };
+
+        if !matches!(
+            header.status,
+            CertificateStatus::Proven | CertificateStatus::Candidate
+        ) {
+            warn!(
                 hash = certificate_id.to_string(),
-                "Failed to update the certificate header status: {:?}", error
+                "Certificate {certificate_id} is {} and can't be recovered", header.status
             );
-        }
 
-        self.pending_state = Some(new_state);
+            return Ok(());
+        }
 
-        let (sender, receiver) = oneshot::channel();
+        info!(
+            hash = certificate_id.to_string(),
+            "Recovering the {} certificate {certificate_id} for network {} at height {height}",
+            header.status,
+            self.network_id
+        );
 
-        if self
-            .certification_notifier
-            .send((
-                sender,
-                ProvenCertificate(certificate_id, self.network_id, height),
-            ))
+        let new_state = match self
+            .certifier_client
+            .execute_natively(self.local_state.clone(), certificate.clone())
             .await
-            .is_err()
         {
-            error!("Failed to send the proven certificate notification");
-        }
+            Ok(new_state) => new_state,
+            Err(error) => {
+                warn!(
+                    hash = certificate_id.to_string(),
+                    "Error during the native execution of {certificate_id}: {}", error
+                );
+                self.set_in_error(certificate_id, error);
 
-        if let Ok(result) = receiver.await {
-            match result {
-                Ok(SettledCertificate(certificate_id, _height, _epoch, _index)) => {
-                    info!(
-                        hash = certificate_id.to_string(),
-                        "Received a certificate settlement notification"
-                    );
-                    if let Some(new) = self.pending_state.take() {
-                        debug!(
-                            "Updated the state for network {} with the new state {} > {}",
-                            self.network_id,
-                            self.local_state.get_roots().display_to_hex(),
-                            new.get_roots().display_to_hex()
-                        );
-
-                        self.local_state = new;
-
-                        // Store the current state
-                        let new_leaves = certificate
-                            .bridge_exits
-                            .iter()
-                            .map(|exit| exit.hash().into())
-                            .collect::<Vec<Hash>>();
-
-                        self.state_store
-                            .write_local_network_state(
-                                &certificate.network_id,
-                                &self.local_state,
-                                new_leaves.as_slice(),
-                            )
-                            .map_err(|e| Error::PersistenceError {
-                                certificate_id,
-                                error: e.to_string(),
-                            })?;
-                    } else {
-                        error!(
-                            "Missing pending state for network {} needed upon settlement, current \
-                             state: {}",
-                            self.network_id,
-                            self.local_state.get_roots().display_to_hex()
-                        );
-                    }
-                }
-                Err(error) => {
-                    error!(
-                        hash = certificate_id.to_string(),
-                        "Failed to settle the certificate: {}", error
-                    );
+                return Ok(());
+            }
+        };
 
-                    if self
-                        .state_store
-                        .update_certificate_header_status(
-                            &certificate_id,
-                            &CertificateStatus::InError {
-                                error: CertificateStatusError::SettlementError(error),
-                            },
-                        )
-                        .is_err()
-                    {
-                        error!(
-                            hash = certificate_id.to_string(),
-                            "Certificate {certificate_id} in error and failed to update the \
-                             certificate header status"
-                        );
-                    }
-                }
+        let output = CertifierOutput {
+            certificate,
+            height,
+            new_state,
+            network: self.network_id,
+        };
+
+        if header.status == CertificateStatus::Proven {
+            self.on_proven_certificate(output).await;
+        } else {
+            // A candidate certificate already belongs to an epoch.
+            if header.epoch_number == Some(self.clock_ref.current_epoch()) {
+                self.certificates_in_epoch += 1;
             }
+
+            self.request_settlement(output).await;
         }
 
+        *next_expected_height += 1;
+
         Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::time::Duration;
+    /// Request the settlement of the oldest certificate proven ahead, once the
+    /// previous certificate is settled and as long as the network didn't reach
+    /// its number of certificates for the current epoch.
+    async fn request_next_settlement(&mut self) {
+        if self.settling.is_some() || self.at_capacity_for_epoch() || *self.paused.borrow() {
+            return;
+        }
 
-    use agglayer_storage::tests::mocks::{MockPendingStore, MockStateStore};
-    use mockall::predicate::{always, eq};
-    use rstest::rstest;
+        if let Some(output) = self.speculative.pop_front() {
+            self.on_proven_certificate(output).await;
+        }
+    }
+
+    /// Apply the result of the settlement of the settling certificate.
+    ///
+    /// Once settled, the new local state is persisted and the settlement of
+    /// the next proven certificate is requested. If the settlement failed, the
+    /// certificates proven on top of it are rolled back and the certification
+    /// restarts from the height of the failed certificate.
+    async fn on_settlement(
+        &mut self,
+        result: Result<Result<SettledCertificate, String>, oneshot::error::RecvError>,
+        next_expected_height: &mut u64,
+    ) -> Result<(), Error> {
+        let Some(Settling { output, .. }) = self.settling.take() else {
+            return Ok(());
+        };
+        let certificate_id = output.certificate.hash();
+
+        match result {
+            Ok(Ok(SettledCertificate(certificate_id, _height, _epoch, _index))) => {
+                info!(
+                    hash = certificate_id.to_string(),
+                    "Received a certificate settlement notification"
+                );
+                debug!(
+                    "Updated the state for network {} with the new state {} > {}",
+                    self.network_id,
+                    self.local_state.get_roots().display_to_hex(),
+                    output.new_state.get_roots().display_to_hex()
+                );
+
+                self.local_state = output.new_state;
+
+                // Store the current state
+                let new_leaves = output
+                    .certificate
+                    .bridge_exits
+                    .iter()
+                    .map(|exit| exit.hash().into())
+                    .collect::<Vec<Hash>>();
+
+                self.state_store
+                    .write_local_network_state(
+                        &output.certificate.network_id,
+                        &output.certificate.height,
+                        &self.local_state,
+                        new_leaves.as_slice(),
+                    )
+                    .map_err(|e| Error::PersistenceError {
+                        certificate_id,
+                        error: e.to_string(),
+                    })?;
+
+                // The recovered certificates are settled before the ones proven since.
+                match self.recovered_certificates.pop_front() {
+                    Some(certificate) => {
+                        self.recover_certificate(certificate, next_expected_height)
+                            .await?
+                    }
+                    None => self.request_next_settlement().await,
+                }
+            }
+            Ok(Err(error)) => {
+                error!(
+                    hash = certificate_id.to_string(),
+                    "Failed to settle the certificate: {}", error
+                );
+
+                self.set_settlement_error(certificate_id, error);
+                self.rollback(output.height, next_expected_height);
+            }
+            // The certificate keeps its status, its settlement is requested again once
+            // recovered at the same height.
+            Err(_) => {
+                warn!(
+                    hash = certificate_id.to_string(),
+                    "The settlement notification channel of {certificate_id} was closed"
+                );
+
+                self.rollback(output.height, next_expected_height);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Expire the certificates of the network pending for the configured number
+    /// of epochs: they are set in error and removed from the pending queue.
+    ///
+    /// Only the certificates still pending are expired, the ones being
+    /// certified or settled are left untouched.
+    fn expire_pending_certificates(&mut self, epoch: EpochNumber) -> Result<(), Error> {
+        let Some(ttl) = self.pending_certificate_ttl else {
+            return Ok(());
+        };
+
+        for certificate in self
+            .pending_store
+            .get_pending_certificates_for_network(&self.network_id)?
+        {
+            let certificate_id = certificate.hash();
+
+            // A certificate queued without its epoch being recorded is considered
+            // pending from this epoch on.
+            let since = match self
+                .pending_store
+                .get_pending_since(self.network_id, certificate.height)?
+            {
+                Some(since) => since,
+                None => {
+                    self.pending_store.set_pending_since(
+                        self.network_id,
+                        certificate.height,
+                        epoch,
+                    )?;
+
+                    epoch
+                }
+            };
+
+            let pending = self
+                .state_store
+                .get_certificate_header(&certificate_id)?
+                .is_some_and(|header| header.status == CertificateStatus::Pending);
+
+            if epoch.saturating_sub(since) < ttl || !pending {
+                continue;
+            }
+
+            warn!(
+                hash = certificate_id.to_string(),
+                "Certificate {certificate_id} for network {} at height {} expired after {ttl} \
+                 epochs pending",
+                self.network_id,
+                certificate.height
+            );
+
+            self.state_store.update_certificate_header_status(
+                &certificate_id,
+                &CertificateStatus::InError {
+                    error: CertificateStatusError::Expired(ttl),
+                },
+            )?;
+            self.pending_store
+                .remove_pending_certificate(self.network_id, certificate.height)?;
+        }
+
+        Ok(())
+    }
+
+    /// Drop the certificates proven or recovered on top of a certificate which
+    /// failed to settle, the certification restarts from its height.
+    fn rollback(&mut self, height: Height, next_expected_height: &mut u64) {
+        self.recovered_certificates.clear();
+
+        for CertifierOutput { certificate, .. } in self.speculative.drain(..) {
+            let certificate_id = certificate.hash();
+            warn!(
+                hash = certificate_id.to_string(),
+                "Rolling back the certificate {certificate_id} for network {} at height {}",
+                self.network_id,
+                certificate.height
+            );
+
+            if let Err(error) = self.pending_store.remove_generated_proof(&certificate_id) {
+                error!(
+                    hash = certificate_id.to_string(),
+                    "Failed to remove the generated proof of {certificate_id}: {:?}", error
+                );
+            }
+        }
+
+        *next_expected_height = height;
+    }
+
+    fn set_in_error(&self, certificate_id: CertificateId, error: CertificationError) {
+        let error = certification_status_error(certificate_id, error);
+
+        if self
+            .state_store
+            .update_certificate_header_status(
+                &certificate_id,
+                &CertificateStatus::InError { error },
+            )
+            .is_err()
+        {
+            error!(
+                hash = certificate_id.to_string(),
+                "Certificate {certificate_id} in error and failed to update the certificate \
+                 header status"
+            );
+        }
+    }
+
+    fn set_settlement_error(&self, certificate_id: CertificateId, error: String) {
+        if self
+            .state_store
+            .update_certificate_header_status(
+                &certificate_id,
+                &CertificateStatus::InError {
+                    error: CertificateStatusError::SettlementError(error),
+                },
+            )
+            .is_err()
+        {
+            error!(
+                hash = certificate_id.to_string(),
+                "Certificate {certificate_id} in error and failed to update the certificate \
+                 header status"
+            );
+        }
+    }
+}
+
+impl<CertifierClient, PendingStore, StateStore>
+    NetworkTask<CertifierClient, PendingStore, StateStore>
+where
+    CertifierClient: Certifier,
+    PendingStore: PendingCertificateReader + PendingCertificateWriter,
+    StateStore: StateWriter,
+{
+    /// Context:
+    ///
+    /// At one point in time, there is at most one certifier task per network
+    /// running. The certifier task try to generate a proof based on
+    /// a certificate. The certifier task doesn't know about other tasks nor if
+    /// the certificate will be included in an epoch. The Orchestrator is
+    /// the one that is responsible to decide if a proof is valid and should
+    /// be included in an epoch.
+    ///
+    /// Based on the current context of the orchestrator, we can
+    /// determine the following:
+    ///
+    /// 1. If the state doesn't know the network and the height is 0, we update
+    ///    the state. This is the first certificate for this network.
+    /// 2. If the state knows the network and the height is the next one, we
+    ///    update the state. This is the next certificate for this network.
+    /// 3. If the state doesn't know the network and the height is not 0, we
+    ///    ignore the proof.
+    /// 4. If the state knows the network and the height is not the next
+    ///    expected one, we ignore the proof.
+    ///
+    /// When a generated proof is accepted:
+    /// - We update the cursor for the network.
+    /// - We update the latest proven certificate for the network.
+    /// - We do not remove the pending certificate. (as it needs to be included
+    ///   in an epoch)
+    /// - We request the settlement of the certificate to the orchestrator.
+    ///
+    /// While the certificate is settling, the next certificate of the network
+    /// is proven on top of its new local state. This proof is only accepted
+    /// once the certificate is settled, and dropped if the settlement fails.
+    async fn on_proven_certificate(&mut self, output: CertifierOutput) {
+        let certificate_id = output.certificate.hash();
+        if let Err(error) = self
+            .pending_store
+            .set_latest_proven_certificate_per_network(
+                &self.network_id,
+                &output.height,
+                &certificate_id,
+            )
+        {
+            error!(
+                hash = certificate_id.to_string(),
+                "Failed to set the latest proven certificate per network: {:?}", error
+            );
+        }
+
+        if let Err(error) = self
+            .state_store
+            .update_certificate_header_status(&certificate_id, &CertificateStatus::Proven)
+        {
+            error!(
+                hash = certificate_id.to_string(),
+                "Failed to update the certificate header status: {:?}", error
+            );
+        }
+
+        self.certificates_in_epoch += 1;
+        self.request_settlement(output).await;
+    }
+
+    /// Notify the orchestrator that a certificate is ready to be settled, its
+    /// new local state is applied once the settlement result is received.
+    async fn request_settlement(&mut self, output: CertifierOutput) {
+        // Once cancelled, the certificate stays proven and its settlement is
+        // resumed when the node restarts.
+        if self.cancellation_token.is_cancelled() {
+            let certificate_id = output.certificate.hash();
+            info!(
+                hash = certificate_id.to_string(),
+                "Certificate {certificate_id} proven while the network task {} is cancelled, its \
+                 settlement is resumed on restart",
+                self.network_id
+            );
+
+            return;
+        }
+
+        let (sender, receiver) = oneshot::channel();
+
+        if self
+            .certification_notifier
+            .send((
+                sender,
+                ProvenCertificate(output.certificate.hash(), self.network_id, output.height),
+            ))
+            .await
+            .is_err()
+        {
+            error!("Failed to send the proven certificate notification");
+        }
+
+        self.settling = Some(Settling { output, receiver });
+    }
+}
+
+/// Status error of a certificate which certification failed.
+fn certification_status_error(
+    certificate_id: CertificateId,
+    error: CertificationError,
+) -> CertificateStatusError {
+    match error {
+        CertificationError::TrustedSequencerNotFound(network) => {
+            CertificateStatusError::TrustedSequencerNotFound(network)
+        }
+        CertificationError::ProofVerificationFailed { source } => source.into(),
+        CertificationError::L1InfoRootNotFound(_certificate_id, l1_leaf_count) => {
+            CertificateStatusError::L1InfoRootNotFound(l1_leaf_count)
+        }
+
+        CertificationError::ProverExecutionFailed { source } => {
+            CertificateStatusError::ProofGenerationError {
+                generation_type: agglayer_types::GenerationType::Prover,
+                source,
+            }
+        }
+        CertificationError::NativeExecutionFailed { source } => {
+            CertificateStatusError::ProofGenerationError {
+                generation_type: agglayer_types::GenerationType::Native,
+                source,
+            }
+        }
+
+        CertificationError::Types { source } => source.into(),
+
+        CertificationError::Storage(error) => {
+            let error = format!(
+                "Storage error happened in the certification process of {certificate_id}: {:?}",
+                error
+            );
+            warn!(hash = certificate_id.to_string(), error);
+
+            CertificateStatusError::InternalError(error)
+        }
+        CertificationError::Serialize { source } => {
+            let error = format!(
+                "Serialization error happened in the certification process of {certificate_id}: \
+                 {:?}",
+                source
+            );
+            warn!(hash = certificate_id.to_string(), error);
+
+            CertificateStatusError::InternalError(error)
+        }
+        CertificationError::Deserialize { source } => {
+            let error = format!(
+                "Deserialization error happened in the certification process of {certificate_id}: \
+                 {:?}",
+                source
+            );
+            warn!(hash = certificate_id.to_string(), error);
+            CertificateStatusError::InternalError(error)
+        }
+        CertificationError::InternalError(error) => {
+            let error = format!(
+                "Internal error happened in the certification process of {certificate_id}: {}",
+                error
+            );
+            warn!(hash = certificate_id.to_string(), error);
+
+            CertificateStatusError::InternalError(error)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, time::Duration};
+
+    use agglayer_storage::tests::mocks::{MockPendingStore, MockStateStore};
+    use mockall::predicate::{always, eq};
+    use rstest::rstest;
 
     use super::*;
     use crate::tests::{clock, mocks::MockCertifier};
@@ -627,6 +1106,7 @@ mod tests {
                     new_local_exit_root: [0; 32].into(),
                     metadata: [0; 32].into(),
                     status: CertificateStatus::Pending,
+                    settlement_tx_hash: None,
                 }))
             });
 
@@ -653,7 +1133,7 @@ mod tests {
 
         state
             .expect_write_local_network_state()
-            .returning(|_, _, _| Ok(()));
+            .returning(|_, _, _, _| Ok(()));
 
         pending
             .expect_set_latest_proven_certificate_per_network()
@@ -674,6 +1154,8 @@ mod tests {
             clock_ref,
             network_id,
             certificate_stream,
+            watch::channel(false).1,
+            1,
         )
         .expect("Failed to create a new network task");
 
@@ -700,6 +1182,71 @@ mod tests {
         assert_eq!(next_expected_height, 1);
     }
 
+    #[rstest]
+    #[tokio::test]
+    #[timeout(Duration::from_secs(1))]
+    async fn paused_network_resumes_at_next_expected_height() {
+        let mut pending = MockPendingStore::new();
+        let mut state = MockStateStore::new();
+        let certifier = MockCertifier::new();
+        let (certification_notifier, _receiver) = mpsc::channel(1);
+        let clock_ref = clock();
+        let network_id = 1.into();
+        let (sender, certificate_stream) = mpsc::channel(1);
+        let (pause_sender, paused) = watch::channel(true);
+
+        let certificate_id = Certificate::new_for_test(network_id, 0).hash();
+
+        state
+            .expect_read_local_network_state()
+            .returning(|_| Ok(Default::default()));
+
+        // The certificate is only looked up once the network is resumed.
+        pending
+            .expect_get_certificate()
+            .once()
+            .with(eq(network_id), eq(0))
+            .returning(|_, _| Ok(None));
+
+        let mut task = NetworkTask::new(
+            Arc::new(pending),
+            Arc::new(state),
+            Arc::new(certifier),
+            certification_notifier,
+            clock_ref,
+            network_id,
+            certificate_stream,
+            paused,
+            1,
+        )
+        .expect("Failed to create a new network task");
+
+        let mut epochs = task.clock_ref.subscribe().unwrap();
+        let mut next_expected_height = 0;
+
+        let _ = sender
+            .send(NewCertificate {
+                certificate_id,
+                height: 0,
+            })
+            .await;
+
+        assert!(tokio::time::timeout(
+            Duration::from_millis(100),
+            task.make_progress(&mut epochs, &mut next_expected_height),
+        )
+        .await
+        .is_err());
+
+        pause_sender.send_replace(false);
+
+        task.make_progress(&mut epochs, &mut next_expected_height)
+            .await
+            .unwrap();
+
+        assert_eq!(next_expected_height, 0);
+    }
+
     #[rstest]
     #[tokio::test]
     #[timeout(Duration::from_secs(1))]
@@ -725,9 +1272,10 @@ mod tests {
                 Ok(Some(Certificate::new_for_test(network_id, height)))
             });
 
+        // The next certificate is proven while the first one settles.
         pending
             .expect_get_certificate()
-            .never()
+            .once()
             .with(eq(network_id), eq(1))
             .returning(|network_id, height| {
                 Ok(Some(Certificate::new_for_test(network_id, height)))
@@ -747,12 +1295,13 @@ mod tests {
                     new_local_exit_root: [0; 32].into(),
                     metadata: [0; 32].into(),
                     status: CertificateStatus::Pending,
+                    settlement_tx_hash: None,
                 }))
             });
 
         state
             .expect_get_certificate_header()
-            .never()
+            .once()
             .with(eq(certificate_id2))
             .returning(|certificate_id| {
                 Ok(Some(agglayer_types::CertificateHeader {
@@ -765,6 +1314,7 @@ mod tests {
                     new_local_exit_root: [0; 32].into(),
                     metadata: [0; 32].into(),
                     status: CertificateStatus::Pending,
+                    settlement_tx_hash: None,
                 }))
             });
         certifier
@@ -790,11 +1340,11 @@ mod tests {
 
         state
             .expect_write_local_network_state()
-            .returning(|_, _, _| Ok(()));
+            .returning(|_, _, _, _| Ok(()));
 
         certifier
             .expect_certify()
-            .never()
+            .once()
             .with(always(), eq(network_id), eq(1))
             .return_once(move |new_state, network_id, _height| {
                 Ok(Box::pin(async move {
@@ -827,6 +1377,8 @@ mod tests {
             clock_ref,
             network_id,
             certificate_stream,
+            watch::channel(false).1,
+            1,
         )
         .expect("Failed to create a new network task");
 
@@ -863,14 +1415,20 @@ mod tests {
 
         assert_eq!(next_expected_height, 1);
 
-        tokio::time::timeout(
+        while let Ok(result) = tokio::time::timeout(
             Duration::from_millis(100),
             task.make_progress(&mut epochs, &mut next_expected_height),
         )
         .await
-        .expect_err("Should have timed out");
+        {
+            result.unwrap();
+        }
 
-        assert_eq!(next_expected_height, 1);
+        // The second certificate is proven but its settlement waits for the next
+        // epoch.
+        assert_eq!(next_expected_height, 2);
+        assert_eq!(task.speculative.len(), 1);
+        assert!(task.settling.is_none());
     }
 
     #[rstest]
@@ -906,13 +1464,19 @@ mod tests {
                 Ok(Some(Certificate::new_for_test(network_id, height)))
             });
 
+        pending
+            .expect_get_certificate()
+            .once()
+            .with(eq(network_id), eq(2))
+            .returning(|_, _| Ok(None));
+
         state
             .expect_read_local_network_state()
             .returning(|_| Ok(Default::default()));
 
         state
             .expect_write_local_network_state()
-            .returning(|_, _, _| Ok(()));
+            .returning(|_, _, _, _| Ok(()));
 
         state
             .expect_get_certificate_header()
@@ -929,6 +1493,7 @@ mod tests {
                     prev_local_exit_root: [1; 32].into(),
                     metadata: [0; 32].into(),
                     status: CertificateStatus::Pending,
+                    settlement_tx_hash: None,
                 }))
             });
 
@@ -947,6 +1512,7 @@ mod tests {
                     new_local_exit_root: [0; 32].into(),
                     metadata: [0; 32].into(),
                     status: CertificateStatus::Pending,
+                    settlement_tx_hash: None,
                 }))
             });
         certifier
@@ -1014,6 +1580,8 @@ mod tests {
             clock_ref.clone(),
             network_id,
             certificate_stream,
+            watch::channel(false).1,
+            1,
         )
         .expect("Failed to create a new network task");
 
@@ -1056,14 +1624,17 @@ mod tests {
 
         assert_eq!(next_expected_height, 1);
 
-        tokio::time::timeout(
+        while let Ok(result) = tokio::time::timeout(
             Duration::from_millis(100),
             task.make_progress(&mut epochs, &mut next_expected_height),
         )
         .await
-        .expect_err("Should have timed out");
+        {
+            result.unwrap();
+        }
 
-        assert_eq!(next_expected_height, 1);
+        assert_eq!(next_expected_height, 2);
+        assert_eq!(task.speculative.len(), 1);
 
         clock_ref
             .get_sender()
@@ -1074,7 +1645,10 @@ mod tests {
             .await
             .unwrap();
 
+        // The second certificate, already proven, is settling.
         assert_eq!(next_expected_height, 2);
+        assert!(task.speculative.is_empty());
+        assert!(task.settling.is_some());
     }
 
     #[rstest]
@@ -1115,6 +1689,7 @@ mod tests {
                     new_local_exit_root: [0; 32].into(),
                     metadata: [0; 32].into(),
                     status: CertificateStatus::Pending,
+                    settlement_tx_hash: None,
                 }))
             });
 
@@ -1156,6 +1731,8 @@ mod tests {
             clock_ref.clone(),
             network_id,
             certificate_stream,
+            watch::channel(false).1,
+            1,
         )
         .expect("Failed to create a new network task");
 
@@ -1184,4 +1761,849 @@ mod tests {
 
         assert_eq!(next_expected_height, 0);
     }
+
+    #[rstest]
+    #[tokio::test]
+    #[timeout(Duration::from_secs(1))]
+    async fn certify_several_certificates_per_epoch() {
+        let mut pending = MockPendingStore::new();
+        let mut state = MockStateStore::new();
+        let mut certifier = MockCertifier::new();
+        let (certification_notifier, mut receiver) = mpsc::channel(1);
+        let clock_ref = clock();
+        let network_id = 1.into();
+        let (sender, certificate_stream) = mpsc::channel(3);
+
+        pending
+            .expect_get_certificate()
+            .times(3)
+            .returning(|network_id, height| {
+                Ok(Some(Certificate::new_for_test(network_id, height)))
+            });
+
+        state
+            .expect_get_certificate_header()
+            .times(3)
+            .returning(|certificate_id| {
+                Ok(Some(agglayer_types::CertificateHeader {
+                    network_id: 1.into(),
+                    height: 0,
+                    epoch_number: None,
+                    certificate_index: None,
+                    certificate_id: *certificate_id,
+                    prev_local_exit_root: [1; 32].into(),
+                    new_local_exit_root: [0; 32].into(),
+                    metadata: [0; 32].into(),
+                    status: CertificateStatus::Pending,
+                    settlement_tx_hash: None,
+                }))
+            });
+
+        certifier
+            .expect_certify()
+            .times(3)
+            .returning(|new_state, network_id, height| {
+                Ok(Box::pin(async move {
+                    Ok(crate::CertifierOutput {
+                        certificate: Certificate::new_for_test(network_id, height),
+                        height,
+                        new_state,
+                        network: network_id,
+                    })
+                }))
+            });
+
+        state
+            .expect_read_local_network_state()
+            .returning(|_| Ok(Default::default()));
+
+        state
+            .expect_write_local_network_state()
+            .returning(|_, _, _, _| Ok(()));
+
+        pending
+            .expect_set_latest_proven_certificate_per_network()
+            .times(2)
+            .returning(|_, _, _| Ok(()));
+        state
+            .expect_update_certificate_header_status()
+            .times(2)
+            .with(always(), eq(CertificateStatus::Proven))
+            .returning(|_, _| Ok(()));
+
+        let mut task = NetworkTask::new(
+            Arc::new(pending),
+            Arc::new(state),
+            Arc::new(certifier),
+            certification_notifier,
+            clock_ref,
+            network_id,
+            certificate_stream,
+            watch::channel(false).1,
+            2,
+        )
+        .expect("Failed to create a new network task");
+
+        let mut epochs = task.clock_ref.subscribe().unwrap();
+        let mut next_expected_height = 0;
+
+        for height in 0..3 {
+            sender
+                .send(NewCertificate {
+                    certificate_id: Certificate::new_for_test(network_id, height).hash(),
+                    height,
+                })
+                .await
+                .expect("Failed to send the certificate");
+        }
+
+        tokio::spawn(async move {
+            while let Some((sender, cert)) = receiver.recv().await {
+                _ = sender.send(Ok(SettledCertificate(cert.0, cert.2, 0, 0)));
+            }
+        });
+
+        while let Ok(result) = tokio::time::timeout(
+            Duration::from_millis(100),
+            task.make_progress(&mut epochs, &mut next_expected_height),
+        )
+        .await
+        {
+            result.unwrap();
+        }
+
+        // The third certificate is proven but its settlement waits for the next
+        // epoch.
+        assert_eq!(next_expected_height, 3);
+        assert!(task.at_capacity_for_epoch());
+        assert_eq!(task.speculative.len(), 1);
+    }
+
+    /// Mock stores and certifier to certify the certificates at heights 0 and
+    /// 1, the certificate at height 0 adding a leaf to the local exit tree.
+    fn speculative_mocks() -> (MockPendingStore, MockStateStore, MockCertifier) {
+        let mut pending = MockPendingStore::new();
+        let mut state = MockStateStore::new();
+        let mut certifier = MockCertifier::new();
+        let network_id = 1.into();
+        let certificate_id = Certificate::new_for_test(network_id, 0).hash();
+
+        pending
+            .expect_get_certificate()
+            .times(2)
+            .returning(|network_id, height| {
+                Ok(Some(Certificate::new_for_test(network_id, height)))
+            });
+
+        state
+            .expect_get_certificate_header()
+            .times(2)
+            .returning(|certificate_id| {
+                Ok(Some(agglayer_types::CertificateHeader {
+                    network_id: 1.into(),
+                    height: 0,
+                    epoch_number: None,
+                    certificate_index: None,
+                    certificate_id: *certificate_id,
+                    prev_local_exit_root: [1; 32].into(),
+                    new_local_exit_root: [0; 32].into(),
+                    metadata: [0; 32].into(),
+                    status: CertificateStatus::Pending,
+                    settlement_tx_hash: None,
+                }))
+            });
+
+        state
+            .expect_read_local_network_state()
+            .returning(|_| Ok(Default::default()));
+
+        certifier
+            .expect_certify()
+            .once()
+            .with(always(), eq(network_id), eq(0))
+            .returning(|mut new_state, network_id, height| {
+                new_state.exit_tree.add_leaf([1; 32]).unwrap();
+
+                Ok(Box::pin(async move {
+                    Ok(crate::CertifierOutput {
+                        certificate: Certificate::new_for_test(network_id, height),
+                        height,
+                        new_state,
+                        network: network_id,
+                    })
+                }))
+            });
+
+        // The next certificate is proven on top of the state of the settling one.
+        let mut settling_state = LocalNetworkStateData::default();
+        settling_state.exit_tree.add_leaf([1; 32]).unwrap();
+        let settling_roots = settling_state.get_roots();
+        certifier
+            .expect_certify()
+            .once()
+            .withf(move |state, _network_id, height| {
+                *height == 1 && state.get_roots() == settling_roots
+            })
+            .returning(|new_state, network_id, height| {
+                Ok(Box::pin(async move {
+                    Ok(crate::CertifierOutput {
+                        certificate: Certificate::new_for_test(network_id, height),
+                        height,
+                        new_state,
+                        network: network_id,
+                    })
+                }))
+            });
+
+        pending
+            .expect_set_latest_proven_certificate_per_network()
+            .once()
+            .with(eq(network_id), eq(0), eq(certificate_id))
+            .returning(|_, _, _| Ok(()));
+        state
+            .expect_update_certificate_header_status()
+            .once()
+            .with(eq(certificate_id), eq(CertificateStatus::Proven))
+            .returning(|_, _| Ok(()));
+
+        (pending, state, certifier)
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[timeout(Duration::from_secs(1))]
+    async fn certify_next_height_while_settling() {
+        let (pending, mut state, certifier) = speculative_mocks();
+        let (certification_notifier, mut receiver) = mpsc::channel(1);
+        let network_id = 1.into();
+        let (sender, certificate_stream) = mpsc::channel(2);
+
+        state.expect_write_local_network_state().never();
+
+        let mut task = NetworkTask::new(
+            Arc::new(pending),
+            Arc::new(state),
+            Arc::new(certifier),
+            certification_notifier,
+            clock(),
+            network_id,
+            certificate_stream,
+            watch::channel(false).1,
+            1,
+        )
+        .expect("Failed to create a new network task");
+
+        let mut epochs = task.clock_ref.subscribe().unwrap();
+        let mut next_expected_height = 0;
+
+        for height in 0..2 {
+            sender
+                .send(NewCertificate {
+                    certificate_id: Certificate::new_for_test(network_id, height).hash(),
+                    height,
+                })
+                .await
+                .expect("Failed to send the certificate");
+        }
+
+        for _ in 0..2 {
+            task.make_progress(&mut epochs, &mut next_expected_height)
+                .await
+                .unwrap();
+        }
+
+        // The certificate at height 0 is still settling.
+        let (_settlement, ProvenCertificate(_, _, height)) = receiver.recv().await.unwrap();
+        assert_eq!(height, 0);
+        assert!(task.settling.is_some());
+
+        assert_eq!(next_expected_height, 2);
+        assert_eq!(task.speculative.len(), 1);
+        assert_eq!(
+            task.local_state.get_roots(),
+            LocalNetworkStateData::default().get_roots()
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[timeout(Duration::from_secs(1))]
+    async fn rollback_speculative_certificate_on_settlement_failure() {
+        let (mut pending, mut state, certifier) = speculative_mocks();
+        let (certification_notifier, mut receiver) = mpsc::channel(1);
+        let network_id = 1.into();
+        let (sender, certificate_stream) = mpsc::channel(2);
+
+        let certificate_id = Certificate::new_for_test(network_id, 0).hash();
+        let certificate_id2 = Certificate::new_for_test(network_id, 1).hash();
+
+        state.expect_write_local_network_state().never();
+        state
+            .expect_update_certificate_header_status()
+            .once()
+            .with(
+                eq(certificate_id),
+                eq(CertificateStatus::InError {
+                    error: CertificateStatusError::SettlementError("failure".to_string()),
+                }),
+            )
+            .returning(|_, _| Ok(()));
+        pending
+            .expect_remove_generated_proof()
+            .once()
+            .with(eq(certificate_id2))
+            .returning(|_| Ok(()));
+
+        let mut task = NetworkTask::new(
+            Arc::new(pending),
+            Arc::new(state),
+            Arc::new(certifier),
+            certification_notifier,
+            clock(),
+            network_id,
+            certificate_stream,
+            watch::channel(false).1,
+            1,
+        )
+        .expect("Failed to create a new network task");
+
+        let mut epochs = task.clock_ref.subscribe().unwrap();
+        let mut next_expected_height = 0;
+
+        for height in 0..2 {
+            sender
+                .send(NewCertificate {
+                    certificate_id: Certificate::new_for_test(network_id, height).hash(),
+                    height,
+                })
+                .await
+                .expect("Failed to send the certificate");
+        }
+
+        for _ in 0..2 {
+            task.make_progress(&mut epochs, &mut next_expected_height)
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(next_expected_height, 2);
+
+        let (settlement, _) = receiver.recv().await.unwrap();
+        settlement
+            .send(Err("failure".to_string()))
+            .expect("Failed to send");
+
+        task.make_progress(&mut epochs, &mut next_expected_height)
+            .await
+            .unwrap();
+
+        // The certification restarts from the certificate which failed to settle.
+        assert_eq!(next_expected_height, 0);
+        assert!(task.speculative.is_empty());
+        assert!(task.settling.is_none());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[timeout(Duration::from_secs(1))]
+    async fn cancelled_task_waits_for_the_settling_certificate() {
+        let pending = MockPendingStore::new();
+        let mut state = MockStateStore::new();
+        let (certification_notifier, _receiver) = mpsc::channel(1);
+        let network_id = 1.into();
+        let (_sender, certificate_stream) = mpsc::channel(1);
+
+        let certificate = Certificate::new_for_test(network_id, 0);
+        let certificate_id = certificate.hash();
+
+        state
+            .expect_read_local_network_state()
+            .returning(|_| Ok(Default::default()));
+        state
+            .expect_get_latest_settled_certificate_per_network()
+            .returning(|_| Ok(None));
+        // The new local state is persisted once settled, despite the cancellation.
+        state
+            .expect_write_local_network_state()
+            .once()
+            .returning(|_, _, _, _| Ok(()));
+
+        let mut task = NetworkTask::new(
+            Arc::new(pending),
+            Arc::new(state),
+            Arc::new(MockCertifier::new()),
+            certification_notifier,
+            clock(),
+            network_id,
+            certificate_stream,
+            watch::channel(false).1,
+            1,
+        )
+        .expect("Failed to create a new network task");
+
+        let (settlement, receiver) = oneshot::channel();
+        task.settling = Some(Settling {
+            output: CertifierOutput {
+                certificate,
+                height: 0,
+                new_state: LocalNetworkStateData::default(),
+                network: network_id,
+            },
+            receiver,
+        });
+
+        let cancellation_token = CancellationToken::new();
+        cancellation_token.cancel();
+        let task = tokio::spawn(task.run(cancellation_token));
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!task.is_finished());
+
+        settlement
+            .send(Ok(SettledCertificate(certificate_id, 0, 0, 0)))
+            .expect("Failed to send");
+        assert_eq!(task.await.unwrap().unwrap(), network_id);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[timeout(Duration::from_secs(1))]
+    async fn cancelled_task_leaves_the_next_certificate_proven() {
+        let mut pending = MockPendingStore::new();
+        let mut state = MockStateStore::new();
+        let (certification_notifier, mut receiver) = mpsc::channel(1);
+        let network_id = 1.into();
+        let (_sender, certificate_stream) = mpsc::channel(1);
+
+        let certificate = Certificate::new_for_test(network_id, 0);
+        let certificate_id = certificate.hash();
+        let next_certificate = Certificate::new_for_test(network_id, 1);
+        let next_certificate_id = next_certificate.hash();
+
+        state
+            .expect_read_local_network_state()
+            .returning(|_| Ok(Default::default()));
+        state
+            .expect_get_latest_settled_certificate_per_network()
+            .returning(|_| Ok(None));
+        state
+            .expect_write_local_network_state()
+            .once()
+            .returning(|_, _, _, _| Ok(()));
+        // The certificate proven ahead becomes the latest proven one, but its
+        // settlement isn't requested.
+        state
+            .expect_update_certificate_header_status()
+            .once()
+            .with(eq(next_certificate_id), eq(CertificateStatus::Proven))
+            .returning(|_, _| Ok(()));
+        pending
+            .expect_set_latest_proven_certificate_per_network()
+            .once()
+            .with(eq(network_id), eq(1), eq(next_certificate_id))
+            .returning(|_, _, _| Ok(()));
+
+        let mut task = NetworkTask::new(
+            Arc::new(pending),
+            Arc::new(state),
+            Arc::new(MockCertifier::new()),
+            certification_notifier,
+            clock(),
+            network_id,
+            certificate_stream,
+            watch::channel(false).1,
+            2,
+        )
+        .expect("Failed to create a new network task");
+
+        let (settlement, settlement_receiver) = oneshot::channel();
+        task.settling = Some(Settling {
+            output: CertifierOutput {
+                certificate,
+                height: 0,
+                new_state: LocalNetworkStateData::default(),
+                network: network_id,
+            },
+            receiver: settlement_receiver,
+        });
+        task.speculative.push_back(CertifierOutput {
+            certificate: next_certificate,
+            height: 1,
+            new_state: LocalNetworkStateData::default(),
+            network: network_id,
+        });
+
+        let cancellation_token = CancellationToken::new();
+        cancellation_token.cancel();
+        let task = tokio::spawn(task.run(cancellation_token));
+
+        settlement
+            .send(Ok(SettledCertificate(certificate_id, 0, 0, 0)))
+            .expect("Failed to send");
+        assert_eq!(task.await.unwrap().unwrap(), network_id);
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn count_settled_certificates_in_epoch() {
+        let pending = MockPendingStore::new();
+        let mut state = MockStateStore::new();
+        let network_id = 1.into();
+        let (_sender, certificate_stream) = mpsc::channel(1);
+
+        state
+            .expect_read_local_network_state()
+            .returning(|_| Ok(Default::default()));
+
+        // Heights 4 and 5 are settled in epoch 3, height 3 in epoch 2.
+        state
+            .expect_get_certificate_header_by_cursor()
+            .returning(|network_id, height| {
+                let certificate = Certificate::new_for_test(network_id, height);
+
+                Ok(Some(agglayer_types::CertificateHeader {
+                    network_id,
+                    height,
+                    epoch_number: Some(if height >= 4 { 3 } else { 2 }),
+                    certificate_index: Some(0),
+                    certificate_id: certificate.hash(),
+                    prev_local_exit_root: [1; 32].into(),
+                    new_local_exit_root: [0; 32].into(),
+                    metadata: [0; 32].into(),
+                    status: CertificateStatus::Settled,
+                    settlement_tx_hash: None,
+                }))
+            });
+
+        let task = NetworkTask::new(
+            Arc::new(pending),
+            Arc::new(state),
+            Arc::new(MockCertifier::new()),
+            mpsc::channel(1).0,
+            clock(),
+            network_id,
+            certificate_stream,
+            watch::channel(false).1,
+            5,
+        )
+        .expect("Failed to create a new network task");
+
+        assert_eq!(task.settled_certificates_in_epoch(3, 5).unwrap(), 2);
+        assert_eq!(task.settled_certificates_in_epoch(2, 3).unwrap(), 1);
+    }
+
+    #[test]
+    fn expire_pending_certificates_after_ttl() {
+        let mut pending = MockPendingStore::new();
+        let mut state = MockStateStore::new();
+        let network_id = 1.into();
+
+        let proven_id = Certificate::new_for_test(network_id, 0).hash();
+        let expired_id = Certificate::new_for_test(network_id, 1).hash();
+
+        state
+            .expect_read_local_network_state()
+            .returning(|_| Ok(Default::default()));
+
+        pending
+            .expect_get_pending_certificates_for_network()
+            .times(2)
+            .returning(|network_id| {
+                Ok(vec![
+                    Certificate::new_for_test(*network_id, 0),
+                    Certificate::new_for_test(*network_id, 1),
+                ])
+            });
+
+        // The certificate at height 1 entered the queue at epoch 3, the one at
+        // height 0 was queued without its epoch being recorded.
+        let pending_since = Arc::new(std::sync::Mutex::new(BTreeMap::from([(1, 3)])));
+        let recorded = pending_since.clone();
+        pending
+            .expect_get_pending_since()
+            .returning(move |_, height| Ok(recorded.lock().unwrap().get(&height).copied()));
+        let recorded = pending_since.clone();
+        pending
+            .expect_set_pending_since()
+            .once()
+            .with(eq(network_id), eq(0), eq(4))
+            .returning(move |_, height, epoch| {
+                recorded.lock().unwrap().insert(height, epoch);
+
+                Ok(())
+            });
+
+        // The certificate at height 0 is being certified, only the one at height 1
+        // is still pending.
+        state
+            .expect_get_certificate_header()
+            .returning(move |certificate_id| {
+                let height = if *certificate_id == proven_id { 0 } else { 1 };
+
+                Ok(Some(agglayer_types::CertificateHeader {
+                    network_id,
+                    height,
+                    epoch_number: None,
+                    certificate_index: None,
+                    certificate_id: *certificate_id,
+                    prev_local_exit_root: [1; 32].into(),
+                    new_local_exit_root: [0; 32].into(),
+                    metadata: [0; 32].into(),
+                    status: if height == 0 {
+                        CertificateStatus::Proven
+                    } else {
+                        CertificateStatus::Pending
+                    },
+                    settlement_tx_hash: None,
+                }))
+            });
+
+        state
+            .expect_update_certificate_header_status()
+            .once()
+            .with(
+                eq(expired_id),
+                eq(CertificateStatus::InError {
+                    error: CertificateStatusError::Expired(2),
+                }),
+            )
+            .returning(|_, _| Ok(()));
+
+        pending
+            .expect_remove_pending_certificate()
+            .once()
+            .with(eq(network_id), eq(1))
+            .returning(|_, _| Ok(()));
+
+        let pending = Arc::new(pending);
+        let state = Arc::new(state);
+        let spawn_task = || {
+            NetworkTask::new(
+                pending.clone(),
+                state.clone(),
+                Arc::new(MockCertifier::new()),
+                mpsc::channel(1).0,
+                clock(),
+                network_id,
+                mpsc::channel(1).1,
+                watch::channel(false).1,
+                1,
+            )
+            .expect("Failed to create a new network task")
+            .with_pending_certificate_ttl(2)
+        };
+
+        spawn_task().expire_pending_certificates(4).unwrap();
+
+        // The certificate keeps the epoch it entered the queue at once the task is
+        // restarted.
+        spawn_task().expire_pending_certificates(5).unwrap();
+
+        assert_eq!(
+            *pending_since.lock().unwrap(),
+            BTreeMap::from([(0, 4), (1, 3)])
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[timeout(Duration::from_secs(1))]
+    async fn recover_proven_certificate() {
+        let mut pending = MockPendingStore::new();
+        let mut state = MockStateStore::new();
+        let mut certifier = MockCertifier::new();
+        let (certification_notifier, mut receiver) = mpsc::channel(1);
+        let clock_ref = clock();
+        let network_id = 1.into();
+        let (sender, certificate_stream) = mpsc::channel(1);
+
+        let certificate = Certificate::new_for_test(network_id, 0);
+        let certificate_id = certificate.hash();
+        pending
+            .expect_get_certificate()
+            .once()
+            .with(eq(network_id), eq(0))
+            .returning(|network_id, height| {
+                Ok(Some(Certificate::new_for_test(network_id, height)))
+            });
+
+        state
+            .expect_get_certificate_header()
+            .times(2)
+            .with(eq(certificate_id))
+            .returning(|certificate_id| {
+                Ok(Some(agglayer_types::CertificateHeader {
+                    network_id: 1.into(),
+                    height: 0,
+                    epoch_number: None,
+                    certificate_index: None,
+                    certificate_id: *certificate_id,
+                    prev_local_exit_root: [1; 32].into(),
+                    new_local_exit_root: [0; 32].into(),
+                    metadata: [0; 32].into(),
+                    status: CertificateStatus::Proven,
+                    settlement_tx_hash: None,
+                }))
+            });
+
+        // The proof already exists, the certificate is only executed natively.
+        certifier.expect_certify().never();
+        certifier
+            .expect_execute_natively()
+            .once()
+            .withf(move |_state, certificate| certificate.hash() == certificate_id)
+            .returning(|state, _certificate| Box::pin(async move { Ok(state) }));
+
+        state
+            .expect_read_local_network_state()
+            .returning(|_| Ok(Default::default()));
+
+        state
+            .expect_write_local_network_state()
+            .once()
+            .returning(|_, _, _, _| Ok(()));
+
+        pending
+            .expect_set_latest_proven_certificate_per_network()
+            .once()
+            .with(eq(network_id), eq(0), eq(certificate_id))
+            .returning(|_, _, _| Ok(()));
+        state
+            .expect_update_certificate_header_status()
+            .once()
+            .with(eq(certificate_id), eq(CertificateStatus::Proven))
+            .returning(|_, _| Ok(()));
+
+        let mut task = NetworkTask::new(
+            Arc::new(pending),
+            Arc::new(state),
+            Arc::new(certifier),
+            certification_notifier,
+            clock_ref,
+            network_id,
+            certificate_stream,
+            watch::channel(false).1,
+            1,
+        )
+        .expect("Failed to create a new network task");
+
+        let mut epochs = task.clock_ref.subscribe().unwrap();
+        let mut next_expected_height = 0;
+
+        let _ = sender
+            .send(NewCertificate {
+                certificate_id,
+                height: 0,
+            })
+            .await;
+
+        tokio::spawn(async move {
+            let (sender, cert) = receiver.recv().await.unwrap();
+
+            _ = sender.send(Ok(SettledCertificate(cert.0, cert.2, 0, 0)));
+        });
+
+        task.make_progress(&mut epochs, &mut next_expected_height)
+            .await
+            .unwrap();
+
+        assert_eq!(next_expected_height, 1);
+        assert!(task.at_capacity_for_epoch());
+
+        // The new local state is persisted once the certificate is settled.
+        task.make_progress(&mut epochs, &mut next_expected_height)
+            .await
+            .unwrap();
+
+        assert!(task.settling.is_none());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[timeout(Duration::from_secs(1))]
+    async fn recover_candidate_certificate() {
+        let pending = MockPendingStore::new();
+        let mut state = MockStateStore::new();
+        let mut certifier = MockCertifier::new();
+        let (certification_notifier, mut receiver) = mpsc::channel(1);
+        let network_id = 1.into();
+        let (_sender, certificate_stream) = mpsc::channel(1);
+
+        let certificate = Certificate::new_for_test(network_id, 0);
+        let certificate_id = certificate.hash();
+
+        state
+            .expect_get_certificate_header()
+            .once()
+            .with(eq(certificate_id))
+            .returning(|certificate_id| {
+                Ok(Some(agglayer_types::CertificateHeader {
+                    network_id: 1.into(),
+                    height: 0,
+                    epoch_number: Some(0),
+                    certificate_index: Some(0),
+                    certificate_id: *certificate_id,
+                    prev_local_exit_root: [1; 32].into(),
+                    new_local_exit_root: [0; 32].into(),
+                    metadata: [0; 32].into(),
+                    status: CertificateStatus::Candidate,
+                    settlement_tx_hash: None,
+                }))
+            });
+
+        certifier
+            .expect_execute_natively()
+            .once()
+            .returning(|state, _certificate| Box::pin(async move { Ok(state) }));
+
+        state
+            .expect_read_local_network_state()
+            .returning(|_| Ok(Default::default()));
+
+        state
+            .expect_write_local_network_state()
+            .once()
+            .returning(|_, _, _, _| Ok(()));
+
+        // The certificate is already in an epoch, it isn't marked as proven again.
+        state.expect_update_certificate_header_status().never();
+
+        let mut task = NetworkTask::new(
+            Arc::new(pending),
+            Arc::new(state),
+            Arc::new(certifier),
+            certification_notifier,
+            clock(),
+            network_id,
+            certificate_stream,
+            watch::channel(false).1,
+            1,
+        )
+        .expect("Failed to create a new network task")
+        .with_recovered_certificate(certificate);
+
+        let mut epochs = task.clock_ref.subscribe().unwrap();
+        let mut next_expected_height = 0;
+
+        tokio::spawn(async move {
+            let (sender, cert) = receiver.recv().await.unwrap();
+
+            _ = sender.send(Ok(SettledCertificate(cert.0, cert.2, 0, 0)));
+        });
+
+        let certificate = task.recovered_certificates.pop_front().unwrap();
+        task.recover_certificate(certificate, &mut next_expected_height)
+            .await
+            .unwrap();
+
+        assert_eq!(next_expected_height, 1);
+        assert!(task.at_capacity_for_epoch());
+
+        task.make_progress(&mut epochs, &mut next_expected_height)
+            .await
+            .unwrap();
+
+        assert!(task.settling.is_none());
+    }
 }
@@ -0,0 +1,331 @@
+//! Fair scheduling of the certifications of the networks on the prover.
+//!
+//! The [`ProverScheduler`] wraps a [`Certifier`] and bounds the number of
+//! certifications in flight across all the networks. Once the bound is reached,
+//! the waiting certifications are served by start-time fair queuing: every
+//! certification of a network is charged in inverse proportion to its weight,
+//! and the waiting network charged the least is served first.
+use std::{
+    collections::{BTreeMap, VecDeque},
+    future::Future,
+    sync::{Arc, Mutex},
+};
+
+use agglayer_config::certificate_orchestrator::scheduler::SchedulerConfig;
+use agglayer_telemetry::{certifier::CERTIFICATION_QUEUE_DEPTH, KeyValue};
+use agglayer_types::{Certificate, Height, LocalNetworkStateData, NetworkId};
+use futures_util::future::BoxFuture;
+use tokio::sync::oneshot;
+
+use crate::{CertificationError, Certifier, CertifierResult};
+
+/// Charge of one certification for a network of weight 1.
+const CERTIFICATION_COST: u64 = 1 << 20;
+
+/// Certifier sharing the prover between the networks.
+pub struct ProverScheduler<C> {
+    inner: C,
+    shared: Arc<Shared>,
+}
+
+impl<C> ProverScheduler<C> {
+    pub fn new(inner: C, config: SchedulerConfig) -> Self {
+        Self {
+            inner,
+            shared: Arc::new(Shared {
+                config,
+                state: Mutex::new(State::default()),
+            }),
+        }
+    }
+}
+
+impl<C: Certifier> Certifier for ProverScheduler<C> {
+    fn certify(
+        &self,
+        full_state: LocalNetworkStateData,
+        network_id: NetworkId,
+        height: Height,
+    ) -> CertifierResult {
+        let certification = self.inner.certify(full_state, network_id, height)?;
+        let slot = self.shared.acquire(network_id);
+
+        Ok(Box::pin(async move {
+            let _slot = slot.await;
+
+            certification.await
+        }))
+    }
+
+    fn execute_natively(
+        &self,
+        full_state: LocalNetworkStateData,
+        certificate: Certificate,
+    ) -> BoxFuture<'static, Result<LocalNetworkStateData, CertificationError>> {
+        self.inner.execute_natively(full_state, certificate)
+    }
+}
+
+struct Shared {
+    config: SchedulerConfig,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    /// Number of certifications holding a prover slot.
+    in_flight: usize,
+    /// Start tag of the latest certification served.
+    virtual_time: u64,
+    networks: BTreeMap<NetworkId, NetworkQueue>,
+}
+
+#[derive(Default)]
+struct NetworkQueue {
+    /// Finish tag of the latest certification of the network served.
+    finish_tag: u64,
+    /// Certifications of the network waiting for a prover slot.
+    waiting: VecDeque<oneshot::Sender<Slot>>,
+}
+
+impl State {
+    /// The network with waiting certifications charged the least.
+    fn next_network(&self) -> Option<NetworkId> {
+        self.networks
+            .iter()
+            .filter(|(_, queue)| !queue.waiting.is_empty())
+            .min_by_key(|(_, queue)| queue.finish_tag.max(self.virtual_time))
+            .map(|(network_id, _)| *network_id)
+    }
+
+    /// Charge a network for a certification taking a prover slot.
+    fn charge(&mut self, network_id: NetworkId, config: &SchedulerConfig) {
+        let cost = CERTIFICATION_COST / u64::from(config.weight_for(*network_id).get());
+        let queue = self.networks.entry(network_id).or_default();
+        let start_tag = queue.finish_tag.max(self.virtual_time);
+
+        queue.finish_tag = start_tag + cost;
+        self.virtual_time = start_tag;
+        self.in_flight += 1;
+    }
+}
+
+impl Shared {
+    /// Wait for a prover slot for a certification of the network.
+    ///
+    /// The certification is queued as soon as this method is called, the
+    /// slot is released once dropped.
+    fn acquire(self: &Arc<Self>, network_id: NetworkId) -> impl Future<Output = Slot> + 'static {
+        let mut state = self.state.lock().unwrap();
+
+        let pending = if state.in_flight < self.config.max_in_flight.get() {
+            state.charge(network_id, &self.config);
+
+            Ok(Slot::new(self.clone()))
+        } else {
+            let (sender, receiver) = oneshot::channel();
+            state
+                .networks
+                .entry(network_id)
+                .or_default()
+                .waiting
+                .push_back(sender);
+            CERTIFICATION_QUEUE_DEPTH
+                .add(1, &[KeyValue::new("network_id", network_id.to_string())]);
+
+            Err(Waiter {
+                shared: self.clone(),
+                network_id,
+                receiver: Some(receiver),
+            })
+        };
+
+        async move {
+            match pending {
+                Ok(slot) => slot,
+                // The scheduler outlives the waiting certifications, the slot is
+                // always sent.
+                Err(mut waiter) => match waiter.receiver.as_mut() {
+                    Some(receiver) => receiver.await.unwrap_or(Slot { shared: None }),
+                    None => Slot { shared: None },
+                },
+            }
+        }
+    }
+
+    /// Release a prover slot and hand it over to the next waiting
+    /// certification.
+    fn release(self: &Arc<Self>) {
+        let (sender, slot) = {
+            let mut state = self.state.lock().unwrap();
+            state.in_flight -= 1;
+
+            let Some(network_id) = state.next_network() else {
+                return;
+            };
+
+            let Some(sender) = state
+                .networks
+                .get_mut(&network_id)
+                .and_then(|queue| queue.waiting.pop_front())
+            else {
+                return;
+            };
+            CERTIFICATION_QUEUE_DEPTH
+                .add(-1, &[KeyValue::new("network_id", network_id.to_string())]);
+            state.charge(network_id, &self.config);
+
+            (sender, Slot::new(self.clone()))
+        };
+
+        // If the certification was cancelled while waiting, the slot is dropped
+        // and handed over to the next one.
+        _ = sender.send(slot);
+    }
+}
+
+/// Certification waiting for a prover slot.
+///
+/// If dropped before being served, the certification is withdrawn from the
+/// queue of its network, so that it is neither charged nor counted anymore.
+struct Waiter {
+    shared: Arc<Shared>,
+    network_id: NetworkId,
+    receiver: Option<oneshot::Receiver<Slot>>,
+}
+
+impl Drop for Waiter {
+    fn drop(&mut self) {
+        // Closing the receiver marks the queued sender as cancelled.
+        drop(self.receiver.take());
+
+        let mut state = self.shared.state.lock().unwrap();
+        let Some(queue) = state.networks.get_mut(&self.network_id) else {
+            return;
+        };
+
+        let waiting = queue.waiting.len();
+        queue.waiting.retain(|sender| !sender.is_closed());
+        let cancelled = waiting - queue.waiting.len();
+
+        if cancelled > 0 {
+            CERTIFICATION_QUEUE_DEPTH.add(
+                -(cancelled as i64),
+                &[KeyValue::new("network_id", self.network_id.to_string())],
+            );
+        }
+    }
+}
+
+/// Prover slot held by a certification, released on drop.
+struct Slot {
+    shared: Option<Arc<Shared>>,
+}
+
+impl Slot {
+    fn new(shared: Arc<Shared>) -> Self {
+        Self {
+            shared: Some(shared),
+        }
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        if let Some(shared) = self.shared.take() {
+            shared.release();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::{NonZeroU32, NonZeroUsize};
+
+    use futures_util::FutureExt as _;
+
+    use super::*;
+
+    fn scheduler(max_in_flight: usize, weights: &[(u32, u32)]) -> Arc<Shared> {
+        Arc::new(Shared {
+            config: SchedulerConfig {
+                max_in_flight: NonZeroUsize::new(max_in_flight).unwrap(),
+                network_weights: weights
+                    .iter()
+                    .map(|(network_id, weight)| (*network_id, NonZeroU32::new(*weight).unwrap()))
+                    .collect(),
+            },
+            state: Mutex::new(State::default()),
+        })
+    }
+
+    #[test]
+    fn slots_are_bounded() {
+        let shared = scheduler(1, &[]);
+
+        let slot = shared.acquire(1.into()).now_or_never().unwrap();
+        let mut waiting = Box::pin(shared.acquire(2.into()));
+        assert!((&mut waiting).now_or_never().is_none());
+
+        drop(slot);
+        assert!(waiting.now_or_never().is_some());
+        assert_eq!(shared.state.lock().unwrap().in_flight, 1);
+    }
+
+    #[test]
+    fn cancelled_certification_hands_over_its_slot() {
+        let shared = scheduler(1, &[]);
+
+        let slot = shared.acquire(1.into()).now_or_never().unwrap();
+        let cancelled = shared.acquire(2.into());
+        let mut waiting = Box::pin(shared.acquire(3.into()));
+
+        drop(cancelled);
+        assert!(shared.state.lock().unwrap().networks[&NetworkId::new(2)]
+            .waiting
+            .is_empty());
+
+        drop(slot);
+
+        let slot = waiting.as_mut().now_or_never();
+        assert!(slot.is_some());
+
+        // The cancelled certification hasn't been charged.
+        let state = shared.state.lock().unwrap();
+        assert_eq!(state.networks[&NetworkId::new(2)].finish_tag, 0);
+        assert_eq!(state.in_flight, 1);
+    }
+
+    #[test]
+    fn networks_are_served_according_to_their_weight() {
+        let shared = scheduler(1, &[(2, 2)]);
+
+        let mut slot = shared.acquire(3.into()).now_or_never();
+        let mut waiting = Vec::new();
+        for network_id in [1, 2] {
+            for _ in 0..3 {
+                waiting.push((network_id, Box::pin(shared.acquire(network_id.into()))));
+            }
+        }
+
+        let mut served = Vec::new();
+        while !waiting.is_empty() {
+            drop(slot.take());
+
+            let index = waiting
+                .iter_mut()
+                .position(|(_, acquire)| {
+                    if let Some(acquired) = acquire.as_mut().now_or_never() {
+                        slot = Some(acquired);
+                        true
+                    } else {
+                        false
+                    }
+                })
+                .unwrap();
+            served.push(waiting.remove(index).0);
+        }
+
+        assert_eq!(served, vec![1, 2, 2, 1, 2, 1]);
+    }
+}
@@ -0,0 +1,287 @@
+//! Supervision of the network tasks.
+//!
+//! A network task stopped by an error is restarted by the orchestrator after
+//! an exponential backoff, so that a transient error doesn't stop the
+//! certification of its network until the node restarts. The [`Supervisor`]
+//! keeps track of the state of every network task, of its restarts and of its
+//! last error.
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    time::{Duration, Instant},
+};
+
+use agglayer_config::certificate_orchestrator::supervisor::SupervisorConfig;
+use agglayer_telemetry::{
+    certifier::{NETWORK_TASKS, NETWORK_TASK_RESTARTS},
+    KeyValue,
+};
+use agglayer_types::NetworkId;
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// Supervision state of a network task.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkTaskState {
+    /// The network task is running.
+    Running,
+    /// The network task failed and waits for its restart.
+    BackingOff,
+    /// The network task stopped and is not restarted.
+    Stopped,
+}
+
+impl NetworkTaskState {
+    fn as_str(&self) -> &'static str {
+        match self {
+            NetworkTaskState::Running => "running",
+            NetworkTaskState::BackingOff => "backing_off",
+            NetworkTaskState::Stopped => "stopped",
+        }
+    }
+}
+
+/// Supervision report of a network task.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NetworkTaskStatus {
+    pub network_id: NetworkId,
+    pub state: NetworkTaskState,
+    /// Number of restarts of the network task after a failure.
+    pub restarts: u32,
+    /// Error of the latest failure of the network task.
+    pub last_error: Option<String>,
+}
+
+struct Supervision {
+    state: NetworkTaskState,
+    restarts: u32,
+    consecutive_failures: u32,
+    last_error: Option<String>,
+    started_at: Instant,
+}
+
+/// Supervision of the network tasks of the orchestrator.
+pub(crate) struct Supervisor {
+    config: SupervisorConfig,
+    networks: BTreeMap<NetworkId, Supervision>,
+}
+
+impl Supervisor {
+    pub(crate) fn new(config: SupervisorConfig) -> Self {
+        Self {
+            config,
+            networks: BTreeMap::new(),
+        }
+    }
+
+    /// Record the start of the network task of a network.
+    pub(crate) fn started(&mut self, network_id: NetworkId) {
+        let (supervision, previous) = match self.networks.entry(network_id) {
+            Entry::Occupied(entry) => {
+                let supervision = entry.into_mut();
+                let previous = supervision.state;
+
+                (supervision, Some(previous))
+            }
+            Entry::Vacant(entry) => (
+                entry.insert(Supervision {
+                    state: NetworkTaskState::Running,
+                    restarts: 0,
+                    consecutive_failures: 0,
+                    last_error: None,
+                    started_at: Instant::now(),
+                }),
+                None,
+            ),
+        };
+
+        match previous {
+            Some(NetworkTaskState::BackingOff) => {
+                supervision.restarts += 1;
+                NETWORK_TASK_RESTARTS
+                    .add(1, &[KeyValue::new("network_id", network_id.to_string())]);
+            }
+            // A stopped network task is only spawned again on demand.
+            Some(NetworkTaskState::Stopped) => supervision.consecutive_failures = 0,
+            Some(NetworkTaskState::Running) | None => {}
+        }
+        supervision.started_at = Instant::now();
+
+        Self::transition(network_id, supervision, previous, NetworkTaskState::Running);
+    }
+
+    /// Record the end of the network task of a network, without error.
+    pub(crate) fn stopped(&mut self, network_id: NetworkId) {
+        if let Some(supervision) = self.networks.get_mut(&network_id) {
+            let previous = Some(supervision.state);
+            Self::transition(network_id, supervision, previous, NetworkTaskState::Stopped);
+        }
+    }
+
+    /// Record the failure of the network task of a network.
+    ///
+    /// Returns the delay before the restart of the network task, or `None` if
+    /// it failed too many consecutive times to be restarted.
+    pub(crate) fn failed(&mut self, network_id: NetworkId, error: &Error) -> Option<Duration> {
+        let supervision = self.networks.get_mut(&network_id)?;
+
+        // A network task which ran long enough before failing is healthy again.
+        if supervision.started_at.elapsed() >= self.config.max_backoff {
+            supervision.consecutive_failures = 0;
+        }
+        supervision.consecutive_failures += 1;
+        supervision.last_error = Some(error.to_string());
+
+        let previous = Some(supervision.state);
+        if self
+            .config
+            .max_restarts
+            .is_some_and(|max_restarts| supervision.consecutive_failures > max_restarts)
+        {
+            Self::transition(network_id, supervision, previous, NetworkTaskState::Stopped);
+
+            return None;
+        }
+
+        Self::transition(
+            network_id,
+            supervision,
+            previous,
+            NetworkTaskState::BackingOff,
+        );
+
+        Some(self.config.backoff_for(supervision.consecutive_failures))
+    }
+
+    /// Whether the network task of a network waits for its restart.
+    pub(crate) fn is_backing_off(&self, network_id: NetworkId) -> bool {
+        self.networks
+            .get(&network_id)
+            .is_some_and(|supervision| supervision.state == NetworkTaskState::BackingOff)
+    }
+
+    /// Whether the network task of a network is not to be spawned for the new
+    /// certificates of the network, as it waits for its restart or failed too
+    /// many consecutive times.
+    pub(crate) fn is_suspended(&self, network_id: NetworkId) -> bool {
+        self.networks
+            .get(&network_id)
+            .is_some_and(|supervision| match supervision.state {
+                NetworkTaskState::Running => false,
+                NetworkTaskState::BackingOff => true,
+                NetworkTaskState::Stopped => self
+                    .config
+                    .max_restarts
+                    .is_some_and(|max_restarts| supervision.consecutive_failures > max_restarts),
+            })
+    }
+
+    /// Report the supervision of every network task.
+    pub(crate) fn statuses(&self) -> Vec<NetworkTaskStatus> {
+        self.networks
+            .iter()
+            .map(|(network_id, supervision)| NetworkTaskStatus {
+                network_id: *network_id,
+                state: supervision.state,
+                restarts: supervision.restarts,
+                last_error: supervision.last_error.clone(),
+            })
+            .collect()
+    }
+
+    fn transition(
+        network_id: NetworkId,
+        supervision: &mut Supervision,
+        previous: Option<NetworkTaskState>,
+        state: NetworkTaskState,
+    ) {
+        let attributes = |state: NetworkTaskState| {
+            [
+                KeyValue::new("network_id", network_id.to_string()),
+                KeyValue::new("state", state.as_str()),
+            ]
+        };
+
+        if let Some(previous) = previous {
+            NETWORK_TASKS.add(-1, &attributes(previous));
+        }
+        NETWORK_TASKS.add(1, &attributes(state));
+
+        supervision.state = state;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn supervisor(max_restarts: Option<u32>) -> Supervisor {
+        Supervisor::new(SupervisorConfig {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_restarts,
+        })
+    }
+
+    #[test]
+    fn failed_network_task_backs_off_exponentially() {
+        let network_id = 1.into();
+        let mut supervisor = supervisor(None);
+
+        supervisor.started(network_id);
+        assert_eq!(
+            supervisor.failed(network_id, &Error::InternalError),
+            Some(Duration::from_secs(1))
+        );
+        assert!(supervisor.is_backing_off(network_id));
+
+        supervisor.started(network_id);
+        assert_eq!(
+            supervisor.failed(network_id, &Error::InternalError),
+            Some(Duration::from_secs(2))
+        );
+
+        supervisor.started(network_id);
+        assert_eq!(
+            supervisor.statuses(),
+            vec![NetworkTaskStatus {
+                network_id,
+                state: NetworkTaskState::Running,
+                restarts: 2,
+                last_error: Some(Error::InternalError.to_string()),
+            }]
+        );
+
+        // Running long enough resets the backoff.
+        supervisor.networks.get_mut(&network_id).unwrap().started_at -= Duration::from_secs(60);
+        assert_eq!(
+            supervisor.failed(network_id, &Error::InternalError),
+            Some(Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn network_task_failing_too_often_is_stopped() {
+        let network_id = 1.into();
+        let mut supervisor = supervisor(Some(1));
+
+        supervisor.started(network_id);
+        assert!(supervisor
+            .failed(network_id, &Error::InternalError)
+            .is_some());
+
+        supervisor.started(network_id);
+        assert_eq!(supervisor.failed(network_id, &Error::InternalError), None);
+        assert!(!supervisor.is_backing_off(network_id));
+        assert!(supervisor.is_suspended(network_id));
+        assert_eq!(supervisor.statuses()[0].state, NetworkTaskState::Stopped);
+
+        // Spawned again on demand, the network task gets a fresh budget.
+        supervisor.started(network_id);
+        assert!(!supervisor.is_suspended(network_id));
+        assert!(supervisor
+            .failed(network_id, &Error::InternalError)
+            .is_some());
+    }
+}
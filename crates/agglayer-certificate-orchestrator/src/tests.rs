@@ -30,14 +30,15 @@ use agglayer_types::{
 };
 use arc_swap::ArcSwap;
 use futures_util::{future::BoxFuture, poll};
+use mockall::predicate::eq;
 use mocks::{MockCertifier, MockEpochPacker};
 use rstest::fixture;
 use tokio::sync::{broadcast, mpsc};
 use tokio_util::sync::CancellationToken;
 
 use crate::{
-    CertificateInput, CertificateOrchestrator, Certifier, CertifierOutput, CertifierResult,
-    EpochPacker, Error, PreCertificationError,
+    CertificateInput, CertificateOrchestrator, CertificationError, Certifier, CertifierOutput,
+    CertifierResult, EpochPacker, Error, PreCertificationError, ReorgFuture,
 };
 
 pub(crate) mod mocks;
@@ -70,6 +71,12 @@ impl PerEpochReader for DummyPendingStore {
     ) -> Result<Option<Proof>, agglayer_storage::error::Error> {
         todo!()
     }
+    fn get_settlement_tx_hashes(
+        &self,
+        _certificate_index: CertificateIndex,
+    ) -> Result<Vec<agglayer_types::Hash>, agglayer_storage::error::Error> {
+        todo!()
+    }
     fn get_end_checkpoint(&self) -> BTreeMap<NetworkId, Height> {
         todo!()
     }
@@ -98,6 +105,14 @@ impl PerEpochWriter for DummyPendingStore {
     fn start_packing(&self) -> Result<(), agglayer_storage::error::Error> {
         todo!()
     }
+
+    fn set_settlement_tx_hashes(
+        &self,
+        _certificate_index: CertificateIndex,
+        _tx_hashes: &[agglayer_types::Hash],
+    ) -> Result<(), agglayer_storage::error::Error> {
+        todo!()
+    }
 }
 
 impl StateReader for DummyPendingStore {
@@ -149,14 +164,57 @@ impl StateReader for DummyPendingStore {
             .and_then(|id| self.certificate_headers.read().unwrap().get(id).cloned()))
     }
 
+    fn get_certificate_headers_by_cursor(
+        &self,
+        network_id: NetworkId,
+        from_height: Height,
+        limit: usize,
+    ) -> Result<Vec<CertificateHeader>, agglayer_storage::error::Error> {
+        let certificate_headers = self.certificate_headers.read().unwrap();
+
+        Ok(self
+            .certificate_per_network
+            .read()
+            .unwrap()
+            .range((network_id, from_height)..=(network_id, Height::MAX))
+            .take(limit)
+            .filter_map(|(_, id)| certificate_headers.get(id).cloned())
+            .collect())
+    }
+
     fn read_local_network_state(
         &self,
         _network_id: NetworkId,
     ) -> Result<Option<LocalNetworkStateData>, agglayer_storage::error::Error> {
         todo!()
     }
+
+    fn get_local_exit_tree_proof(
+        &self,
+        _network_id: NetworkId,
+        _leaf_index: u32,
+        _leaf_count: Option<u32>,
+    ) -> Result<Option<agglayer_types::LocalExitTreeProof>, agglayer_storage::error::Error> {
+        todo!()
+    }
+}
+impl EpochStoreReader for DummyPendingStore {
+    fn get_certificate_in_epoch(
+        &self,
+        _epoch_number: EpochNumber,
+        _certificate_index: CertificateIndex,
+    ) -> Result<Option<Certificate>, agglayer_storage::error::Error> {
+        todo!()
+    }
+
+    fn get_proof_in_epoch(
+        &self,
+        _epoch_number: EpochNumber,
+        _certificate_index: CertificateIndex,
+    ) -> Result<Option<Proof>, agglayer_storage::error::Error> {
+        todo!()
+    }
 }
-impl EpochStoreReader for DummyPendingStore {}
 
 impl EpochStoreWriter for DummyPendingStore {
     type PerEpochStore = Self;
@@ -192,6 +250,15 @@ impl PendingCertificateWriter for DummyPendingStore {
         Ok(())
     }
 
+    fn set_pending_since(
+        &self,
+        _network_id: NetworkId,
+        _height: Height,
+        _epoch_number: EpochNumber,
+    ) -> Result<(), agglayer_storage::error::Error> {
+        Ok(())
+    }
+
     fn insert_generated_proof(
         &self,
         certificate_id: &CertificateId,
@@ -275,6 +342,7 @@ impl StateWriter for DummyPendingStore {
                 new_local_exit_root: certificate.new_local_exit_root.into(),
                 status,
                 metadata: certificate.metadata,
+                settlement_tx_hash: None,
             },
         );
 
@@ -298,6 +366,23 @@ impl StateWriter for DummyPendingStore {
         Ok(())
     }
 
+    fn update_certificate_header_settlement_tx_hash(
+        &self,
+        certificate_id: &CertificateId,
+        tx_hash: &agglayer_types::Hash,
+    ) -> Result<(), agglayer_storage::error::Error> {
+        if let Some(entry) = self
+            .certificate_headers
+            .write()
+            .unwrap()
+            .get_mut(certificate_id)
+        {
+            entry.settlement_tx_hash = Some(*tx_hash);
+        }
+
+        Ok(())
+    }
+
     fn set_latest_settled_certificate_for_network(
         &self,
         _network_id: &NetworkId,
@@ -312,11 +397,20 @@ impl StateWriter for DummyPendingStore {
     fn write_local_network_state(
         &self,
         _network_id: &NetworkId,
+        _height: &Height,
         _new_state: &LocalNetworkStateData,
         _new_leaves: &[agglayer_types::Hash],
     ) -> Result<(), agglayer_storage::error::Error> {
         todo!()
     }
+
+    fn rewind_local_network_state(
+        &self,
+        _network_id: &NetworkId,
+        _height: &Height,
+    ) -> Result<(), agglayer_storage::error::Error> {
+        todo!()
+    }
 }
 
 impl PendingCertificateReader for DummyPendingStore {
@@ -356,6 +450,43 @@ impl PendingCertificateReader for DummyPendingStore {
             .map(|x| x.2))
     }
 
+    fn get_networks_with_pending_certificates(
+        &self,
+    ) -> Result<Vec<NetworkId>, agglayer_storage::error::Error> {
+        let mut networks: Vec<NetworkId> = self
+            .pending_certificate
+            .read()
+            .unwrap()
+            .keys()
+            .map(|(network_id, _height)| *network_id)
+            .collect();
+        networks.dedup();
+
+        Ok(networks)
+    }
+
+    fn get_pending_certificates_for_network(
+        &self,
+        network_id: &NetworkId,
+    ) -> Result<Vec<Certificate>, agglayer_storage::error::Error> {
+        Ok(self
+            .pending_certificate
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|((certificate_network_id, _height), _)| certificate_network_id == network_id)
+            .map(|(_, certificate)| certificate.clone())
+            .collect())
+    }
+
+    fn get_pending_since(
+        &self,
+        _network_id: NetworkId,
+        _height: Height,
+    ) -> Result<Option<EpochNumber>, agglayer_storage::error::Error> {
+        Ok(None)
+    }
+
     fn get_certificate(
         &self,
         network_id: NetworkId,
@@ -738,6 +869,9 @@ pub(crate) fn create_orchestrator_mock(
         pending_store
             .expect_get_current_proven_height()
             .returning(|| Ok(vec![]));
+        pending_store
+            .expect_get_networks_with_pending_certificates()
+            .returning(|| Ok(vec![]));
 
         pending_store
     }));
@@ -775,6 +909,737 @@ pub(crate) fn create_orchestrator_mock(
     )
 }
 
+// On startup, the unsettled certificate of a network is handed to its network
+// task, and the networks with pending certificates are notified.
+#[test]
+fn recover_network_tasks_on_startup() {
+    let mut pending_store = MockPendingStore::new();
+    let mut state_store = MockStateStore::new();
+
+    let proven = Certificate::new_for_test(1.into(), 0);
+    let proven_id = proven.hash();
+    let pending = Certificate::new_for_test(2.into(), 0);
+
+    pending_store
+        .expect_get_current_proven_height()
+        .once()
+        .returning(move || Ok(vec![ProvenCertificate(proven_id, 1.into(), 0)]));
+    pending_store
+        .expect_get_networks_with_pending_certificates()
+        .once()
+        .returning(|| Ok(vec![1.into(), 2.into()]));
+    pending_store
+        .expect_get_certificate()
+        .with(eq(NetworkId::new(1)), eq(0))
+        .once()
+        .return_once(move |_, _| Ok(Some(proven)));
+    pending_store
+        .expect_get_certificate()
+        .with(eq(NetworkId::new(1)), eq(1))
+        .once()
+        .returning(|_, _| Ok(None));
+    pending_store
+        .expect_get_certificate()
+        .with(eq(NetworkId::new(2)), eq(0))
+        .once()
+        .return_once(move |_, _| Ok(Some(pending)));
+
+    state_store
+        .expect_get_latest_settled_certificate_per_network()
+        .times(2)
+        .returning(|_| Ok(None));
+    state_store
+        .expect_get_certificate_header()
+        .with(eq(proven_id))
+        .once()
+        .returning(|certificate_id| {
+            Ok(Some(CertificateHeader {
+                network_id: 1.into(),
+                height: 0,
+                epoch_number: None,
+                certificate_index: None,
+                certificate_id: *certificate_id,
+                prev_local_exit_root: [1; 32].into(),
+                new_local_exit_root: [0; 32].into(),
+                metadata: [0; 32].into(),
+                status: CertificateStatus::Proven,
+                settlement_tx_hash: None,
+            }))
+        });
+    state_store
+        .expect_read_local_network_state()
+        .times(2)
+        .returning(|_| Ok(None));
+
+    let (_, mut orchestrator) = create_orchestrator_mock(
+        MockOrchestrator::builder()
+            .pending_store(pending_store)
+            .state_store(state_store)
+            .build(),
+        clock(),
+    );
+
+    orchestrator.recover_network_tasks().unwrap();
+
+    assert!(orchestrator.recovered_certificates.is_empty());
+    assert_eq!(
+        orchestrator
+            .spawned_network_tasks
+            .keys()
+            .copied()
+            .collect::<Vec<_>>(),
+        vec![1.into(), 2.into()]
+    );
+
+    // Only the network 2 is notified of a pending certificate, the network 1
+    // first resumes the settlement of its proven certificate.
+    let queued = |network_id: NetworkId| {
+        let sender = &orchestrator.spawned_network_tasks[&network_id].sender;
+        sender.max_capacity() - sender.capacity()
+    };
+    assert_eq!(queued(1.into()), 0);
+    assert_eq!(queued(2.into()), 1);
+}
+
+// A network task stopped by an error is restarted once its backoff elapsed.
+#[tokio::test]
+async fn failed_network_task_is_restarted() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use agglayer_config::certificate_orchestrator::supervisor::SupervisorConfig;
+    use futures_util::StreamExt as _;
+
+    use crate::{supervisor::Supervisor, NetworkTaskState};
+
+    let network_id: NetworkId = 1.into();
+    let mut state_store = MockStateStore::new();
+    let calls = AtomicUsize::new(0);
+    state_store
+        .expect_get_latest_settled_certificate_per_network()
+        .returning(move |_| {
+            if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(agglayer_storage::error::Error::Unexpected(
+                    "transient failure".into(),
+                ))
+            } else {
+                Ok(None)
+            }
+        });
+    state_store
+        .expect_read_local_network_state()
+        .returning(|_| Ok(None));
+
+    let mut pending_store = MockPendingStore::new();
+    pending_store
+        .expect_get_current_proven_height()
+        .returning(|| Ok(vec![]));
+    pending_store
+        .expect_get_certificate()
+        .with(eq(network_id), eq(0))
+        .returning(|_, _| Ok(None));
+
+    let (_, mut orchestrator) = create_orchestrator_mock(
+        MockOrchestrator::builder()
+            .state_store(state_store)
+            .pending_store(pending_store)
+            .build(),
+        clock(),
+    );
+    orchestrator.supervisor = Supervisor::new(SupervisorConfig {
+        initial_backoff: std::time::Duration::from_millis(10),
+        ..Default::default()
+    });
+
+    orchestrator.spawn_network_task(network_id).unwrap();
+    let (_, task_id, result) = orchestrator.network_tasks.next().await.unwrap();
+    assert!(result.is_err());
+
+    orchestrator.handle_network_task_end(network_id, task_id, result);
+    assert!(orchestrator.spawned_network_tasks.is_empty());
+    assert_eq!(
+        orchestrator.supervisor.statuses()[0].state,
+        NetworkTaskState::BackingOff
+    );
+
+    let restarted = orchestrator.restart_timers.next().await.unwrap();
+    orchestrator.restart_network_task(restarted);
+
+    assert!(orchestrator.spawned_network_tasks.contains_key(&network_id));
+    let status = &orchestrator.supervisor.statuses()[0];
+    assert_eq!(status.state, NetworkTaskState::Running);
+    assert_eq!(status.restarts, 1);
+    assert!(status.last_error.is_some());
+}
+
+// A network task failing while its settlement is in flight is restarted
+// without settling its certificate a second time: the recovered task awaits
+// the settlement in flight.
+#[tokio::test]
+async fn restarted_network_task_awaits_the_settlement_in_flight() {
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    use futures_util::StreamExt as _;
+    use tokio::sync::oneshot;
+
+    let network_id: NetworkId = 1.into();
+    let certificate = Certificate::new_for_test(network_id, 0);
+    let certificate_id = certificate.hash();
+    let settled = SettledCertificate(certificate_id, 0, 0, 0);
+
+    let mut state_store = MockStateStore::new();
+    let calls = AtomicUsize::new(0);
+    state_store
+        .expect_get_latest_settled_certificate_per_network()
+        .returning(move |_| {
+            if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(agglayer_storage::error::Error::Unexpected(
+                    "transient failure".into(),
+                ))
+            } else {
+                Ok(None)
+            }
+        });
+    state_store
+        .expect_read_local_network_state()
+        .returning(|_| Ok(None));
+    state_store
+        .expect_get_certificate_header()
+        .with(eq(certificate_id))
+        .returning(|certificate_id| {
+            Ok(Some(CertificateHeader {
+                network_id: 1.into(),
+                height: 0,
+                epoch_number: None,
+                certificate_index: None,
+                certificate_id: *certificate_id,
+                prev_local_exit_root: [1; 32].into(),
+                new_local_exit_root: [0; 32].into(),
+                metadata: [0; 32].into(),
+                status: CertificateStatus::Proven,
+                settlement_tx_hash: None,
+            }))
+        });
+    state_store
+        .expect_update_certificate_header_status()
+        .returning(|_, _| Ok(()));
+    state_store
+        .expect_write_local_network_state()
+        .once()
+        .returning(|_, _, _, _| Ok(()));
+
+    let mut pending_store = MockPendingStore::new();
+    pending_store
+        .expect_get_current_proven_height()
+        .returning(move || Ok(vec![ProvenCertificate(certificate_id, network_id, 0)]));
+    pending_store
+        .expect_get_certificate()
+        .with(eq(network_id), eq(0))
+        .return_once(move |_, _| Ok(Some(certificate)));
+    pending_store
+        .expect_get_certificate()
+        .with(eq(network_id), eq(1))
+        .returning(|_, _| Ok(None));
+    pending_store
+        .expect_set_latest_proven_certificate_per_network()
+        .returning(|_, _, _| Ok(()));
+
+    let mut certifier = MockCertifier::new();
+    certifier
+        .expect_execute_natively()
+        .once()
+        .returning(|state, _| Box::pin(async move { Ok(state) }));
+
+    let mut current_epoch = MockPerEpochStore::new();
+    current_epoch
+        .expect_add_certificate()
+        .once()
+        .returning(|_, _| Ok((0, 0)));
+
+    let (settle, settlement) = oneshot::channel();
+    let mut epoch_packer = MockEpochPacker::new();
+    epoch_packer
+        .expect_settle_certificate()
+        .once()
+        .return_once(move |_, _, _| {
+            Ok(Box::pin(async move {
+                Ok((network_id, settlement.await.expect("Settlement dropped")))
+            }))
+        });
+
+    let (_, mut orchestrator) = create_orchestrator_mock(
+        MockOrchestrator::builder()
+            .state_store(state_store)
+            .pending_store(pending_store)
+            .certifier(certifier)
+            .epoch_packer(epoch_packer)
+            .current_epoch(current_epoch)
+            .build(),
+        clock(),
+    );
+
+    // The network task requested the settlement of its certificate, then failed.
+    orchestrator.spawn_network_task(network_id).unwrap();
+    let (response, _) = oneshot::channel();
+    orchestrator
+        .handle_proven_certificate((response, ProvenCertificate(certificate_id, network_id, 0)));
+    let (_, task_id, result) = orchestrator.network_tasks.next().await.unwrap();
+    assert!(result.is_err());
+    orchestrator.handle_network_task_end(network_id, task_id, result);
+
+    orchestrator.restart_network_task(network_id);
+    assert!(orchestrator.spawned_network_tasks.contains_key(&network_id));
+
+    // The recovered task requests the settlement again, which is awaited.
+    let notification = tokio::select! {
+        _ = orchestrator.network_tasks.next() => panic!("The recovered network task stopped"),
+        notification = orchestrator.certification_notification.recv() => notification.unwrap(),
+    };
+    orchestrator.handle_proven_certificate(notification);
+    assert_eq!(orchestrator.settlement_tasks.len(), 1);
+
+    settle.send(settled).unwrap();
+    let settlement_result = orchestrator.settlement_tasks.next().await.unwrap();
+    _ = orchestrator.handle_settlement_result(settlement_result);
+    assert!(orchestrator.settlement_notifier.is_empty());
+
+    // The recovered task persists the new local state once notified.
+    assert!(tokio::time::timeout(
+        Duration::from_millis(100),
+        orchestrator.network_tasks.next()
+    )
+    .await
+    .is_err());
+}
+
+// The certificates whose settlement vanished in an L1 reorg are recovered by
+// the network task respawned from the rolled back local state, which persists
+// the new local state once settled again.
+#[tokio::test]
+async fn reorged_settlements_are_settled_again() {
+    use std::time::Duration;
+
+    use futures_util::StreamExt as _;
+
+    let network_id: NetworkId = 1.into();
+    let certificate = Certificate::new_for_test(network_id, 0);
+    let certificate_id = certificate.hash();
+    let settled = SettledCertificate(certificate_id, 0, 0, 3);
+
+    let mut epoch_packer = MockEpochPacker::new();
+    let reorged = settled.clone();
+    epoch_packer
+        .expect_recheck_settlements()
+        .with(eq(12))
+        .once()
+        .return_once(move |_| Ok(Box::pin(async move { Ok(vec![(network_id, reorged)]) })));
+    let resettled = settled.clone();
+    epoch_packer
+        .expect_settle_certificate()
+        .with(mockall::predicate::always(), eq(3), eq(certificate_id))
+        .once()
+        .return_once(move |_, _, _| Ok(Box::pin(async move { Ok((network_id, resettled)) })));
+
+    // The settlement and the local state were rolled back by the packer.
+    let mut state_store = MockStateStore::new();
+    state_store
+        .expect_get_latest_settled_certificate_per_network()
+        .returning(|_| Ok(None));
+    state_store
+        .expect_read_local_network_state()
+        .returning(|_| Ok(None));
+    state_store
+        .expect_get_certificate_header()
+        .with(eq(certificate_id))
+        .returning(|certificate_id| {
+            Ok(Some(CertificateHeader {
+                network_id: 1.into(),
+                height: 0,
+                epoch_number: Some(0),
+                certificate_index: Some(3),
+                certificate_id: *certificate_id,
+                prev_local_exit_root: [1; 32].into(),
+                new_local_exit_root: [0; 32].into(),
+                metadata: [0; 32].into(),
+                status: CertificateStatus::Candidate,
+                settlement_tx_hash: None,
+            }))
+        });
+    state_store
+        .expect_write_local_network_state()
+        .once()
+        .with(
+            eq(network_id),
+            eq(0),
+            mockall::predicate::always(),
+            mockall::predicate::always(),
+        )
+        .returning(|_, _, _, _| Ok(()));
+
+    let mut epochs_store = MockEpochsStore::new();
+    epochs_store
+        .expect_get_certificate_in_epoch()
+        .with(eq(0), eq(3))
+        .once()
+        .return_once(move |_, _| Ok(Some(certificate)));
+
+    let mut pending_store = MockPendingStore::new();
+    pending_store
+        .expect_get_current_proven_height()
+        .returning(|| Ok(vec![]));
+    pending_store
+        .expect_get_certificate()
+        .with(eq(network_id), eq(1))
+        .returning(|_, _| Ok(None));
+
+    let mut certifier = MockCertifier::new();
+    certifier
+        .expect_execute_natively()
+        .once()
+        .returning(|state, _| Box::pin(async move { Ok(state) }));
+
+    let mut current_epoch = MockPerEpochStore::new();
+    current_epoch.expect_get_epoch_number().return_const(0u64);
+
+    let (_, mut orchestrator) = create_orchestrator_mock(
+        MockOrchestrator::builder()
+            .state_store(state_store)
+            .pending_store(pending_store)
+            .epochs_store(epochs_store)
+            .certifier(certifier)
+            .epoch_packer(epoch_packer)
+            .current_epoch(current_epoch)
+            .build(),
+        clock(),
+    );
+
+    orchestrator.handle_l1_reorg(12);
+    let reorged = orchestrator.reorg_tasks.next().await.unwrap().unwrap();
+    orchestrator.handle_reorged_settlements(reorged);
+    assert!(orchestrator.spawned_network_tasks.contains_key(&network_id));
+
+    // The respawned task requests the settlement of the reverted certificate.
+    let notification = tokio::select! {
+        _ = orchestrator.network_tasks.next() => panic!("The respawned network task stopped"),
+        notification = orchestrator.certification_notification.recv() => notification.unwrap(),
+    };
+    orchestrator.handle_proven_certificate(notification);
+
+    let (settled_id, result) = orchestrator.settlement_tasks.next().await.unwrap();
+    assert_eq!(settled_id, certificate_id);
+    assert_eq!(result.as_ref().unwrap(), &(network_id, settled));
+    _ = orchestrator.handle_settlement_result((settled_id, result));
+    assert!(orchestrator.settlement_notifier.is_empty());
+
+    // The respawned task persists the new local state once notified.
+    assert!(tokio::time::timeout(
+        Duration::from_millis(100),
+        orchestrator.network_tasks.next()
+    )
+    .await
+    .is_err());
+}
+
+// The certificates of a paused network are not dispatched until the network is
+// resumed, and the pause flag is persisted.
+#[test]
+fn paused_network_is_dispatched_once_resumed() {
+    use tokio::sync::oneshot;
+
+    use crate::AdminCommand;
+
+    let network_id: NetworkId = 1.into();
+    let certificate = Certificate::new_for_test(network_id, 0);
+    let certificate_id = certificate.hash();
+
+    let mut state_store = MockStateStore::new();
+    state_store
+        .expect_set_network_paused()
+        .with(eq(network_id), eq(true))
+        .once()
+        .returning(|_, _| Ok(()));
+    state_store
+        .expect_set_network_paused()
+        .with(eq(network_id), eq(false))
+        .once()
+        .returning(|_, _| Ok(()));
+    state_store
+        .expect_get_latest_settled_certificate_per_network()
+        .returning(|_| Ok(None));
+    state_store
+        .expect_read_local_network_state()
+        .returning(|_| Ok(None));
+
+    let mut pending_store = MockPendingStore::new();
+    pending_store
+        .expect_get_current_proven_height()
+        .returning(|| Ok(vec![]));
+    pending_store
+        .expect_get_certificate()
+        .with(eq(network_id), eq(0))
+        .once()
+        .return_once(move |_, _| Ok(Some(certificate)));
+
+    let (_, mut orchestrator) = create_orchestrator_mock(
+        MockOrchestrator::builder()
+            .state_store(state_store)
+            .pending_store(pending_store)
+            .build(),
+        clock(),
+    );
+
+    let (response, mut paused) = oneshot::channel();
+    orchestrator.handle_admin_command(AdminCommand::PauseNetwork {
+        network_id,
+        response,
+    });
+    assert_eq!(paused.try_recv().unwrap(), Ok(()));
+
+    orchestrator
+        .receive_certificates([(network_id, 0, certificate_id)])
+        .unwrap();
+    assert!(orchestrator.spawned_network_tasks.is_empty());
+
+    let (response, mut resumed) = oneshot::channel();
+    orchestrator.handle_admin_command(AdminCommand::ResumeNetwork {
+        network_id,
+        response,
+    });
+    assert_eq!(resumed.try_recv().unwrap(), Ok(()));
+    assert!(orchestrator.spawned_network_tasks.contains_key(&network_id));
+}
+
+// A network task respawned from the admin interface is only replaced once the
+// cancelled task completed.
+#[tokio::test]
+async fn respawned_network_task_waits_for_the_replaced_task() {
+    use futures_util::StreamExt as _;
+    use tokio::sync::oneshot;
+
+    use crate::AdminCommand;
+
+    let network_id: NetworkId = 1.into();
+
+    let mut state_store = MockStateStore::new();
+    state_store
+        .expect_get_latest_settled_certificate_per_network()
+        .returning(|_| Ok(None));
+    state_store
+        .expect_read_local_network_state()
+        .returning(|_| Ok(None));
+
+    let mut pending_store = MockPendingStore::new();
+    pending_store
+        .expect_get_current_proven_height()
+        .returning(|| Ok(vec![]));
+    pending_store
+        .expect_get_certificate()
+        .with(eq(network_id), eq(0))
+        .returning(|_, _| Ok(None));
+
+    let (_, mut orchestrator) = create_orchestrator_mock(
+        MockOrchestrator::builder()
+            .state_store(state_store)
+            .pending_store(pending_store)
+            .build(),
+        clock(),
+    );
+
+    orchestrator.spawn_network_task(network_id).unwrap();
+    let replaced_id = orchestrator.spawned_network_tasks[&network_id].task_id;
+
+    let (response, mut respawned) = oneshot::channel();
+    orchestrator.handle_admin_command(AdminCommand::RespawnNetworkTask {
+        network_id,
+        response,
+    });
+
+    // The cancelled task is still running, the new one isn't spawned yet.
+    assert!(respawned.try_recv().is_err());
+    let replaced = &orchestrator.spawned_network_tasks[&network_id];
+    assert_eq!(replaced.task_id, replaced_id);
+    assert!(replaced.cancellation_token.is_cancelled());
+
+    let (_, task_id, result) = orchestrator.network_tasks.next().await.unwrap();
+    assert_eq!(task_id, replaced_id);
+    orchestrator.handle_network_task_end(network_id, task_id, result);
+
+    assert_eq!(respawned.try_recv().unwrap(), Ok(()));
+    assert_ne!(
+        orchestrator.spawned_network_tasks[&network_id].task_id,
+        replaced_id
+    );
+}
+
+// A settlement requested again while in flight is awaited instead of being
+// sent twice, every requester being notified of its result.
+#[tokio::test]
+async fn settlement_in_flight_is_not_requested_again() {
+    use futures_util::StreamExt as _;
+    use tokio::sync::oneshot;
+
+    let network_id: NetworkId = 1.into();
+    let certificate_id = Certificate::new_for_test(network_id, 0).hash();
+    let settled = SettledCertificate(certificate_id, 0, 0, 0);
+
+    let mut state_store = MockStateStore::new();
+    state_store
+        .expect_get_certificate_header()
+        .with(eq(certificate_id))
+        .once()
+        .returning(|_| Ok(None));
+
+    let mut current_epoch = MockPerEpochStore::new();
+    current_epoch
+        .expect_add_certificate()
+        .with(eq(network_id), eq(0))
+        .once()
+        .returning(|_, _| Ok((0, 0)));
+
+    let mut epoch_packer = MockEpochPacker::new();
+    let result = settled.clone();
+    epoch_packer
+        .expect_settle_certificate()
+        .once()
+        .return_once(move |_, _, _| Ok(Box::pin(async move { Ok((network_id, result)) })));
+
+    let (_, mut orchestrator) = create_orchestrator_mock(
+        MockOrchestrator::builder()
+            .state_store(state_store)
+            .current_epoch(current_epoch)
+            .epoch_packer(epoch_packer)
+            .build(),
+        clock(),
+    );
+
+    let (first, mut first_settlement) = oneshot::channel();
+    orchestrator
+        .handle_proven_certificate((first, ProvenCertificate(certificate_id, network_id, 0)));
+    let (second, mut second_settlement) = oneshot::channel();
+    orchestrator
+        .handle_proven_certificate((second, ProvenCertificate(certificate_id, network_id, 0)));
+    assert_eq!(orchestrator.settlement_tasks.len(), 1);
+
+    let settlement_result = orchestrator.settlement_tasks.next().await.unwrap();
+    _ = orchestrator.handle_settlement_result(settlement_result);
+
+    assert_eq!(first_settlement.try_recv().unwrap(), Ok(settled.clone()));
+    assert_eq!(second_settlement.try_recv().unwrap(), Ok(settled));
+    assert!(orchestrator.settlements_in_flight.is_empty());
+}
+
+// Once cancelled, the orchestrator waits for the settlements in flight before
+// stopping.
+#[tokio::test]
+async fn cancelled_orchestrator_drains_the_settlements_in_flight() {
+    use std::time::Duration;
+
+    use tokio::sync::oneshot;
+
+    let network_id: NetworkId = 1.into();
+    let certificate_id = Certificate::new_for_test(network_id, 0).hash();
+    let settled = SettledCertificate(certificate_id, 0, 0, 0);
+
+    let (settle, settlement) = oneshot::channel();
+    let mut epoch_packer = MockEpochPacker::new();
+    epoch_packer
+        .expect_settle_certificate()
+        .once()
+        .return_once(move |_, _, _| {
+            Ok(Box::pin(async move {
+                Ok((network_id, settlement.await.expect("Settlement dropped")))
+            }))
+        });
+
+    let (_, mut orchestrator) = create_orchestrator_mock(
+        MockOrchestrator::builder()
+            .epoch_packer(epoch_packer)
+            .build(),
+        clock(),
+    );
+    orchestrator.drain_timeout = Duration::from_secs(60);
+
+    orchestrator
+        .settle_certificate(Arc::new(MockPerEpochStore::new()), 0, certificate_id)
+        .unwrap();
+    orchestrator.cancellation_token.cancel();
+
+    assert!(matches!(poll!(&mut orchestrator), Poll::Pending));
+
+    settle.send(settled).unwrap();
+    tokio::time::timeout(Duration::from_secs(1), &mut orchestrator)
+        .await
+        .expect("The orchestrator didn't stop once drained");
+    assert!(orchestrator.settlement_tasks.is_empty());
+}
+
+// The certificates proven while draining are not settled, their notifier being
+// kept so that they stay proven.
+#[tokio::test]
+async fn cancelled_orchestrator_defers_the_settlement_of_proven_certificates() {
+    use std::time::Duration;
+
+    use tokio::sync::oneshot;
+
+    let network_id: NetworkId = 1.into();
+    let certificate_id = Certificate::new_for_test(network_id, 0).hash();
+
+    let (_, mut orchestrator) = create_orchestrator_mock(MockOrchestrator::default(), clock());
+    orchestrator.drain_timeout = Duration::from_secs(60);
+
+    let (response, mut settlement) = oneshot::channel();
+    orchestrator
+        .certification_notification_sender
+        .send((response, ProvenCertificate(certificate_id, network_id, 0)))
+        .await
+        .unwrap();
+    orchestrator.cancellation_token.cancel();
+
+    tokio::time::timeout(Duration::from_secs(1), &mut orchestrator)
+        .await
+        .expect("The orchestrator didn't stop once drained");
+    assert!(orchestrator.deferred_settlements.contains_key(&network_id));
+    assert!(matches!(
+        settlement.try_recv(),
+        Err(oneshot::error::TryRecvError::Empty)
+    ));
+}
+
+// The settlements still in flight once the drain deadline elapsed are
+// abandoned.
+#[tokio::test]
+async fn cancelled_orchestrator_abandons_the_settlements_after_the_deadline() {
+    use std::time::Duration;
+
+    let certificate_id = Certificate::new_for_test(1.into(), 0).hash();
+
+    let mut epoch_packer = MockEpochPacker::new();
+    epoch_packer
+        .expect_settle_certificate()
+        .once()
+        .return_once(|_, _, _| Ok(Box::pin(futures_util::future::pending())));
+
+    let (_, mut orchestrator) = create_orchestrator_mock(
+        MockOrchestrator::builder()
+            .epoch_packer(epoch_packer)
+            .build(),
+        clock(),
+    );
+    orchestrator.drain_timeout = Duration::from_millis(10);
+
+    orchestrator
+        .settle_certificate(Arc::new(MockPerEpochStore::new()), 0, certificate_id)
+        .unwrap();
+    orchestrator.cancellation_token.cancel();
+
+    tokio::time::timeout(Duration::from_secs(1), &mut orchestrator)
+        .await
+        .expect("The orchestrator didn't stop at the drain deadline");
+    assert_eq!(orchestrator.settlement_tasks.len(), 1);
+}
+
 #[derive(Clone)]
 pub(crate) struct Check {
     pending_store: Arc<PendingStore>,
@@ -856,6 +1721,10 @@ impl EpochPacker for Check {
 
         Ok(Box::pin(async { Ok(()) }))
     }
+
+    fn recheck_settlements(&self, _from_block: u64) -> Result<ReorgFuture, Error> {
+        Ok(Box::pin(async { Ok(Vec::new()) }))
+    }
 }
 
 impl CertificateInput for () {
@@ -898,4 +1767,11 @@ impl Certifier for Check {
         _ = self.executed.try_send(result.clone());
         Ok(Box::pin(async move { Ok(result) }))
     }
+    fn execute_natively(
+        &self,
+        local_state: LocalNetworkStateData,
+        _certificate: Certificate,
+    ) -> BoxFuture<'static, Result<LocalNetworkStateData, CertificationError>> {
+        Box::pin(async move { Ok(local_state) })
+    }
 }
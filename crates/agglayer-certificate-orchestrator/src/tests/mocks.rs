@@ -4,7 +4,7 @@ use mockall::mock;
 
 use crate::{
     error::{CertificationError, PreCertificationError},
-    Certifier, CertifierOutput, EpochPacker, Error, SettlementFuture,
+    Certifier, CertifierOutput, EpochPacker, Error, ReorgFuture, SettlementFuture,
 };
 
 mock! {
@@ -16,6 +16,12 @@ mock! {
             network_id: NetworkId,
             height: Height,
         ) -> Result<BoxFuture<'static, Result<CertifierOutput, CertificationError>>, PreCertificationError>;
+
+        fn execute_natively(
+            &self,
+            state: agglayer_types::LocalNetworkStateData,
+            certificate: agglayer_types::Certificate,
+        ) -> BoxFuture<'static, Result<agglayer_types::LocalNetworkStateData, CertificationError>>;
     }
 }
 
@@ -30,5 +36,6 @@ mock! {
             certificate_index: agglayer_types::CertificateIndex,
             certificate_id: agglayer_types::CertificateId,
         ) -> Result<SettlementFuture<'static>, Error>;
+        fn recheck_settlements(&self, from_block: u64) -> Result<ReorgFuture<'static>, Error>;
     }
 }
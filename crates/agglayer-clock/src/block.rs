@@ -1,4 +1,6 @@
 use std::{
+    collections::BTreeMap,
+    future::Future,
     num::NonZeroU64,
     sync::{
         atomic::{AtomicU64, Ordering},
@@ -8,13 +10,15 @@ use std::{
 };
 
 use alloy::{
+    eips::BlockId,
     network::Ethereum,
+    primitives::B256,
     providers::{
         fillers::{BlobGasFiller, ChainIdFiller, FillProvider, GasFiller, JoinFill, NonceFiller},
         Identity, Provider, ProviderBuilder, RootProvider, WsConnect,
     },
     pubsub::{ConnectionHandle, PubSubConnect, PubSubFrontend},
-    rpc::client::ClientBuilder,
+    rpc::{client::ClientBuilder, types::BlockTransactionsKind},
     transports::{impl_future, TransportErrorKind, TransportResult},
 };
 use backoff::ExponentialBackoff;
@@ -27,6 +31,9 @@ use crate::{Clock, ClockRef, Error, Event, BROADCAST_CHANNEL_SIZE};
 #[cfg(test)]
 mod tests;
 
+/// Number of recent L1 Blocks whose hash is kept to detect the reorgs.
+const BLOCK_HASH_HISTORY: usize = 256;
+
 type BlockProvider = FillProvider<
     JoinFill<
         Identity,
@@ -52,6 +59,8 @@ pub struct BlockClock<P> {
     current_epoch: Arc<AtomicU64>,
     /// The last seen block number.
     latest_seen_block: u64,
+    /// The hashes of the recent L1 Blocks, by L1 Block number.
+    recent_blocks: BTreeMap<u64, B256>,
 }
 
 #[async_trait::async_trait]
@@ -97,6 +106,7 @@ impl<P> BlockClock<P> {
             epoch_duration: Arc::new(epoch_duration),
             current_epoch: Arc::new(AtomicU64::new(0)),
             latest_seen_block: 0,
+            recent_blocks: BTreeMap::new(),
         }
     }
 
@@ -104,6 +114,63 @@ impl<P> BlockClock<P> {
     fn calculate_block_number(&self, from_block: u64) -> u64 {
         from_block.saturating_sub(self.genesis_block)
     }
+
+    /// Record the hash of a received L1 Block.
+    ///
+    /// Returns the first L1 Block number replaced if the Block doesn't extend
+    /// the L1 Blocks seen so far. The recorded hashes are then compared with
+    /// the `canonical_hash` of their L1 Block, down to the common ancestor of
+    /// the replaced Blocks and the new ones. A Block whose canonical hash
+    /// can't be fetched is considered replaced.
+    async fn track_block_hash<F, Fut>(
+        &mut self,
+        number: u64,
+        hash: B256,
+        parent_hash: B256,
+        canonical_hash: F,
+    ) -> Option<u64>
+    where
+        F: Fn(u64) -> Fut,
+        Fut: Future<Output = Option<B256>>,
+    {
+        let mut reorg_from = match self.recent_blocks.get(&number) {
+            Some(known) if *known == hash => return None,
+            Some(_) => Some(number),
+            None => number
+                .checked_sub(1)
+                .and_then(|parent| Some((parent, self.recent_blocks.get(&parent)?)))
+                .filter(|(_, known_parent)| **known_parent != parent_hash)
+                .map(|(parent, _)| parent),
+        };
+
+        if let Some(from) = reorg_from.as_mut() {
+            let older_blocks: Vec<(u64, B256)> = self
+                .recent_blocks
+                .range(..*from)
+                .rev()
+                .map(|(number, hash)| (*number, *hash))
+                .collect();
+
+            for (older_number, older_hash) in older_blocks {
+                if canonical_hash(older_number).await == Some(older_hash) {
+                    break;
+                }
+
+                *from = older_number;
+            }
+
+            // The replaced Blocks are forgotten, their new hashes are recorded as
+            // the new Blocks are received.
+            self.recent_blocks.split_off(from);
+        }
+
+        self.recent_blocks.insert(number, hash);
+        while self.recent_blocks.len() > BLOCK_HASH_HISTORY {
+            self.recent_blocks.pop_first();
+        }
+
+        reorg_from
+    }
 }
 
 impl BlockClock<BlockProvider> {
@@ -222,6 +289,30 @@ where
                 }
                 block_result = stream.recv() => {
                     let block = block_result?;
+                    let canonical_hash = |number: u64| {
+                        let provider = provider.clone();
+                        async move {
+                            provider
+                                .get_block(BlockId::number(number), BlockTransactionsKind::Hashes)
+                                .await
+                                .ok()
+                                .flatten()
+                                .map(|block| block.header.hash)
+                        }
+                    };
+                    if let Some(from_block) = self
+                        .track_block_hash(block.number, block.hash, block.parent_hash, canonical_hash)
+                        .await
+                    {
+                        warn!(
+                            "L1 reorg detected from the L1 Block {}: new block number={}, hash={}",
+                            from_block,
+                            block.number,
+                            block.hash
+                        );
+                        _ = sender.send(Event::L1Reorg(from_block));
+                    }
+
                     if block.number <= self.latest_seen_block {
                         trace!("Skipping block: number={}, latest_seen_block={}", block.number, self.latest_seen_block);
                         continue;
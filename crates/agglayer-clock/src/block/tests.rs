@@ -1,10 +1,13 @@
 use std::{
+    collections::BTreeMap,
+    future,
     num::NonZeroU64,
     sync::{atomic::AtomicU64, Arc},
     time::Duration,
 };
 
 use alloy::{
+    primitives::B256,
     providers::{Provider, ProviderBuilder, WsConnect},
     rpc::client::ClientBuilder,
 };
@@ -16,7 +19,7 @@ use tokio::sync::broadcast;
 use tokio_util::sync::CancellationToken;
 
 use crate::{
-    block::{BlockClockError, BlockProvider},
+    block::{BlockClockError, BlockProvider, BLOCK_HASH_HISTORY},
     BlockClock, Clock, ClockRef, Event, BROADCAST_CHANNEL_SIZE,
 };
 
@@ -40,6 +43,123 @@ fn test_block_calculation() {
     );
 }
 
+/// The canonical hashes of an L1 chain made of the given Blocks.
+fn canonical_chain(blocks: &[(u64, u8)]) -> impl Fn(u64) -> future::Ready<Option<B256>> {
+    let blocks: BTreeMap<u64, B256> = blocks
+        .iter()
+        .map(|(number, value)| (*number, B256::repeat_byte(*value)))
+        .collect();
+
+    move |number| future::ready(blocks.get(&number).copied())
+}
+
+#[tokio::test]
+async fn test_reorg_detection() {
+    let mut clock = BlockClock::new((), 0, NonZeroU64::new(3).unwrap());
+    let hash = |value: u8| B256::repeat_byte(value);
+
+    let chain = canonical_chain(&[(1, 1), (2, 2), (3, 3)]);
+    assert_eq!(
+        clock.track_block_hash(1, hash(1), hash(0), &chain).await,
+        None
+    );
+    assert_eq!(
+        clock.track_block_hash(2, hash(2), hash(1), &chain).await,
+        None
+    );
+    assert_eq!(
+        clock.track_block_hash(3, hash(3), hash(2), &chain).await,
+        None
+    );
+    // The same Block received twice isn't a reorg.
+    assert_eq!(
+        clock.track_block_hash(3, hash(3), hash(2), &chain).await,
+        None
+    );
+
+    // The Block 3 is replaced.
+    let chain = canonical_chain(&[(1, 1), (2, 2), (3, 13), (4, 14)]);
+    assert_eq!(
+        clock.track_block_hash(3, hash(13), hash(2), &chain).await,
+        Some(3)
+    );
+    assert_eq!(
+        clock.track_block_hash(4, hash(14), hash(13), &chain).await,
+        None
+    );
+
+    // The Block 4 was replaced by a Block never received.
+    let chain = canonical_chain(&[(1, 1), (2, 2), (3, 13), (4, 24), (5, 25), (6, 26)]);
+    assert_eq!(
+        clock.track_block_hash(5, hash(25), hash(24), &chain).await,
+        Some(4)
+    );
+    assert_eq!(
+        clock.track_block_hash(6, hash(26), hash(25), &chain).await,
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_deep_reorg_is_reported_from_the_common_ancestor() {
+    let mut clock = BlockClock::new((), 0, NonZeroU64::new(3).unwrap());
+    let hash = |value: u8| B256::repeat_byte(value);
+
+    let chain = canonical_chain(&[(1, 1), (2, 2), (3, 3), (4, 4), (5, 5), (6, 6)]);
+    for number in 1..=6 {
+        let value = number as u8;
+        assert_eq!(
+            clock
+                .track_block_hash(number, hash(value), hash(value - 1), &chain)
+                .await,
+            None
+        );
+    }
+
+    // The Blocks 4 to 6 are replaced, the common ancestor is the Block 3.
+    let chain = canonical_chain(&[
+        (1, 1),
+        (2, 2),
+        (3, 3),
+        (4, 14),
+        (5, 15),
+        (6, 16),
+        (7, 17),
+        (8, 18),
+    ]);
+    assert_eq!(
+        clock.track_block_hash(7, hash(17), hash(16), &chain).await,
+        Some(4)
+    );
+    assert_eq!(
+        clock.recent_blocks.keys().copied().collect::<Vec<_>>(),
+        vec![1, 2, 3, 7]
+    );
+    assert_eq!(
+        clock.track_block_hash(8, hash(18), hash(17), &chain).await,
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_block_hash_history_is_bounded() {
+    let mut clock = BlockClock::new((), 0, NonZeroU64::new(3).unwrap());
+    let chain = canonical_chain(&[]);
+
+    for number in 0..(BLOCK_HASH_HISTORY as u64 * 2) {
+        clock
+            .track_block_hash(
+                number,
+                B256::with_last_byte(1),
+                B256::with_last_byte(1),
+                &chain,
+            )
+            .await;
+    }
+
+    assert_eq!(clock.recent_blocks.len(), BLOCK_HASH_HISTORY);
+}
+
 #[tokio::test]
 async fn test_block_clock() {
     let anvil = Anvil::new().block_time(1u64).spawn();
@@ -2,6 +2,7 @@
 //!
 //! The Clock is responsible for providing information about Epoch timing by
 //! exposing references to the data and by broadcasting `EpochChange` events.
+//! The [`BlockClock`] also broadcasts the reorgs of the L1 it observes.
 
 use std::{
     num::NonZeroU64,
@@ -91,6 +92,9 @@ impl ClockRef {
 pub enum Event {
     /// Notify that an Epoch just ended with the associated Epoch number.
     EpochEnded(u64),
+    /// Notify that the L1 reorganized, the L1 Blocks from the associated L1
+    /// Block number onwards were replaced.
+    L1Reorg(u64),
 }
 
 /// Errors that can be returned by the Clock.
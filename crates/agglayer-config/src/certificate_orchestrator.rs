@@ -1,24 +1,97 @@
+use std::{collections::BTreeMap, num::NonZeroU64};
+
 use prover::ProverConfig;
+use scheduler::SchedulerConfig;
 use serde::{Deserialize, Serialize};
+use serde_with::DisplayFromStr;
+use supervisor::SupervisorConfig;
 
 pub mod prover;
+pub mod scheduler;
+pub mod supervisor;
 
 /// The CertificateOrchestrator configuration.
+#[serde_with::serde_as]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 pub struct CertificateOrchestrator {
     #[serde(default = "default_input_backpressure_buffer_size_default")]
     pub input_backpressure_buffer_size: usize,
 
+    /// The maximum number of certificates of a network that can be included
+    /// in a single epoch, unless overridden for the network.
+    #[serde(default = "default_max_certificates_per_epoch")]
+    pub max_certificates_per_epoch: NonZeroU64,
+
+    /// What happens to the certificates submitted for a network while its
+    /// certification is paused.
+    #[serde(default)]
+    pub paused_network_policy: PausedNetworkPolicy,
+
+    /// The number of epochs after which a certificate still pending is
+    /// expired and removed from the pending queue. The certificates never
+    /// expire if unset.
+    ///
+    /// The epochs are counted from the first epoch end at which the
+    /// certificate is seen pending, a restart of the node starting the count
+    /// again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_certificate_ttl: Option<NonZeroU64>,
+
     #[serde(default = "default_prover_config_default")]
     pub prover: ProverConfig,
+
+    /// The scheduling of the certifications on the prover.
+    #[serde(default)]
+    pub scheduler: SchedulerConfig,
+
+    /// The supervision of the network tasks.
+    #[serde(default)]
+    pub supervisor: SupervisorConfig,
+
+    /// The maximum number of certificates per epoch of specific networks.
+    ///
+    /// The key is the network ID, and the value overrides the
+    /// `max-certificates-per-epoch` of this network.
+    #[serde_as(as = "BTreeMap<DisplayFromStr, _>")]
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub network_max_certificates_per_epoch: BTreeMap<u32, NonZeroU64>,
+}
+
+/// Handling of the certificates submitted for a paused network.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum PausedNetworkPolicy {
+    /// The certificates are accepted and wait in the pending queue until the
+    /// network is resumed.
+    #[default]
+    Queue,
+    /// The certificates are rejected.
+    Reject,
+}
+
+impl CertificateOrchestrator {
+    /// The maximum number of certificates of a network that can be included in
+    /// a single epoch.
+    pub fn max_certificates_per_epoch_for(&self, network_id: u32) -> NonZeroU64 {
+        self.network_max_certificates_per_epoch
+            .get(&network_id)
+            .copied()
+            .unwrap_or(self.max_certificates_per_epoch)
+    }
 }
 
 impl Default for CertificateOrchestrator {
     fn default() -> Self {
         Self {
             input_backpressure_buffer_size: default_input_backpressure_buffer_size_default(),
+            max_certificates_per_epoch: default_max_certificates_per_epoch(),
+            paused_network_policy: PausedNetworkPolicy::default(),
+            pending_certificate_ttl: None,
             prover: default_prover_config_default(),
+            scheduler: SchedulerConfig::default(),
+            supervisor: SupervisorConfig::default(),
+            network_max_certificates_per_epoch: BTreeMap::new(),
         }
     }
 }
@@ -27,7 +100,93 @@ fn default_input_backpressure_buffer_size_default() -> usize {
     1_000
 }
 
+fn default_max_certificates_per_epoch() -> NonZeroU64 {
+    NonZeroU64::MIN
+}
+
 /// The default prover configuration.
 fn default_prover_config_default() -> ProverConfig {
     ProverConfig::SP1Local {}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_certificates_per_epoch_override() {
+        let config: CertificateOrchestrator = toml::from_str(
+            r#"
+            max-certificates-per-epoch = 2
+
+            [network-max-certificates-per-epoch]
+            3 = 5
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.max_certificates_per_epoch_for(1).get(), 2);
+        assert_eq!(config.max_certificates_per_epoch_for(3).get(), 5);
+    }
+
+    #[test]
+    fn scheduler_network_weights() {
+        let config: CertificateOrchestrator = toml::from_str(
+            r#"
+            [scheduler]
+            max-in-flight = 4
+
+            [scheduler.network-weights]
+            3 = 2
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.scheduler.max_in_flight.get(), 4);
+        assert_eq!(config.scheduler.weight_for(1).get(), 1);
+        assert_eq!(config.scheduler.weight_for(3).get(), 2);
+    }
+
+    #[test]
+    fn supervisor_backoff() {
+        let config: CertificateOrchestrator = toml::from_str(
+            r#"
+            [supervisor]
+            initial-backoff = "2s"
+            max-backoff = "10s"
+            "#,
+        )
+        .unwrap();
+
+        let backoffs: Vec<_> = (1..=5)
+            .map(|failures| config.supervisor.backoff_for(failures).as_secs())
+            .collect();
+        assert_eq!(backoffs, vec![2, 4, 8, 10, 10]);
+        assert_eq!(config.supervisor.max_restarts, None);
+    }
+
+    #[test]
+    fn max_certificates_per_epoch_default() {
+        let config: CertificateOrchestrator = toml::from_str("").unwrap();
+
+        assert_eq!(config.max_certificates_per_epoch_for(1).get(), 1);
+        assert_eq!(config.paused_network_policy, PausedNetworkPolicy::Queue);
+        assert_eq!(config.pending_certificate_ttl, None);
+    }
+
+    #[test]
+    fn paused_network_policy() {
+        let config: CertificateOrchestrator =
+            toml::from_str(r#"paused-network-policy = "reject""#).unwrap();
+
+        assert_eq!(config.paused_network_policy, PausedNetworkPolicy::Reject);
+    }
+
+    #[test]
+    fn pending_certificate_ttl() {
+        let config: CertificateOrchestrator =
+            toml::from_str("pending-certificate-ttl = 3").unwrap();
+
+        assert_eq!(config.pending_certificate_ttl.map(NonZeroU64::get), Some(3));
+    }
+}
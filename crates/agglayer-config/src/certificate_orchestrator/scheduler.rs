@@ -0,0 +1,51 @@
+use std::{
+    collections::BTreeMap,
+    num::{NonZeroU32, NonZeroUsize},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_with::DisplayFromStr;
+
+/// The scheduling of the certifications of the networks on the prover.
+#[serde_with::serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct SchedulerConfig {
+    /// The maximum number of certifications in flight across all the
+    /// networks.
+    #[serde(default = "default_max_in_flight")]
+    pub max_in_flight: NonZeroUsize,
+
+    /// The weight of specific networks when sharing the prover.
+    ///
+    /// The key is the network ID, and the value is its weight. A network with
+    /// a weight of 2 is served twice as often as a network with the default
+    /// weight of 1 when certifications are waiting.
+    #[serde_as(as = "BTreeMap<DisplayFromStr, _>")]
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub network_weights: BTreeMap<u32, NonZeroU32>,
+}
+
+impl SchedulerConfig {
+    /// The weight of a network when sharing the prover.
+    pub fn weight_for(&self, network_id: u32) -> NonZeroU32 {
+        self.network_weights
+            .get(&network_id)
+            .copied()
+            .unwrap_or(NonZeroU32::MIN)
+    }
+}
+
+impl Default for SchedulerConfig {
+    fn default() -> Self {
+        Self {
+            max_in_flight: default_max_in_flight(),
+            network_weights: BTreeMap::new(),
+        }
+    }
+}
+
+/// Same as the default concurrency limit of the prover.
+fn default_max_in_flight() -> NonZeroUsize {
+    NonZeroUsize::new(100).unwrap()
+}
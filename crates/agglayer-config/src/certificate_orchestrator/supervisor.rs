@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+/// The supervision of the network tasks.
+///
+/// A network task stopped by an error is restarted after a delay doubling
+/// with every consecutive failure, from the initial backoff up to the maximum
+/// backoff.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct SupervisorConfig {
+    /// Delay before the first restart of a failed network task.
+    #[serde(default = "default_initial_backoff")]
+    #[serde(with = "crate::with::HumanDuration")]
+    pub initial_backoff: Duration,
+
+    /// Maximum delay between two restarts of a failed network task. A task
+    /// running for longer than this delay is considered healthy again.
+    #[serde(default = "default_max_backoff")]
+    #[serde(with = "crate::with::HumanDuration")]
+    pub max_backoff: Duration,
+
+    /// Number of consecutive failures after which a network task is no
+    /// longer restarted. Unlimited if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_restarts: Option<u32>,
+}
+
+impl SupervisorConfig {
+    /// The delay before the restart of a network task which failed the given
+    /// number of consecutive times.
+    pub fn backoff_for(&self, consecutive_failures: u32) -> Duration {
+        let factor = 2u32.saturating_pow(consecutive_failures.saturating_sub(1));
+
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for SupervisorConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff: default_initial_backoff(),
+            max_backoff: default_max_backoff(),
+            max_restarts: None,
+        }
+    }
+}
+
+const fn default_initial_backoff() -> Duration {
+    Duration::from_secs(1)
+}
+
+const fn default_max_backoff() -> Duration {
+    Duration::from_secs(5 * 60)
+}
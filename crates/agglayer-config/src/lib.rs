@@ -39,7 +39,7 @@ pub use l2::L2;
 pub use log::Log;
 use prover::default_prover_entrypoint;
 pub use rate_limiting::RateLimitingConfig;
-pub use rpc::RpcConfig;
+pub use rpc::{AdminRpcConfig, HealthConfig, RpcConfig};
 
 /// The Agglayer configuration.
 #[serde_with::serde_as]
@@ -166,6 +166,11 @@ impl Config {
         std::net::SocketAddr::from((self.rpc.host, self.rpc.port))
     }
 
+    /// Get the target admin RPC socket address from the configuration.
+    pub fn admin_rpc_addr(&self) -> std::net::SocketAddr {
+        std::net::SocketAddr::from((self.rpc.admin.host, self.rpc.admin.port))
+    }
+
     pub fn path_contextualized(mut self, base_path: &Path) -> Self {
         self.storage = self.storage.path_contextualized(base_path);
 
@@ -1,9 +1,14 @@
 use std::time::Duration;
 
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
 use serde_with::serde_as;
 
+/// Minimum increase of the fees of a replacement transaction accepted by the
+/// nodes, in percent.
+const MIN_FEE_BUMP_PERCENTAGE: u64 = 10;
+
 /// Outbound configuration.
 #[derive(Serialize, Default, Debug, Deserialize, PartialEq, Eq)]
 #[serde(rename = "outbound", rename_all = "kebab-case")]
@@ -27,6 +32,9 @@ pub struct OutboundRpcConfig {
 #[serde(rename = "settle", rename_all = "kebab-case")]
 pub struct OutboundRpcSettleConfig {
     /// Maximum number of retries for the transaction.
+    ///
+    /// Only used by the legacy settlement, the settlement of the certificates
+    /// keeps polling the transaction until the settlement timeout.
     #[serde(default = "default_rpc_retries")]
     pub max_retries: usize,
 
@@ -41,10 +49,32 @@ pub struct OutboundRpcSettleConfig {
     pub confirmations: usize,
 
     /// Timeout for the submission of the settlement transaction to L1,
-    /// including the required number of confirmations.
+    /// including the required number of confirmations. A certificate
+    /// settlement still pending then is cancelled by a transfer with the same
+    /// nonce.
     #[serde(default = "default_settlement_timeout")]
     #[serde(with = "crate::with::HumanDuration")]
     pub settlement_timeout: Duration,
+
+    /// Time after which a pending settlement transaction is re-broadcast with
+    /// bumped fees.
+    #[serde(default = "default_fee_bump_interval")]
+    #[serde(with = "crate::with::HumanDuration")]
+    pub fee_bump_interval: Duration,
+
+    /// Increase of the fees of a re-broadcast settlement transaction, in
+    /// percent. Nodes reject replacement transactions bumped by less than 10%,
+    /// so lower values are rejected.
+    #[serde(
+        default = "default_fee_bump_percentage",
+        deserialize_with = "deserialize_fee_bump_percentage"
+    )]
+    pub fee_bump_percentage: u64,
+
+    /// Maximum fee per gas of the settlement transactions, in wei. The fees
+    /// are no longer bumped once reached.
+    #[serde(default = "default_max_fee_per_gas_cap")]
+    pub max_fee_per_gas_cap: u64,
 }
 
 impl Default for OutboundRpcSettleConfig {
@@ -54,6 +84,9 @@ impl Default for OutboundRpcSettleConfig {
             retry_interval: default_rpc_retry_interval(),
             confirmations: default_rpc_confirmations(),
             settlement_timeout: default_settlement_timeout(),
+            fee_bump_interval: default_fee_bump_interval(),
+            fee_bump_percentage: default_fee_bump_percentage(),
+            max_fee_per_gas_cap: default_max_fee_per_gas_cap(),
         }
     }
 }
@@ -80,6 +113,36 @@ const fn default_settlement_timeout() -> Duration {
     Duration::from_secs(20 * 60)
 }
 
+/// Default time after which a pending settlement transaction is re-broadcast.
+const fn default_fee_bump_interval() -> Duration {
+    Duration::from_secs(60)
+}
+
+/// Default increase of the fees of a re-broadcast settlement transaction.
+const fn default_fee_bump_percentage() -> u64 {
+    20
+}
+
+/// Default maximum fee per gas of the settlement transactions, 500 gwei.
+const fn default_max_fee_per_gas_cap() -> u64 {
+    500_000_000_000
+}
+
+fn deserialize_fee_bump_percentage<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let percentage = u64::deserialize(deserializer)?;
+
+    if percentage < MIN_FEE_BUMP_PERCENTAGE {
+        return Err(serde::de::Error::custom(format!(
+            "fee-bump-percentage must be at least {MIN_FEE_BUMP_PERCENTAGE}, got {percentage}"
+        )));
+    }
+
+    Ok(percentage)
+}
+
 #[cfg(test)]
 mod tests {
     mod outbound {
@@ -120,6 +183,9 @@ mod tests {
                     assert_eq!(config.max_retries, 3);
                     assert_eq!(config.retry_interval, Duration::from_secs(7));
                     assert_eq!(config.confirmations, 1);
+                    assert_eq!(config.fee_bump_interval, Duration::from_secs(60));
+                    assert_eq!(config.fee_bump_percentage, 20);
+                    assert_eq!(config.max_fee_per_gas_cap, 500_000_000_000);
                 }
 
                 #[test]
@@ -128,6 +194,9 @@ mod tests {
                         max-retries = 10
                         retry-interval = 1
                         confirmations = 5
+                        fee-bump-interval = "2m"
+                        fee-bump-percentage = 15
+                        max-fee-per-gas-cap = 100000000000
                         "#;
 
                     let config = toml::from_str::<OutboundRpcSettleConfig>(toml).unwrap();
@@ -135,6 +204,30 @@ mod tests {
                     assert_eq!(config.max_retries, 10);
                     assert_eq!(config.retry_interval, Duration::from_secs(1));
                     assert_eq!(config.confirmations, 5);
+                    assert_eq!(config.fee_bump_interval, Duration::from_secs(120));
+                    assert_eq!(config.fee_bump_percentage, 15);
+                    assert_eq!(config.max_fee_per_gas_cap, 100_000_000_000);
+                }
+
+                #[test]
+                fn fee_bump_percentage_below_the_minimum_is_rejected() {
+                    let toml = r#"
+                        fee-bump-percentage = 9
+                        "#;
+
+                    let error = toml::from_str::<OutboundRpcSettleConfig>(toml).unwrap_err();
+
+                    assert!(error
+                        .to_string()
+                        .contains("fee-bump-percentage must be at least 10, got 9"));
+
+                    let toml = r#"
+                        fee-bump-percentage = 10
+                        "#;
+
+                    let config = toml::from_str::<OutboundRpcSettleConfig>(toml).unwrap();
+
+                    assert_eq!(config.fee_bump_percentage, 10);
                 }
             }
         }
@@ -7,6 +7,9 @@ use serde_with::serde_as;
 /// The default port for the local RPC server.
 const DEFAULT_PORT: u16 = 9090;
 
+/// The default port for the admin RPC server.
+const DEFAULT_ADMIN_PORT: u16 = 9091;
+
 /// The local RPC server configuration.
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
@@ -42,6 +45,70 @@ pub struct RpcConfig {
     #[serde_as(as = "crate::with::HumanDuration")]
     #[serde(default = "default_request_timeout")]
     pub request_timeout: Duration,
+    /// The admin RPC server configuration.
+    #[serde(default)]
+    pub admin: AdminRpcConfig,
+    /// The health checks configuration.
+    #[serde(default)]
+    pub health: HealthConfig,
+}
+
+/// The admin RPC server configuration.
+///
+/// The admin RPC server exposes the `admin_` namespace on its own port. It
+/// must either be bound to a loopback address or be protected by a bearer
+/// token.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct AdminRpcConfig {
+    #[serde(default = "default_admin_port")]
+    pub port: u16,
+    #[serde(default = "default_admin_host")]
+    pub host: Ipv4Addr,
+    /// The token expected in the `Authorization: Bearer` header of every
+    /// request. If `None`, the admin RPC server only accepts to be bound to a
+    /// loopback address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bearer_token: Option<String>,
+}
+
+/// The health checks configuration.
+///
+/// The health of the node components is reported by `system_health`, and the
+/// node is only reported as ready on `/ready` once every component is
+/// healthy.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct HealthConfig {
+    /// Maximum time since the clock observed a new block before reporting it
+    /// as unhealthy.
+    #[serde_as(as = "crate::with::HumanDuration")]
+    #[serde(default = "default_max_block_age")]
+    pub max_block_age: Duration,
+    /// Timeout of the health check of each component.
+    #[serde_as(as = "crate::with::HumanDuration")]
+    #[serde(default = "default_check_timeout")]
+    pub check_timeout: Duration,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            max_block_age: default_max_block_age(),
+            check_timeout: default_check_timeout(),
+        }
+    }
+}
+
+impl Default for AdminRpcConfig {
+    fn default() -> Self {
+        Self {
+            port: default_admin_port(),
+            host: default_admin_host(),
+            bearer_token: None,
+        }
+    }
 }
 
 impl Default for RpcConfig {
@@ -55,6 +122,8 @@ impl Default for RpcConfig {
             batch_request_limit: None,
             ping_interval: None,
             request_timeout: default_request_timeout(),
+            admin: AdminRpcConfig::default(),
+            health: HealthConfig::default(),
         }
     }
 }
@@ -80,11 +149,31 @@ const fn default_host() -> Ipv4Addr {
     Ipv4Addr::new(0, 0, 0, 0)
 }
 
+/// The default port for the admin RPC server.
+const fn default_admin_port() -> u16 {
+    DEFAULT_ADMIN_PORT
+}
+
+/// The default host for the admin RPC server, only reachable locally.
+const fn default_admin_host() -> Ipv4Addr {
+    Ipv4Addr::LOCALHOST
+}
+
 /// Default timeout for completion of an RPC request to the AggLayer node.
 const fn default_request_timeout() -> Duration {
     Duration::from_secs(180)
 }
 
+/// Default maximum time since the clock observed a new block.
+const fn default_max_block_age() -> Duration {
+    Duration::from_secs(60)
+}
+
+/// Default timeout of the health check of each component.
+const fn default_check_timeout() -> Duration {
+    Duration::from_secs(5)
+}
+
 fn deserialize_port<'de, D>(deserializer: D) -> Result<u16, D::Error>
 where
     D: Deserializer<'de>,
@@ -9,12 +9,20 @@ pub struct ShutdownConfig {
     #[serde(default = "default_shutdown_runtime_timeout")]
     #[serde(with = "crate::with::HumanDuration")]
     pub runtime_timeout: Duration,
+
+    /// Maximum duration given to the work in flight to complete on shutdown,
+    /// such as the certifications and the settlements, before it is
+    /// abandoned.
+    #[serde(default = "default_shutdown_drain_timeout")]
+    #[serde(with = "crate::with::HumanDuration")]
+    pub drain_timeout: Duration,
 }
 
 impl Default for ShutdownConfig {
     fn default() -> Self {
         Self {
             runtime_timeout: default_shutdown_runtime_timeout(),
+            drain_timeout: default_shutdown_drain_timeout(),
         }
     }
 }
@@ -22,3 +30,7 @@ impl Default for ShutdownConfig {
 const fn default_shutdown_runtime_timeout() -> Duration {
     Duration::from_secs(5)
 }
+
+const fn default_shutdown_drain_timeout() -> Duration {
+    Duration::from_secs(60)
+}
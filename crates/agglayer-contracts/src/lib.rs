@@ -1,6 +1,6 @@
 //! Agglayer smart-contract bindings.
 
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use ethers::prelude::*;
 use ethers::providers::Middleware;
@@ -100,6 +100,10 @@ where
 {
     type M = RpcProvider;
 
+    fn client(&self) -> Arc<Self::M> {
+        self.inner.client()
+    }
+
     fn decode_contract_revert(error: &ContractError<Self::M>) -> Option<String> {
         error
             .decode_contract_revert::<PolygonRollupManagerErrors>()
@@ -1,9 +1,14 @@
+use std::sync::Arc;
+
 use ethers::providers::Middleware;
 use ethers_contract::{ContractCall, ContractError};
 
 pub trait Settler {
     type M: Middleware;
 
+    /// Client submitting and monitoring the settlement transactions.
+    fn client(&self) -> Arc<Self::M>;
+
     fn decode_contract_revert(error: &ContractError<Self::M>) -> Option<String>;
     fn build_verify_pessimistic_trusted_aggregator_call(
         &self,
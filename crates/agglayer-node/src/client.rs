@@ -0,0 +1,362 @@
+//! Typed client of the agglayer RPC.
+//!
+//! The [`Client`] wraps any jsonrpsee client implementing the generated
+//! [`AgglayerClient`] trait, over HTTP or WebSocket. Calls failing on a
+//! transient error are retried according to a [`RetryPolicy`], and the errors
+//! returned by the agglayer are decoded from their [`code`].
+use std::time::Duration;
+
+use agglayer_types::{
+    Certificate, CertificateHeader, CertificateId, EpochConfiguration, Height, LocalExitTreeProof,
+    NetworkId,
+};
+use ethers::types::H256;
+use futures::future::BoxFuture;
+use jsonrpsee::{
+    core::{client::Subscription, ClientError},
+    http_client::{HttpClient, HttpClientBuilder},
+    types::error::INTERNAL_ERROR_CODE,
+    ws_client::{WsClient, WsClientBuilder},
+};
+use pessimistic_proof::{
+    bridge_exit::TokenInfo, global_index::GlobalIndex, local_state::StateCommitment,
+    PessimisticProofOutput,
+};
+use tracing::warn;
+
+pub use crate::rpc::error::code;
+pub use crate::rpc::{
+    AgglayerClient, CertificateHeadersPage, CertificateProof, CertificateProofFormat,
+    CertificateProofResponse, CertificateStatusFilter, CertificateStatusKind, EpochClockKind,
+    EpochEvent, L1Info, NodeInfo, NullifierStatus, TokenBalance, TxStatus,
+};
+pub use crate::signed_tx::{Proof, ProofEncodingError, ProofManifest, SignedTx, Zkp};
+
+/// Kind of an error returned by the agglayer, decoded from its [`code`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    RollupNotRegistered,
+    SignatureMismatch,
+    ValidationFailure,
+    SettlementError,
+    StatusError,
+    SendCertificate,
+    RateLimited,
+    ResourceNotFound,
+    CertificateValidation,
+    Admin,
+    InvalidCertificateSigner,
+    UnexpectedCertificateHeight,
+    PrevLocalExitRootMismatch,
+    ConflictingCertificate,
+    NetworkPaused,
+    Internal,
+    /// The code isn't one of the agglayer error codes.
+    Unknown,
+}
+
+impl ErrorKind {
+    pub fn from_code(value: i32) -> Self {
+        match value {
+            code::ROLLUP_NOT_REGISTERED => Self::RollupNotRegistered,
+            code::SIGNATURE_MISMATCH => Self::SignatureMismatch,
+            code::VALIDATION_FAILURE => Self::ValidationFailure,
+            code::SETTLEMENT_ERROR => Self::SettlementError,
+            code::STATUS_ERROR => Self::StatusError,
+            code::SEND_CERTIFICATE => Self::SendCertificate,
+            code::RATE_LIMITED => Self::RateLimited,
+            code::RESOURCE_NOT_FOUND => Self::ResourceNotFound,
+            code::CERTIFICATE_VALIDATION => Self::CertificateValidation,
+            code::ADMIN => Self::Admin,
+            code::INVALID_CERTIFICATE_SIGNER => Self::InvalidCertificateSigner,
+            code::UNEXPECTED_CERTIFICATE_HEIGHT => Self::UnexpectedCertificateHeight,
+            code::PREV_LOCAL_EXIT_ROOT_MISMATCH => Self::PrevLocalExitRootMismatch,
+            code::CONFLICTING_CERTIFICATE => Self::ConflictingCertificate,
+            code::NETWORK_PAUSED => Self::NetworkPaused,
+            INTERNAL_ERROR_CODE => Self::Internal,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Error returned by the [`Client`].
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The agglayer rejected the call.
+    #[error("{kind:?} error ({code}): {message}")]
+    Rpc {
+        kind: ErrorKind,
+        code: i32,
+        message: String,
+        /// The structured error, as serialized by the agglayer.
+        data: Option<serde_json::Value>,
+    },
+    /// The call failed before reaching the agglayer, or its response couldn't
+    /// be decoded.
+    #[error(transparent)]
+    Client(ClientError),
+}
+
+impl Error {
+    /// Kind of the error returned by the agglayer, if any.
+    pub fn kind(&self) -> Option<ErrorKind> {
+        match self {
+            Self::Rpc { kind, .. } => Some(*kind),
+            Self::Client(_) => None,
+        }
+    }
+
+    /// Whether the call may succeed if attempted again.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::Client(ClientError::Transport(_) | ClientError::RequestTimeout)
+        )
+    }
+}
+
+impl From<ClientError> for Error {
+    fn from(error: ClientError) -> Self {
+        match error {
+            ClientError::Call(error) => Self::Rpc {
+                kind: ErrorKind::from_code(error.code()),
+                code: error.code(),
+                message: error.message().to_string(),
+                data: error
+                    .data()
+                    .and_then(|data| serde_json::from_str(data.get()).ok()),
+            },
+            error => Self::Client(error),
+        }
+    }
+}
+
+/// Policy applied to retry the calls failing on a transient error.
+///
+/// The delay between two attempts doubles from the initial backoff, up to the
+/// maximum backoff.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of retries, `0` disables the retries.
+    pub max_retries: usize,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy which never retries.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+}
+
+/// Typed client of the agglayer RPC.
+pub struct Client<C> {
+    inner: C,
+    retry_policy: RetryPolicy,
+}
+
+impl Client<HttpClient> {
+    /// Create a client of the agglayer RPC over HTTP.
+    pub fn http(url: impl AsRef<str>) -> Result<Self, Error> {
+        Ok(Self::new(HttpClientBuilder::default().build(url)?))
+    }
+}
+
+impl Client<WsClient> {
+    /// Create a client of the agglayer RPC over WebSocket.
+    ///
+    /// Unlike the HTTP client, this client supports the subscriptions.
+    pub async fn ws(url: impl AsRef<str>) -> Result<Self, Error> {
+        Ok(Self::new(WsClientBuilder::default().build(url).await?))
+    }
+}
+
+impl<C> Client<C>
+where
+    C: AgglayerClient + Sync,
+{
+    /// Wrap a jsonrpsee client, using the default [`RetryPolicy`].
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// The underlying jsonrpsee client.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Perform a call with the underlying client, retrying it on transient
+    /// errors.
+    pub async fn call<T, F>(&self, call: F) -> Result<T, Error>
+    where
+        F: for<'a> Fn(&'a C) -> BoxFuture<'a, Result<T, ClientError>>,
+    {
+        let mut backoff = self.retry_policy.initial_backoff;
+        let mut retries = 0;
+
+        loop {
+            match call(&self.inner).await.map_err(Error::from) {
+                Err(error) if error.is_transient() && retries < self.retry_policy.max_retries => {
+                    retries += 1;
+                    warn!(
+                        "Agglayer call failed ({error}), retrying in {backoff:?} ({retries}/{})",
+                        self.retry_policy.max_retries
+                    );
+
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(self.retry_policy.max_backoff);
+                }
+                result => return result,
+            }
+        }
+    }
+
+    pub async fn send_tx(&self, tx: SignedTx) -> Result<H256, Error> {
+        self.call(|client| client.send_tx(tx.clone())).await
+    }
+
+    pub async fn get_tx_status(&self, hash: H256) -> Result<TxStatus, Error> {
+        self.call(|client| client.get_tx_status(hash)).await
+    }
+
+    pub async fn send_certificate(&self, certificate: Certificate) -> Result<CertificateId, Error> {
+        self.call(|client| client.send_certificate(certificate.clone()))
+            .await
+    }
+
+    pub async fn validate_certificate(
+        &self,
+        certificate: Certificate,
+    ) -> Result<PessimisticProofOutput, Error> {
+        self.call(|client| client.validate_certificate(certificate.clone()))
+            .await
+    }
+
+    pub async fn get_certificate_header(
+        &self,
+        certificate_id: CertificateId,
+    ) -> Result<CertificateHeader, Error> {
+        self.call(|client| client.get_certificate_header(certificate_id))
+            .await
+    }
+
+    pub async fn get_epoch_configuration(&self) -> Result<EpochConfiguration, Error> {
+        self.call(|client| client.get_epoch_configuration()).await
+    }
+
+    pub async fn get_latest_known_certificate_header(
+        &self,
+        network_id: NetworkId,
+    ) -> Result<Option<CertificateHeader>, Error> {
+        self.call(|client| client.get_latest_known_certificate_header(network_id))
+            .await
+    }
+
+    pub async fn get_certificate_header_by_height(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> Result<CertificateHeader, Error> {
+        self.call(|client| client.get_certificate_header_by_height(network_id, height))
+            .await
+    }
+
+    pub async fn get_certificate_headers(
+        &self,
+        network_id: NetworkId,
+        from_height: Height,
+        limit: usize,
+        status_filter: Option<CertificateStatusKind>,
+    ) -> Result<CertificateHeadersPage, Error> {
+        self.call(|client| {
+            client.get_certificate_headers(network_id, from_height, limit, status_filter)
+        })
+        .await
+    }
+
+    pub async fn get_local_network_state_roots(
+        &self,
+        network_id: NetworkId,
+    ) -> Result<StateCommitment, Error> {
+        self.call(|client| client.get_local_network_state_roots(network_id))
+            .await
+    }
+
+    pub async fn get_token_balance(
+        &self,
+        network_id: NetworkId,
+        token_info: TokenInfo,
+    ) -> Result<TokenBalance, Error> {
+        self.call(|client| client.get_token_balance(network_id, token_info))
+            .await
+    }
+
+    pub async fn get_nullifier_status(
+        &self,
+        network_id: NetworkId,
+        global_index: GlobalIndex,
+    ) -> Result<NullifierStatus, Error> {
+        self.call(|client| client.get_nullifier_status(network_id, global_index))
+            .await
+    }
+
+    pub async fn get_local_exit_tree_proof(
+        &self,
+        network_id: NetworkId,
+        leaf_index: u32,
+        leaf_count: Option<u32>,
+    ) -> Result<LocalExitTreeProof, Error> {
+        self.call(|client| client.get_local_exit_tree_proof(network_id, leaf_index, leaf_count))
+            .await
+    }
+
+    pub async fn get_certificate_proof(
+        &self,
+        certificate_id: CertificateId,
+        format: Option<CertificateProofFormat>,
+    ) -> Result<CertificateProofResponse, Error> {
+        self.call(|client| client.get_certificate_proof(certificate_id, format))
+            .await
+    }
+
+    pub async fn get_node_info(&self) -> Result<NodeInfo, Error> {
+        self.call(|client| client.get_node_info()).await
+    }
+
+    /// Subscribe to the status transitions of a certificate or of every
+    /// certificate of a network.
+    pub async fn subscribe_certificate_status(
+        &self,
+        filter: CertificateStatusFilter,
+    ) -> Result<Subscription<CertificateHeader>, Error> {
+        self.call(|client| client.subscribe_certificate_status(filter))
+            .await
+    }
+
+    /// Subscribe to the end of every epoch.
+    pub async fn subscribe_epochs(&self) -> Result<Subscription<EpochEvent>, Error> {
+        self.call(|client| client.subscribe_epochs()).await
+    }
+}
@@ -0,0 +1,298 @@
+//! Health checks of the node components.
+//!
+//! The health of every component is reported by the `system_health` method,
+//! served on `/health`. The `system_ready` method, served on `/ready`, fails
+//! as soon as one of the components is unhealthy so that the node can be
+//! taken out of rotation.
+
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+use agglayer_clock::ClockRef;
+use agglayer_prover_types::v1::proof_generation_service_client::ProofGenerationServiceClient;
+use ethers::providers::Middleware;
+use futures::future::{join_all, BoxFuture};
+use parking_lot::Mutex;
+use serde::Serialize;
+use tokio::{task::AbortHandle, time::Instant};
+
+/// Health of one component of the node.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub(crate) struct ComponentHealth {
+    pub(crate) healthy: bool,
+    /// Details on the status of the component.
+    pub(crate) detail: String,
+}
+
+impl ComponentHealth {
+    pub(crate) fn healthy(detail: impl Into<String>) -> Self {
+        Self {
+            healthy: true,
+            detail: detail.into(),
+        }
+    }
+
+    pub(crate) fn unhealthy(detail: impl Into<String>) -> Self {
+        Self {
+            healthy: false,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Health of the node and of each of its components.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct HealthReport {
+    /// Whether every component is healthy.
+    pub(crate) health: bool,
+    pub(crate) components: BTreeMap<&'static str, ComponentHealth>,
+}
+
+/// Check of the health of one component.
+pub(crate) trait HealthCheck: Send + Sync {
+    fn check(&self) -> BoxFuture<'_, ComponentHealth>;
+}
+
+/// Health checks of the node components.
+#[derive(Clone)]
+pub(crate) struct HealthChecks {
+    checks: Vec<(&'static str, Arc<dyn HealthCheck>)>,
+    /// Timeout of each check, after which the component is reported as
+    /// unhealthy.
+    timeout: Duration,
+}
+
+impl HealthChecks {
+    pub(crate) fn new(timeout: Duration) -> Self {
+        Self {
+            checks: Vec::new(),
+            timeout,
+        }
+    }
+
+    pub(crate) fn with_check(
+        mut self,
+        name: &'static str,
+        check: impl HealthCheck + 'static,
+    ) -> Self {
+        self.checks.push((name, Arc::new(check)));
+        self
+    }
+
+    /// Run every health check concurrently.
+    pub(crate) async fn report(&self) -> HealthReport {
+        let components: BTreeMap<_, _> =
+            join_all(self.checks.iter().map(|(name, check)| async move {
+                let health = tokio::time::timeout(self.timeout, check.check())
+                    .await
+                    .unwrap_or_else(|_| {
+                        ComponentHealth::unhealthy(format!(
+                            "Health check timed out after {:?}",
+                            self.timeout
+                        ))
+                    });
+
+                (*name, health)
+            }))
+            .await
+            .into_iter()
+            .collect();
+
+        HealthReport {
+            health: components.values().all(|component| component.healthy),
+            components,
+        }
+    }
+}
+
+/// Check that the clock keeps observing new blocks.
+///
+/// The clock only exposes its current block height, the time at which the
+/// height last changed is tracked across the checks.
+pub(crate) struct ClockHealthCheck {
+    clock_ref: ClockRef,
+    max_block_age: Duration,
+    last_block: Mutex<(u64, Instant)>,
+}
+
+impl ClockHealthCheck {
+    pub(crate) fn new(clock_ref: ClockRef, max_block_age: Duration) -> Self {
+        let last_block = Mutex::new((clock_ref.current_block_height(), Instant::now()));
+
+        Self {
+            clock_ref,
+            max_block_age,
+            last_block,
+        }
+    }
+
+    fn check_block_age(&self) -> ComponentHealth {
+        let block_height = self.clock_ref.current_block_height();
+
+        let mut last_block = self.last_block.lock();
+        if last_block.0 != block_height {
+            *last_block = (block_height, Instant::now());
+        }
+
+        let age = last_block.1.elapsed();
+        let detail = format!("Block {block_height} observed {}s ago", age.as_secs());
+        if age > self.max_block_age {
+            ComponentHealth::unhealthy(detail)
+        } else {
+            ComponentHealth::healthy(detail)
+        }
+    }
+}
+
+impl HealthCheck for ClockHealthCheck {
+    fn check(&self) -> BoxFuture<'_, ComponentHealth> {
+        Box::pin(async { self.check_block_age() })
+    }
+}
+
+/// Check that the prover can be connected to.
+pub(crate) struct ProverHealthCheck {
+    endpoint: String,
+}
+
+impl ProverHealthCheck {
+    pub(crate) fn new(endpoint: String) -> Self {
+        Self { endpoint }
+    }
+}
+
+impl HealthCheck for ProverHealthCheck {
+    fn check(&self) -> BoxFuture<'_, ComponentHealth> {
+        Box::pin(async {
+            match ProofGenerationServiceClient::connect(self.endpoint.clone()).await {
+                Ok(_) => ComponentHealth::healthy(format!("Connected to {}", self.endpoint)),
+                Err(error) => ComponentHealth::unhealthy(format!(
+                    "Unable to connect to {}: {error}",
+                    self.endpoint
+                )),
+            }
+        })
+    }
+}
+
+/// Check that the L1 RPC is reachable.
+pub(crate) struct L1HealthCheck<Rpc> {
+    rpc: Arc<Rpc>,
+}
+
+impl<Rpc> L1HealthCheck<Rpc> {
+    pub(crate) fn new(rpc: Arc<Rpc>) -> Self {
+        Self { rpc }
+    }
+}
+
+impl<Rpc> HealthCheck for L1HealthCheck<Rpc>
+where
+    Rpc: Middleware + 'static,
+{
+    fn check(&self) -> BoxFuture<'_, ComponentHealth> {
+        Box::pin(async {
+            match self.rpc.get_block_number().await {
+                Ok(block_number) => {
+                    ComponentHealth::healthy(format!("Latest L1 block {block_number}"))
+                }
+                Err(error) => {
+                    ComponentHealth::unhealthy(format!("Unable to reach the L1 RPC: {error}"))
+                }
+            }
+        })
+    }
+}
+
+/// Check that a task spawned by the node is still running.
+pub(crate) struct TaskHealthCheck {
+    handle: AbortHandle,
+}
+
+impl TaskHealthCheck {
+    pub(crate) fn new(handle: AbortHandle) -> Self {
+        Self { handle }
+    }
+}
+
+impl HealthCheck for TaskHealthCheck {
+    fn check(&self) -> BoxFuture<'_, ComponentHealth> {
+        Box::pin(async {
+            if self.handle.is_finished() {
+                ComponentHealth::unhealthy("The task is no longer running")
+            } else {
+                ComponentHealth::healthy("The task is running")
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        num::NonZeroU64,
+        sync::atomic::{AtomicU64, Ordering},
+    };
+
+    use super::*;
+
+    struct Never;
+
+    impl HealthCheck for Never {
+        fn check(&self) -> BoxFuture<'_, ComponentHealth> {
+            Box::pin(futures::future::pending())
+        }
+    }
+
+    #[tokio::test]
+    async fn report_is_healthy_without_checks() {
+        let report = HealthChecks::new(Duration::from_secs(1)).report().await;
+
+        assert!(report.health);
+        assert!(report.components.is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn clock_without_new_block_is_unhealthy() {
+        let block_height = Arc::new(AtomicU64::new(10));
+        let clock_ref = ClockRef::new(
+            tokio::sync::broadcast::channel(1).0,
+            block_height.clone(),
+            Arc::new(NonZeroU64::new(3).unwrap()),
+        );
+        let checks = HealthChecks::new(Duration::from_secs(1)).with_check(
+            "clock",
+            ClockHealthCheck::new(clock_ref, Duration::from_secs(30)),
+        );
+
+        assert!(checks.report().await.health);
+
+        tokio::time::advance(Duration::from_secs(31)).await;
+        let report = checks.report().await;
+        assert!(!report.health);
+        assert!(!report.components["clock"].healthy);
+
+        block_height.store(11, Ordering::Release);
+        assert!(checks.report().await.health);
+    }
+
+    #[tokio::test]
+    async fn finished_task_is_unhealthy() {
+        let handle = tokio::spawn(async {});
+        let checks = HealthChecks::new(Duration::from_secs(1))
+            .with_check("task", TaskHealthCheck::new(handle.abort_handle()));
+        handle.await.unwrap();
+
+        let report = checks.report().await;
+        assert!(!report.health);
+        assert!(!report.components["task"].healthy);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn check_timing_out_is_unhealthy() {
+        let checks = HealthChecks::new(Duration::from_secs(1)).with_check("never", Never);
+
+        let report = checks.report().await;
+        assert!(!report.health);
+        assert!(!report.components["never"].healthy);
+    }
+}
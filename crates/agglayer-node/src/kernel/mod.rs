@@ -5,6 +5,8 @@ use agglayer_config::Config;
 use agglayer_contracts::{
     polygon_rollup_manager::{PolygonRollupManager, RollupIDToRollupDataReturn},
     polygon_zk_evm::PolygonZkEvm,
+    polygon_zkevm_global_exit_root_v2::PolygonZkEVMGlobalExitRootV2,
+    L1RpcClient,
 };
 use ethers::prelude::*;
 use thiserror::Error;
@@ -150,6 +152,18 @@ where
     fn get_rollup_manager_contract(&self) -> PolygonRollupManager<RpcProvider> {
         PolygonRollupManager::new(self.config.l1.rollup_manager_contract, self.rpc.clone()).clone()
     }
+
+    /// Get a [`L1RpcClient`] bound to the rollup manager and the global exit
+    /// root contracts specified by the given configuration.
+    pub(crate) fn get_l1_rpc_client(&self) -> L1RpcClient<RpcProvider> {
+        L1RpcClient::new(
+            self.get_rollup_manager_contract(),
+            PolygonZkEVMGlobalExitRootV2::new(
+                self.config.l1.polygon_zkevm_global_exit_root_v2_contract,
+                self.rpc.clone(),
+            ),
+        )
+    }
 }
 
 /// Errors related to signature verification process.
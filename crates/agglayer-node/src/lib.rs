@@ -6,6 +6,9 @@ use node::Node;
 use tokio_util::sync::CancellationToken;
 use tracing::info;
 
+#[cfg(any(test, feature = "client"))]
+pub mod client;
+mod health;
 mod kernel;
 mod logging;
 mod rate_limiting;
@@ -18,6 +21,7 @@ mod epoch_synchronizer;
 mod node;
 
 use agglayer_telemetry::ServerBuilder as MetricsBuilder;
+pub use rpc::openrpc::document as openrpc_document;
 
 /// This is the main node entrypoint.
 ///
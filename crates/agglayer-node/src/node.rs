@@ -1,7 +1,7 @@
 use std::{num::NonZeroU64, sync::Arc};
 
 use agglayer_aggregator_notifier::{CertifierClient, EpochPackerClient};
-use agglayer_certificate_orchestrator::CertificateOrchestrator;
+use agglayer_certificate_orchestrator::{CertificateOrchestrator, ProverScheduler};
 use agglayer_clock::{BlockClock, Clock, TimeClock};
 use agglayer_config::{Config, Epoch};
 use agglayer_contracts::{
@@ -16,6 +16,7 @@ use agglayer_storage::{
         PerEpochReader as _,
     },
 };
+use agglayer_types::Address;
 use alloy::providers::WsConnect;
 use anyhow::Result;
 use ethers::{
@@ -25,13 +26,23 @@ use ethers::{
 };
 use tokio::{join, sync::mpsc, task::JoinHandle};
 use tokio_util::sync::CancellationToken;
-use tracing::{debug, info};
+use tracing::{debug, error, info};
 
-use crate::{epoch_synchronizer::EpochSynchronizer, kernel::Kernel, rpc::AgglayerImpl};
+use crate::{
+    epoch_synchronizer::EpochSynchronizer,
+    health::{ClockHealthCheck, HealthChecks, L1HealthCheck, ProverHealthCheck, TaskHealthCheck},
+    kernel::Kernel,
+    rpc::{admin::AdminImpl, AgglayerImpl},
+};
+
+/// The size of the channel used to send admin commands to the orchestrator.
+const ADMIN_COMMAND_CHANNEL_SIZE: usize = 16;
 
 pub(crate) struct Node {
     rpc_handle: JoinHandle<()>,
     certificate_orchestrator_handle: JoinHandle<()>,
+    /// The databases which write-ahead log is flushed on shutdown.
+    databases: Vec<(&'static str, Arc<DB>)>,
 }
 
 #[buildstructor::buildstructor]
@@ -71,6 +82,7 @@ impl Node {
     /// - The L1 node URL is invalid.
     /// - The configured signer is invalid.
     /// - The RPC server failed to start.
+    /// - The admin RPC server failed to start.
     /// - The [`TimeClock`] failed to start.
     #[builder(entry = "builder", exit = "start", visibility = "pub(crate)")]
     pub(crate) async fn start(
@@ -173,16 +185,24 @@ impl Node {
             Arc::clone(&config),
         )
         .await?;
-        info!("Certifier client created.");
+        let vkey = certifier_client.vkey();
+        info!("Certifier client created, pessimistic proof vkey: {vkey}");
 
         // Construct the core.
-        let core = Kernel::new(rpc, config.clone());
+        let core = Kernel::new(rpc.clone(), config.clone());
 
         let epoch_packing_aggregator_task = EpochPackerClient::try_new(
             Arc::new(config.outbound.rpc.settle.clone()),
             state_store.clone(),
             Arc::clone(&rollup_manager),
         )?;
+        // The settlements mined before the restart are still checked on an L1 reorg.
+        if let Err(error) = epoch_packing_aggregator_task
+            .recover_recent_settlements()
+            .await
+        {
+            error!("Failed to recover the recent settlements: {}", error);
+        }
 
         info!("Epoch packing aggregator task created.");
 
@@ -192,8 +212,10 @@ impl Node {
                 .input_backpressure_buffer_size,
         );
 
+        let (admin_sender, admin_receiver) = mpsc::channel(ADMIN_COMMAND_CHANNEL_SIZE);
+
         let certificate_orchestrator_handle = CertificateOrchestrator::builder()
-            .clock(clock_ref)
+            .clock(clock_ref.clone())
             .data_receiver(data_receiver)
             .cancellation_token(cancellation_token.clone())
             .epoch_packing_task_builder(epoch_packing_aggregator_task)
@@ -201,43 +223,95 @@ impl Node {
             .epochs_store(epochs_store.clone())
             .current_epoch(arc_swap::ArcSwap::new(Arc::new(current_epoch_store)))
             .state_store(state_store.clone())
-            .certifier_task_builder(certifier_client)
+            .certifier_task_builder(ProverScheduler::new(
+                certifier_client,
+                config.certificate_orchestrator.scheduler.clone(),
+            ))
+            .admin_receiver(admin_receiver)
+            .config(config.certificate_orchestrator.clone())
+            .drain_timeout(config.shutdown.drain_timeout)
             .start()
             .await?;
 
         info!("Certificate orchestrator started.");
+
+        let health_checks = HealthChecks::new(config.rpc.health.check_timeout)
+            .with_check(
+                "clock",
+                ClockHealthCheck::new(clock_ref.clone(), config.rpc.health.max_block_age),
+            )
+            .with_check(
+                "prover",
+                ProverHealthCheck::new(config.prover_entrypoint.clone()),
+            )
+            .with_check("l1", L1HealthCheck::new(rpc))
+            .with_check(
+                "orchestrator",
+                TaskHealthCheck::new(certificate_orchestrator_handle.abort_handle()),
+            );
+
         // Bind the core to the RPC server.
+        let admin_server_handle = AdminImpl::new(
+            data_sender.clone(),
+            admin_sender,
+            pending_store.clone(),
+            state_store.clone(),
+            config.clone(),
+        )
+        .start()
+        .await?;
+
         let server_handle = AgglayerImpl::new(
             core,
             data_sender,
             pending_store.clone(),
             state_store.clone(),
             debug_store,
+            epochs_store.clone(),
             config.clone(),
+            clock_ref,
+            state_store.certificate_status_sender(),
         )
+        .with_health_checks(health_checks)
+        .with_settlement_identity(vkey, Address::new(address.0))
         .start()
         .await?;
 
         let rpc_handle = tokio::spawn(async move {
             tokio::select! {
-                _ = server_handle.stopped() => {},
+                _ = server_handle.clone().stopped() => {},
+                _ = admin_server_handle.clone().stopped() => {},
                 _ = cancellation_token.cancelled() => {
                     debug!("Node RPC shutdown requested.");
                 }
             }
+
+            // No certificate is accepted anymore while the orchestrator drains the
+            // work in flight.
+            _ = server_handle.stop();
+            _ = admin_server_handle.stop();
         });
 
         let node = Self {
             rpc_handle,
             certificate_orchestrator_handle,
+            databases: vec![("pending", pending_db), ("state", state_db)],
         };
 
         Ok(node)
     }
 
+    /// Wait for the RPC servers to stop and for the orchestrator to drain the
+    /// work in flight, then flush the write-ahead log of the databases.
     pub(crate) async fn await_shutdown(self) {
         debug!("Node shutdown started.");
         _ = join!(self.rpc_handle, self.certificate_orchestrator_handle);
+
+        for (name, db) in self.databases {
+            if let Err(error) = db.flush_wal() {
+                error!("Failed to flush the write-ahead log of the {name} database: {error}");
+            }
+        }
         debug!("Node shutdown completed.");
     }
 }
@@ -0,0 +1,333 @@
+//! The admin RPC service, used by operators to act on the certificate
+//! lifecycle at runtime.
+
+use std::sync::Arc;
+
+use agglayer_certificate_orchestrator::{AdminCommand, OrchestratorState};
+use agglayer_config::Config;
+use agglayer_storage::stores::{
+    PendingCertificateReader, PendingCertificateWriter, StateReader, StateWriter,
+};
+use agglayer_types::{CertificateId, CertificateStatus, Height, NetworkId};
+use jsonrpsee::{
+    core::async_trait,
+    proc_macros::rpc,
+    server::{HttpBody, HttpRequest, HttpResponse, ServerBuilder, ServerHandle},
+};
+use subtle::ConstantTimeEq as _;
+use tokio::sync::{mpsc, oneshot};
+use tower_http::validate_request::ValidateRequestHeaderLayer;
+use tracing::{error, info, warn};
+
+use crate::rpc::error::{AdminError, Error, RpcResult};
+
+#[rpc(server, namespace = "admin")]
+trait Admin {
+    /// Put a certificate in error back in the pending queue of its network.
+    #[method(name = "requeueCertificate")]
+    async fn requeue_certificate(&self, certificate_id: CertificateId) -> RpcResult<()>;
+
+    /// Remove the pending certificate of a network at a given height.
+    #[method(name = "removePendingCertificate")]
+    async fn remove_pending_certificate(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> RpcResult<()>;
+
+    /// Stop the network task of a network and spawn a new one.
+    #[method(name = "respawnNetworkTask")]
+    async fn respawn_network_task(&self, network_id: NetworkId) -> RpcResult<()>;
+
+    /// Stop certifying the certificates of a network until it is resumed, the
+    /// network remaining paused across the restarts of the node.
+    #[method(name = "pauseNetwork")]
+    async fn pause_network(&self, network_id: NetworkId) -> RpcResult<()>;
+
+    /// Resume the certification of a paused network.
+    #[method(name = "resumeNetwork")]
+    async fn resume_network(&self, network_id: NetworkId) -> RpcResult<()>;
+
+    /// Dump the internal state of the certificate orchestrator, including the
+    /// supervision state of the network tasks.
+    #[method(name = "getOrchestratorState")]
+    async fn get_orchestrator_state(&self) -> RpcResult<OrchestratorState>;
+}
+
+/// The admin RPC service implementation.
+pub(crate) struct AdminImpl<PendingStore, StateStore> {
+    certificate_sender: mpsc::Sender<(NetworkId, Height, CertificateId)>,
+    admin_sender: mpsc::Sender<AdminCommand>,
+    pending_store: Arc<PendingStore>,
+    state: Arc<StateStore>,
+    config: Arc<Config>,
+}
+
+impl<PendingStore, StateStore> AdminImpl<PendingStore, StateStore> {
+    /// Create an instance of the admin RPC service.
+    pub(crate) fn new(
+        certificate_sender: mpsc::Sender<(NetworkId, Height, CertificateId)>,
+        admin_sender: mpsc::Sender<AdminCommand>,
+        pending_store: Arc<PendingStore>,
+        state: Arc<StateStore>,
+        config: Arc<Config>,
+    ) -> Self {
+        Self {
+            certificate_sender,
+            admin_sender,
+            pending_store,
+            state,
+            config,
+        }
+    }
+
+    /// Send a command to the orchestrator and wait for its response.
+    async fn send_command<T>(
+        &self,
+        command: impl FnOnce(oneshot::Sender<T>) -> AdminCommand,
+    ) -> RpcResult<T> {
+        let (response, receiver) = oneshot::channel();
+
+        self.admin_sender
+            .send(command(response))
+            .await
+            .map_err(|_| AdminError::OrchestratorUnavailable)?;
+
+        Ok(receiver
+            .await
+            .map_err(|_| AdminError::OrchestratorUnavailable)?)
+    }
+}
+
+impl<PendingStore, StateStore> AdminImpl<PendingStore, StateStore>
+where
+    PendingStore: PendingCertificateWriter + PendingCertificateReader + 'static,
+    StateStore: StateReader + StateWriter + 'static,
+{
+    pub(crate) async fn start(self) -> anyhow::Result<ServerHandle> {
+        let addr = self.config.admin_rpc_addr();
+        let bearer_token = self.config.rpc.admin.bearer_token.clone();
+
+        // Without a token, only local clients are allowed to reach the admin
+        // namespace.
+        if bearer_token.is_none() && !addr.ip().is_loopback() {
+            anyhow::bail!(
+                "The admin RPC server must be bound to a loopback address when no bearer token is \
+                 configured, got {addr}"
+            );
+        }
+
+        let expected_authorization = bearer_token.map(|token| format!("Bearer {token}"));
+        let middleware = tower::ServiceBuilder::new().layer(ValidateRequestHeaderLayer::custom(
+            move |request: &mut HttpRequest| {
+                let Some(expected) = &expected_authorization else {
+                    return Ok(());
+                };
+
+                let authorization = request
+                    .headers()
+                    .get(hyper::header::AUTHORIZATION)
+                    .and_then(|value| value.to_str().ok());
+
+                // Compared in constant time to not leak the token through the
+                // response time.
+                let authorized = authorization.is_some_and(|authorization| {
+                    authorization.as_bytes().ct_eq(expected.as_bytes()).into()
+                });
+
+                if authorized {
+                    Ok(())
+                } else {
+                    let mut response = HttpResponse::new(HttpBody::empty());
+                    *response.status_mut() = hyper::StatusCode::UNAUTHORIZED;
+
+                    Err(response)
+                }
+            },
+        ));
+
+        let server = ServerBuilder::new()
+            .set_http_middleware(middleware)
+            .build(addr)
+            .await?;
+
+        info!("Admin RPC listening on {addr}");
+
+        Ok(server.start(self.into_rpc()))
+    }
+}
+
+#[async_trait]
+impl<PendingStore, StateStore> AdminServer for AdminImpl<PendingStore, StateStore>
+where
+    PendingStore: PendingCertificateWriter + PendingCertificateReader + 'static,
+    StateStore: StateReader + StateWriter + 'static,
+{
+    async fn requeue_certificate(&self, certificate_id: CertificateId) -> RpcResult<()> {
+        info!("Received request to requeue the certificate {certificate_id}");
+
+        let header = match self.state.get_certificate_header(&certificate_id) {
+            Ok(Some(header)) => header,
+            Ok(None) => {
+                return Err(Error::resource_not_found(format!(
+                    "Certificate({})",
+                    certificate_id
+                )))
+            }
+            Err(error) => {
+                error!("Failed to get certificate header: {}", error);
+
+                return Err(Error::internal("Unable to get certificate header"));
+            }
+        };
+
+        if !matches!(header.status, CertificateStatus::InError { .. }) {
+            return Err(AdminError::CertificateNotInError { certificate_id }.into());
+        }
+
+        // The certificate must still be the one queued for its height.
+        match self
+            .pending_store
+            .get_certificate(header.network_id, header.height)
+        {
+            Ok(Some(certificate)) if certificate.hash() == certificate_id => {}
+            Ok(_) => {
+                return Err(Error::resource_not_found(format!(
+                    "PendingCertificate(network_id: {}, height: {})",
+                    header.network_id, header.height
+                )))
+            }
+            Err(error) => {
+                error!("Failed to get pending certificate: {}", error);
+
+                return Err(Error::internal("Unable to get pending certificate"));
+            }
+        }
+
+        // Drop any proof generated before the failure so that the certificate is
+        // proven again.
+        self.pending_store
+            .remove_generated_proof(&certificate_id)
+            .and_then(|_| {
+                self.state
+                    .update_certificate_header_status(&certificate_id, &CertificateStatus::Pending)
+            })
+            .map_err(|error| {
+                error!("Failed to requeue certificate {certificate_id}: {error}");
+                Error::internal(error.to_string())
+            })?;
+
+        if let Err(error) = self
+            .certificate_sender
+            .send((header.network_id, header.height, certificate_id))
+            .await
+        {
+            error!("Failed to send certificate: {error}");
+
+            return Err(Error::send_certificate(error));
+        }
+
+        Ok(())
+    }
+
+    async fn remove_pending_certificate(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> RpcResult<()> {
+        info!(
+            "Received request to remove the pending certificate of network {network_id} at height \
+             {height}"
+        );
+
+        let certificate = match self.pending_store.get_certificate(network_id, height) {
+            Ok(Some(certificate)) => certificate,
+            Ok(None) => {
+                return Err(Error::resource_not_found(format!(
+                    "PendingCertificate(network_id: {}, height: {})",
+                    network_id, height
+                )))
+            }
+            Err(error) => {
+                error!("Failed to get pending certificate: {}", error);
+
+                return Err(Error::internal("Unable to get pending certificate"));
+            }
+        };
+
+        let certificate_id = certificate.hash();
+        match self.state.get_certificate_header(&certificate_id) {
+            Ok(Some(header))
+                if !matches!(
+                    header.status,
+                    CertificateStatus::Pending | CertificateStatus::InError { .. }
+                ) =>
+            {
+                return Err(AdminError::CertificateNotRemovable {
+                    certificate_id,
+                    status: header.status,
+                }
+                .into());
+            }
+            Ok(_) => {}
+            Err(error) => {
+                error!("Failed to get certificate header: {}", error);
+
+                return Err(Error::internal("Unable to get certificate header"));
+            }
+        }
+
+        self.pending_store
+            .remove_pending_certificate(network_id, height)
+            .map_err(|error| {
+                error!("Failed to remove pending certificate {certificate_id}: {error}");
+                Error::internal(error.to_string())
+            })?;
+
+        warn!(
+            hash = certificate_id.to_string(),
+            "Pending certificate {certificate_id} of network {network_id} at height {height} \
+             removed by an admin request"
+        );
+
+        Ok(())
+    }
+
+    async fn respawn_network_task(&self, network_id: NetworkId) -> RpcResult<()> {
+        info!("Received request to respawn the network task of network {network_id}");
+
+        self.send_command(|response| AdminCommand::RespawnNetworkTask {
+            network_id,
+            response,
+        })
+        .await?
+        .map_err(|detail| AdminError::NetworkTaskRespawn { network_id, detail }.into())
+    }
+
+    async fn pause_network(&self, network_id: NetworkId) -> RpcResult<()> {
+        info!("Received request to pause the certification of network {network_id}");
+
+        self.send_command(|response| AdminCommand::PauseNetwork {
+            network_id,
+            response,
+        })
+        .await?
+        .map_err(|detail| AdminError::NetworkPause { network_id, detail }.into())
+    }
+
+    async fn resume_network(&self, network_id: NetworkId) -> RpcResult<()> {
+        info!("Received request to resume the certification of network {network_id}");
+
+        self.send_command(|response| AdminCommand::ResumeNetwork {
+            network_id,
+            response,
+        })
+        .await?
+        .map_err(|detail| AdminError::NetworkPause { network_id, detail }.into())
+    }
+
+    async fn get_orchestrator_state(&self) -> RpcResult<OrchestratorState> {
+        self.send_command(|response| AdminCommand::DumpState { response })
+            .await
+    }
+}
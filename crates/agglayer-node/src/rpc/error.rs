@@ -1,7 +1,9 @@
 //! Support for structured errors in RPC.
 
+use agglayer_types::{Address, CertificateId, CertificateStatus, Hash, Height, NetworkId};
 use ethers::{middleware::Middleware, types::H256};
 use jsonrpsee::types::error::ErrorObjectOwned;
+use pessimistic_proof::ProofError;
 use serde::Serialize;
 
 use crate::{
@@ -34,6 +36,27 @@ pub mod code {
 
     /// Resource not found.
     pub const RESOURCE_NOT_FOUND: i32 = -10008;
+
+    /// Certificate validation failure.
+    pub const CERTIFICATE_VALIDATION: i32 = -10009;
+
+    /// Admin operation failure.
+    pub const ADMIN: i32 = -10010;
+
+    /// Certificate not signed by the trusted sequencer of its network.
+    pub const INVALID_CERTIFICATE_SIGNER: i32 = -10011;
+
+    /// Certificate not submitted at the next expected height of its network.
+    pub const UNEXPECTED_CERTIFICATE_HEIGHT: i32 = -10012;
+
+    /// Certificate not chained to the latest local exit root of its network.
+    pub const PREV_LOCAL_EXIT_ROOT_MISMATCH: i32 = -10013;
+
+    /// Another certificate is already pending for the same network and height.
+    pub const CONFLICTING_CERTIFICATE: i32 = -10014;
+
+    /// Certification of the network paused, its certificates being rejected.
+    pub const NETWORK_PAUSED: i32 = -10015;
 }
 
 #[derive(PartialEq, Eq, Serialize, Debug, Clone, thiserror::Error)]
@@ -46,6 +69,52 @@ pub enum ValidationError {
     RootVerification { detail: String },
 }
 
+#[derive(PartialEq, Eq, Serialize, Debug, Clone, thiserror::Error)]
+#[serde(rename_all = "kebab-case")]
+pub enum CertificateValidationError {
+    #[error("Unable to retrieve the L1 info root for the leaf count {leaf_count}")]
+    #[serde(rename_all = "kebab-case")]
+    L1InfoRootNotFound { leaf_count: u32 },
+
+    #[error("Invalid certificate: {0}")]
+    InvalidCertificate(agglayer_types::Error),
+
+    #[error("Native execution failed: {0}")]
+    NativeExecution(ProofError),
+}
+
+#[derive(PartialEq, Eq, Serialize, Debug, Clone, thiserror::Error)]
+#[serde(rename_all = "kebab-case")]
+pub enum AdminError {
+    #[error("Certificate {certificate_id} is not in error")]
+    #[serde(rename_all = "kebab-case")]
+    CertificateNotInError { certificate_id: CertificateId },
+
+    #[error("Certificate {certificate_id} cannot be removed while {status}")]
+    #[serde(rename_all = "kebab-case")]
+    CertificateNotRemovable {
+        certificate_id: CertificateId,
+        status: CertificateStatus,
+    },
+
+    #[error("Failed to respawn the network task of network {network_id}: {detail}")]
+    #[serde(rename_all = "kebab-case")]
+    NetworkTaskRespawn {
+        network_id: NetworkId,
+        detail: String,
+    },
+
+    #[error("Failed to pause or resume the certification of network {network_id}: {detail}")]
+    #[serde(rename_all = "kebab-case")]
+    NetworkPause {
+        network_id: NetworkId,
+        detail: String,
+    },
+
+    #[error("The certificate orchestrator is unavailable")]
+    OrchestratorUnavailable,
+}
+
 #[derive(PartialEq, Eq, Serialize, Debug, Clone, thiserror::Error)]
 #[serde(rename_all = "kebab-case")]
 pub enum SettlementError {
@@ -129,6 +198,51 @@ pub enum Error {
     #[error("Resource not found: {0}")]
     ResourceNotFound(String),
 
+    #[error("Certificate validation failed: {0}")]
+    CertificateValidation(#[from] CertificateValidationError),
+
+    #[error("Admin operation failed: {0}")]
+    Admin(#[from] AdminError),
+
+    #[error("Invalid certificate signer: expected {trusted_sequencer}, got {signer:?}")]
+    #[serde(rename_all = "kebab-case")]
+    InvalidCertificateSigner {
+        signer: Option<Address>,
+        trusted_sequencer: Address,
+    },
+
+    #[error("Unexpected certificate height: expected {expected_height}, got {height}")]
+    #[serde(rename_all = "kebab-case")]
+    UnexpectedCertificateHeight {
+        height: Height,
+        expected_height: Height,
+    },
+
+    #[error(
+        "Mismatch on the previous local exit root: expected {expected}, got {prev_local_exit_root}"
+    )]
+    #[serde(rename_all = "kebab-case")]
+    PrevLocalExitRootMismatch {
+        prev_local_exit_root: Hash,
+        expected: Hash,
+    },
+
+    #[error(
+        "Certificate {existing_certificate_id} is already {status} for network {network_id} at \
+         height {height}"
+    )]
+    #[serde(rename_all = "kebab-case")]
+    ConflictingCertificate {
+        network_id: NetworkId,
+        height: Height,
+        existing_certificate_id: CertificateId,
+        status: CertificateStatus,
+    },
+
+    #[error("Certification of network {network_id} is paused")]
+    #[serde(rename_all = "kebab-case")]
+    NetworkPaused { network_id: NetworkId },
+
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -174,6 +288,13 @@ impl Error {
         match self {
             Self::Internal(_) => jsonrpsee::types::error::INTERNAL_ERROR_CODE,
             Self::ResourceNotFound { .. } => code::RESOURCE_NOT_FOUND,
+            Self::CertificateValidation(_) => code::CERTIFICATE_VALIDATION,
+            Self::Admin(_) => code::ADMIN,
+            Self::InvalidCertificateSigner { .. } => code::INVALID_CERTIFICATE_SIGNER,
+            Self::UnexpectedCertificateHeight { .. } => code::UNEXPECTED_CERTIFICATE_HEIGHT,
+            Self::PrevLocalExitRootMismatch { .. } => code::PREV_LOCAL_EXIT_ROOT_MISMATCH,
+            Self::ConflictingCertificate { .. } => code::CONFLICTING_CERTIFICATE,
+            Self::NetworkPaused { .. } => code::NETWORK_PAUSED,
             Self::RollupNotRegistered { .. } => code::ROLLUP_NOT_REGISTERED,
             Self::SignatureMismatch { .. } => code::SIGNATURE_MISMATCH,
             Self::Validation(_) => code::VALIDATION_FAILURE,
@@ -1,19 +1,32 @@
 use std::sync::Arc;
 
+use agglayer_aggregator_notifier::{execute_pessimistic_proof, pessimistic_proof_vkey};
+use agglayer_certificate_orchestrator::CertificationError;
+use agglayer_clock::ClockRef;
+use agglayer_config::certificate_orchestrator::PausedNetworkPolicy;
 use agglayer_config::epoch::BlockClockConfig;
 use agglayer_config::Config;
 use agglayer_config::Epoch;
+use agglayer_contracts::RollupContract as _;
 use agglayer_storage::columns::latest_settled_certificate_per_network::SettledCertificate;
 use agglayer_storage::stores::DebugReader;
 use agglayer_storage::stores::DebugWriter;
+use agglayer_storage::stores::EpochStoreReader;
+use agglayer_storage::stores::MetadataReader;
 use agglayer_storage::stores::PendingCertificateReader;
 use agglayer_storage::stores::PendingCertificateWriter;
 use agglayer_storage::stores::StateReader;
 use agglayer_storage::stores::StateWriter;
 use agglayer_telemetry::KeyValue;
 use agglayer_types::CertificateStatus;
+use agglayer_types::CertificateStatusError;
 use agglayer_types::EpochConfiguration;
-use agglayer_types::{Certificate, CertificateHeader, CertificateId, Height, NetworkId};
+use agglayer_types::{
+    Address, Certificate, CertificateHeader, CertificateId, EpochNumber, Hash, Height,
+    Keccak256Hasher, LocalExitTreeProof, LocalNetworkStateData, NetworkId, NullifierProof,
+    PessimisticProofFixture, Proof, U256,
+};
+use alloy::primitives::Bytes;
 use ethers::{
     contract::{ContractError, ContractRevert},
     providers::Middleware,
@@ -21,28 +34,45 @@ use ethers::{
 };
 use futures::TryFutureExt;
 use jsonrpsee::{
-    core::async_trait,
+    core::{async_trait, SubscriptionResult},
     proc_macros::rpc,
     server::{middleware::http::ProxyGetRequestLayer, PingConfig, ServerBuilder, ServerHandle},
+    types::{error::INTERNAL_ERROR_CODE, ErrorObjectOwned},
+    PendingSubscriptionSink, SubscriptionMessage, SubscriptionSink,
+};
+use pessimistic_proof::{
+    bridge_exit::TokenInfo, global_index::GlobalIndex, local_balance_tree::LocalBalancePath,
+    local_state::StateCommitment, PessimisticProofOutput,
+};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    sync::{broadcast, mpsc},
+    try_join,
 };
-use tokio::{sync::mpsc, try_join};
 use tower_http::cors::CorsLayer;
 use tracing::trace;
-use tracing::{debug, error, info, instrument};
+use tracing::{debug, error, info, instrument, warn};
 
 use crate::{
+    health::HealthChecks,
     kernel::Kernel,
-    rpc::error::{Error, RpcResult, StatusError},
+    rpc::error::{CertificateValidationError, Error, RpcResult, StatusError},
     signed_tx::SignedTx,
 };
 
-mod error;
+pub(crate) mod admin;
+pub(crate) mod error;
+pub(crate) mod openrpc;
 mod rpc_middleware;
 
 #[cfg(test)]
 mod tests;
 
-#[rpc(server, namespace = "interop")]
+#[cfg_attr(not(any(test, feature = "client")), rpc(server, namespace = "interop"))]
+#[cfg_attr(
+    any(test, feature = "client"),
+    rpc(client, server, namespace = "interop")
+)]
 trait Agglayer {
     #[method(name = "sendTx")]
     async fn send_tx(&self, tx: SignedTx) -> RpcResult<H256>;
@@ -53,6 +83,12 @@ trait Agglayer {
     #[method(name = "sendCertificate")]
     async fn send_certificate(&self, certificate: Certificate) -> RpcResult<CertificateId>;
 
+    #[method(name = "validateCertificate")]
+    async fn validate_certificate(
+        &self,
+        certificate: Certificate,
+    ) -> RpcResult<PessimisticProofOutput>;
+
     #[method(name = "getCertificateHeader")]
     async fn get_certificate_header(
         &self,
@@ -68,72 +104,682 @@ trait Agglayer {
         network_id: NetworkId,
     ) -> RpcResult<Option<CertificateHeader>>;
 
+    #[method(name = "getCertificateHeaderByHeight")]
+    async fn get_certificate_header_by_height(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> RpcResult<CertificateHeader>;
+
+    #[method(name = "getCertificateHeaders")]
+    async fn get_certificate_headers(
+        &self,
+        network_id: NetworkId,
+        from_height: Height,
+        limit: usize,
+        status_filter: Option<CertificateStatusKind>,
+    ) -> RpcResult<CertificateHeadersPage>;
+
+    #[method(name = "getLocalNetworkStateRoots")]
+    async fn get_local_network_state_roots(
+        &self,
+        network_id: NetworkId,
+    ) -> RpcResult<StateCommitment>;
+
+    #[method(name = "getTokenBalance")]
+    async fn get_token_balance(
+        &self,
+        network_id: NetworkId,
+        token_info: TokenInfo,
+    ) -> RpcResult<TokenBalance>;
+
+    #[method(name = "getNullifierStatus")]
+    async fn get_nullifier_status(
+        &self,
+        network_id: NetworkId,
+        global_index: GlobalIndex,
+    ) -> RpcResult<NullifierStatus>;
+
+    #[method(name = "getLocalExitTreeProof")]
+    async fn get_local_exit_tree_proof(
+        &self,
+        network_id: NetworkId,
+        leaf_index: u32,
+        leaf_count: Option<u32>,
+    ) -> RpcResult<LocalExitTreeProof>;
+
+    #[method(name = "getCertificateProof")]
+    async fn get_certificate_proof(
+        &self,
+        certificate_id: CertificateId,
+        format: Option<CertificateProofFormat>,
+    ) -> RpcResult<CertificateProofResponse>;
+
+    #[method(name = "getNodeInfo")]
+    async fn get_node_info(&self) -> RpcResult<NodeInfo>;
+
     #[method(name = "debugGetCertificate")]
     async fn debug_get_certificate(
         &self,
         certificate_id: CertificateId,
     ) -> RpcResult<(Certificate, Option<CertificateHeader>)>;
+
+    /// Subscribe to the status transitions of a certificate or of every
+    /// certificate of a network.
+    #[subscription(
+        name = "subscribeCertificateStatus" => "certificateStatus",
+        unsubscribe = "unsubscribeCertificateStatus",
+        item = CertificateHeader
+    )]
+    async fn subscribe_certificate_status(
+        &self,
+        filter: CertificateStatusFilter,
+    ) -> SubscriptionResult;
+
+    /// Subscribe to the end of every epoch and to the reorgs of the L1.
+    #[subscription(
+        name = "subscribeEpochs" => "epochs",
+        unsubscribe = "unsubscribeEpochs",
+        item = EpochEvent
+    )]
+    async fn subscribe_epochs(&self) -> SubscriptionResult;
+}
+
+/// Filter applied to the certificate status subscription.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum CertificateStatusFilter {
+    /// Only notify the transitions of one certificate.
+    CertificateId(CertificateId),
+    /// Notify the transitions of every certificate of a network.
+    NetworkId(NetworkId),
+}
+
+impl CertificateStatusFilter {
+    fn matches(&self, header: &CertificateHeader) -> bool {
+        match self {
+            Self::CertificateId(certificate_id) => header.certificate_id == *certificate_id,
+            Self::NetworkId(network_id) => header.network_id == *network_id,
+        }
+    }
+}
+
+/// Maximum number of headers returned by a single `getCertificateHeaders`
+/// call.
+const MAX_CERTIFICATE_HEADERS_PER_PAGE: usize = 100;
+
+/// Maximum number of heights scanned by a single `getCertificateHeaders` call,
+/// the page ending early with a cursor once reached.
+const MAX_CERTIFICATE_HEIGHTS_SCANNED_PER_PAGE: usize = 1_000;
+
+/// Status of a certificate without its associated data, used to filter the
+/// certificate headers.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CertificateStatusKind {
+    Pending,
+    Proven,
+    Candidate,
+    InError,
+    Settled,
+}
+
+impl From<&CertificateStatus> for CertificateStatusKind {
+    fn from(status: &CertificateStatus) -> Self {
+        match status {
+            CertificateStatus::Pending => Self::Pending,
+            CertificateStatus::Proven => Self::Proven,
+            CertificateStatus::Candidate => Self::Candidate,
+            CertificateStatus::InError { .. } => Self::InError,
+            CertificateStatus::Settled => Self::Settled,
+        }
+    }
+}
+
+/// A page of certificate headers of one network.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CertificateHeadersPage {
+    /// The certificate headers matching the status filter, ordered by height.
+    pub headers: Vec<CertificateHeader>,
+    /// The height to request the next page from, `None` once the latest known
+    /// height has been reached.
+    pub next_height: Option<Height>,
+}
+
+/// Balance of a token in the local balance tree of a network.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenBalance {
+    pub token_info: TokenInfo,
+    pub balance: U256,
+    /// The balance root the proof is verified against.
+    pub balance_root: Hash,
+    /// Inclusion proof of the balance in the local balance tree.
+    pub proof: LocalBalancePath<Keccak256Hasher>,
+}
+
+/// Claim status of a global index in the nullifier tree of a network.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NullifierStatus {
+    pub global_index: GlobalIndex,
+    pub claimed: bool,
+    /// The nullifier root the proof is verified against.
+    pub nullifier_root: Hash,
+    /// Inclusion proof if the global index is claimed, non-inclusion proof
+    /// otherwise.
+    pub proof: NullifierProof,
+}
+
+/// Event notified to the epoch subscribers.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum EpochEvent {
+    /// The epoch with the associated number just ended.
+    EpochEnded(EpochNumber),
+    /// The L1 reorganized, the L1 blocks from the associated block number
+    /// onwards were replaced.
+    L1Reorg(u64),
+}
+
+/// Format of the proof returned by `getCertificateProof`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum CertificateProofFormat {
+    /// The proof along with its decoded public values.
+    #[default]
+    Proof,
+    /// The fixture used to verify the proof with the Solidity verifier, as
+    /// written by `ppgen`.
+    Fixture,
+}
+
+/// Proof generated for a certificate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CertificateProof {
+    pub certificate_id: CertificateId,
+    /// The proof bytes as expected by the L1 verifier, unset if the proof
+    /// can't be verified onchain.
+    pub proof: Option<Bytes>,
+    /// The decoded public values of the proof.
+    pub public_values: PessimisticProofOutput,
+    /// The SP1 version used to generate the proof.
+    pub sp1_version: String,
+    /// The verification key of the pessimistic proof program.
+    pub vkey: String,
+}
+
+/// Response of `getCertificateProof`, depending on the requested format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CertificateProofResponse {
+    Proof(CertificateProof),
+    Fixture(PessimisticProofFixture),
+}
+
+/// Kind of the clock driving the epochs.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum EpochClockKind {
+    BlockClock,
+    TimeClock,
+}
+
+/// L1 chain and contracts the node settles on.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct L1Info {
+    pub chain_id: u64,
+    pub rollup_manager_contract: Address,
+    pub global_exit_root_contract: Address,
+}
+
+/// Versions, keys and contract wiring of the node.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NodeInfo {
+    /// The version of the agglayer.
+    pub version: String,
+    /// The verification key of the pessimistic proof program computed by the
+    /// certifier, unset if the node doesn't run a certifier.
+    pub vkey: Option<String>,
+    /// The address signing the settlement transactions, unset if the node
+    /// doesn't settle.
+    pub settlement_signer: Option<Address>,
+    pub l1: L1Info,
+    pub epoch_clock: EpochClockKind,
+    pub debug_mode: bool,
+}
+
+impl NodeInfo {
+    /// Node information available from the configuration alone.
+    fn from_config(config: &Config) -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            vkey: None,
+            settlement_signer: None,
+            l1: L1Info {
+                chain_id: config.l1.chain_id,
+                rollup_manager_contract: Address::new(config.l1.rollup_manager_contract.0),
+                global_exit_root_contract: Address::new(
+                    config.l1.polygon_zkevm_global_exit_root_v2_contract.0,
+                ),
+            },
+            epoch_clock: match config.epoch {
+                Epoch::BlockClock(_) => EpochClockKind::BlockClock,
+                Epoch::TimeClock(_) => EpochClockKind::TimeClock,
+            },
+            debug_mode: config.debug_mode,
+        }
+    }
+}
+
+/// Outcome of the lookup of the pending queue slot targeted by a submitted
+/// certificate.
+enum PendingSlot {
+    /// No certificate is pending for this network and height.
+    Free,
+    /// The same certificate is already pending and not in error.
+    AlreadyPending,
+    /// A certificate in error is pending and is replaced by the submitted one.
+    Replacing(CertificateId),
 }
 
 /// The RPC agglayer service implementation.
-pub(crate) struct AgglayerImpl<Rpc, PendingStore, StateStore, DebugStore> {
+pub(crate) struct AgglayerImpl<Rpc, PendingStore, StateStore, DebugStore, EpochsStore> {
     kernel: Kernel<Rpc>,
     certificate_sender: mpsc::Sender<(NetworkId, Height, CertificateId)>,
     pending_store: Arc<PendingStore>,
     state: Arc<StateStore>,
     debug_store: Arc<DebugStore>,
+    epochs_store: Arc<EpochsStore>,
     config: Arc<Config>,
+    clock_ref: ClockRef,
+    certificate_status_sender: broadcast::Sender<CertificateHeader>,
+    health_checks: HealthChecks,
+    node_info: NodeInfo,
 }
 
-impl<Rpc, PendingStore, StateStore, DebugStore>
-    AgglayerImpl<Rpc, PendingStore, StateStore, DebugStore>
+impl<Rpc, PendingStore, StateStore, DebugStore, EpochsStore>
+    AgglayerImpl<Rpc, PendingStore, StateStore, DebugStore, EpochsStore>
 {
     /// Create an instance of the RPC agglayer service.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         kernel: Kernel<Rpc>,
         certificate_sender: mpsc::Sender<(NetworkId, Height, CertificateId)>,
         pending_store: Arc<PendingStore>,
         state: Arc<StateStore>,
         debug_store: Arc<DebugStore>,
+        epochs_store: Arc<EpochsStore>,
         config: Arc<Config>,
+        clock_ref: ClockRef,
+        certificate_status_sender: broadcast::Sender<CertificateHeader>,
     ) -> Self {
+        let health_checks = HealthChecks::new(config.rpc.health.check_timeout);
+        let node_info = NodeInfo::from_config(&config);
+
         Self {
             kernel,
             certificate_sender,
             pending_store,
             state,
             debug_store,
+            epochs_store,
             config,
+            clock_ref,
+            certificate_status_sender,
+            health_checks,
+            node_info,
         }
     }
+
+    /// Set the health checks of the node components, reported by
+    /// `system_health` and `system_ready`.
+    pub(crate) fn with_health_checks(mut self, health_checks: HealthChecks) -> Self {
+        self.health_checks = health_checks;
+        self
+    }
+
+    /// Set the verification key used by the certifier and the address
+    /// signing the settlement transactions, reported by `getNodeInfo`.
+    pub(crate) fn with_settlement_identity(mut self, vkey: String, signer: Address) -> Self {
+        self.node_info.vkey = Some(vkey);
+        self.node_info.settlement_signer = Some(signer);
+        self
+    }
 }
 
-impl<Rpc, PendingStore, StateStore, DebugStore> Drop
-    for AgglayerImpl<Rpc, PendingStore, StateStore, DebugStore>
+impl<Rpc, PendingStore, StateStore, DebugStore, EpochsStore> Drop
+    for AgglayerImpl<Rpc, PendingStore, StateStore, DebugStore, EpochsStore>
 {
     fn drop(&mut self) {
         info!("Shutting down the agglayer service");
     }
 }
 
-impl<Rpc, PendingStore, StateStore, DebugStore>
-    AgglayerImpl<Rpc, PendingStore, StateStore, DebugStore>
+impl<Rpc, PendingStore, StateStore, DebugStore, EpochsStore>
+    AgglayerImpl<Rpc, PendingStore, StateStore, DebugStore, EpochsStore>
 where
     Rpc: Middleware + 'static,
     PendingStore: PendingCertificateWriter + PendingCertificateReader + 'static,
-    StateStore: StateReader + StateWriter + 'static,
+    StateStore: StateReader + StateWriter + MetadataReader + 'static,
     DebugStore: DebugReader + DebugWriter + 'static,
+    EpochsStore: EpochStoreReader + 'static,
 {
+    /// Look up the header of the certificate of a network at a given height.
+    ///
+    /// Settled certificates are indexed per height in the state store, the
+    /// others are resolved from the pending queue or from the latest proven
+    /// certificate of the network.
+    fn certificate_header_at_height(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> Result<Option<CertificateHeader>, agglayer_storage::error::Error> {
+        if let Some(header) = self
+            .state
+            .get_certificate_header_by_cursor(network_id, height)?
+        {
+            return Ok(Some(header));
+        }
+
+        self.non_settled_certificate_header_at_height(network_id, height)
+    }
+
+    /// The highest height of the network known to the node, whether settled,
+    /// proven or pending.
+    fn latest_known_height(
+        &self,
+        network_id: NetworkId,
+    ) -> Result<Option<Height>, agglayer_storage::error::Error> {
+        let settled = self
+            .state
+            .get_latest_settled_certificate_per_network(&network_id)?
+            .map(|(_, SettledCertificate(_, height, _, _))| height);
+        let proven = self
+            .pending_store
+            .get_current_proven_height_for_network(&network_id)?;
+        let pending = self
+            .pending_store
+            .get_pending_certificates_for_network(&network_id)?
+            .last()
+            .map(|certificate| certificate.height);
+
+        Ok([settled, proven, pending].into_iter().flatten().max())
+    }
+
+    fn non_settled_certificate_header_at_height(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> Result<Option<CertificateHeader>, agglayer_storage::error::Error> {
+        let certificate_id = match self.pending_store.get_certificate(network_id, height)? {
+            Some(certificate) => Some(certificate.hash()),
+            None => self
+                .pending_store
+                .get_latest_proven_certificate_per_network(&network_id)?
+                .and_then(|(_, proven_height, certificate_id)| {
+                    (proven_height == height).then_some(certificate_id)
+                }),
+        };
+
+        match certificate_id {
+            Some(certificate_id) => self.state.get_certificate_header(&certificate_id),
+            None => Ok(None),
+        }
+    }
+
+    /// Check that a certificate can be certified before accepting it.
+    ///
+    /// The certificate must be signed by the trusted sequencer of its network
+    /// (or by its configured proof signer), be submitted at the height
+    /// following the latest settled or proven certificate, and start from the
+    /// local exit root that this certificate produced.
+    /// Check that the certificate is signed by the trusted sequencer of its
+    /// network.
+    async fn check_certificate_signer(&self, certificate: &Certificate) -> RpcResult<()> {
+        let network_id = certificate.network_id;
+
+        let trusted_sequencer = self
+            .kernel
+            .get_l1_rpc_client()
+            .get_trusted_sequencer_address(*network_id, self.config.proof_signers.clone())
+            .await
+            .map_err(|_| {
+                error!("Failed to retrieve the trusted sequencer of network {network_id}");
+                Error::internal("Unable to retrieve the trusted sequencer")
+            })?;
+        let trusted_sequencer = Address::new(*trusted_sequencer.as_fixed_bytes());
+
+        let signer = certificate.signer();
+        if signer != Some(trusted_sequencer) {
+            return Err(Error::InvalidCertificateSigner {
+                signer,
+                trusted_sequencer,
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn check_certificate_ingress(&self, certificate: &Certificate) -> RpcResult<()> {
+        let network_id = certificate.network_id;
+
+        self.check_certificate_signer(certificate).await?;
+
+        let storage_error = |error: agglayer_storage::error::Error| {
+            error!("Failed to read the latest certificate of network {network_id}: {error}");
+            Error::internal("Unable to read the latest certificate")
+        };
+
+        let latest_settled = self
+            .state
+            .get_latest_settled_certificate_per_network(&network_id)
+            .map_err(storage_error)?
+            .map(|(_, SettledCertificate(certificate_id, height, _, _))| (height, certificate_id));
+        let latest_proven = self
+            .pending_store
+            .get_latest_proven_certificate_per_network(&network_id)
+            .map_err(storage_error)?
+            .map(|(_, height, certificate_id)| (height, certificate_id));
+
+        let Some((latest_height, latest_certificate_id)) = latest_settled.max(latest_proven) else {
+            // The first certificate of a network has no predecessor to check against.
+            return if certificate.height == 0 {
+                Ok(())
+            } else {
+                Err(Error::UnexpectedCertificateHeight {
+                    height: certificate.height,
+                    expected_height: 0,
+                })
+            };
+        };
+
+        let expected_height = latest_height + 1;
+        if certificate.height != expected_height {
+            return Err(Error::UnexpectedCertificateHeight {
+                height: certificate.height,
+                expected_height,
+            });
+        }
+
+        let latest_header = self
+            .state
+            .get_certificate_header(&latest_certificate_id)
+            .map_err(storage_error)?
+            .ok_or_else(|| {
+                error!("Missing header of the latest certificate {latest_certificate_id}");
+                Error::internal("Unable to read the latest certificate")
+            })?;
+
+        let prev_local_exit_root: Hash = certificate.prev_local_exit_root.into();
+        if prev_local_exit_root != latest_header.new_local_exit_root {
+            return Err(Error::PrevLocalExitRootMismatch {
+                prev_local_exit_root,
+                expected: latest_header.new_local_exit_root,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Reject the certificates of a paused network, unless they are configured
+    /// to wait in the pending queue until the network is resumed.
+    fn check_paused_network(&self, network_id: NetworkId) -> RpcResult<()> {
+        if self.config.certificate_orchestrator.paused_network_policy != PausedNetworkPolicy::Reject
+        {
+            return Ok(());
+        }
+
+        let paused_networks = self.state.get_paused_networks().map_err(|error| {
+            error!("Failed to read the paused networks: {error}");
+            Error::internal("Unable to read the paused networks")
+        })?;
+
+        if paused_networks.contains(&network_id) {
+            return Err(Error::NetworkPaused { network_id });
+        }
+
+        Ok(())
+    }
+
+    /// Load a certificate along with its generated proof.
+    ///
+    /// Certificates assigned to an epoch are read from the storage of that
+    /// epoch, the others from the pending store.
+    fn certificate_with_proof(
+        &self,
+        certificate_id: CertificateId,
+    ) -> RpcResult<(Certificate, Proof)> {
+        let storage_error = |error: agglayer_storage::error::Error| {
+            error!("Failed to read the proof of certificate {certificate_id}: {error}");
+            Error::internal("Unable to read the certificate proof")
+        };
+
+        let header = self
+            .state
+            .get_certificate_header(&certificate_id)
+            .map_err(storage_error)?
+            .ok_or_else(|| Error::resource_not_found(format!("Certificate({})", certificate_id)))?;
+
+        let (certificate, proof) = match (header.epoch_number, header.certificate_index) {
+            (Some(epoch_number), Some(certificate_index)) => (
+                self.epochs_store
+                    .get_certificate_in_epoch(epoch_number, certificate_index)
+                    .map_err(storage_error)?,
+                self.epochs_store
+                    .get_proof_in_epoch(epoch_number, certificate_index)
+                    .map_err(storage_error)?,
+            ),
+            _ => (
+                self.pending_store
+                    .get_certificate(header.network_id, header.height)
+                    .map_err(storage_error)?
+                    .filter(|certificate| certificate.hash() == certificate_id),
+                self.pending_store
+                    .get_proof(certificate_id)
+                    .map_err(storage_error)?,
+            ),
+        };
+
+        match (certificate, proof) {
+            (Some(certificate), Some(proof)) => Ok((certificate, proof)),
+            _ => Err(Error::resource_not_found(format!(
+                "Proof({})",
+                certificate_id
+            ))),
+        }
+    }
+
+    /// Check the pending queue slot targeted by a certificate.
+    ///
+    /// A certificate pending for the same network and height can only be
+    /// replaced once it is in error, any other conflicting submission is
+    /// rejected.
+    fn check_pending_slot(&self, certificate: &Certificate) -> RpcResult<PendingSlot> {
+        let network_id = certificate.network_id;
+        let height = certificate.height;
+
+        let storage_error = |error: agglayer_storage::error::Error| {
+            error!(
+                "Failed to read the pending certificate of network {network_id} at height \
+                 {height}: {error}"
+            );
+            Error::internal("Unable to read the pending certificate")
+        };
+
+        let Some(pending) = self
+            .pending_store
+            .get_certificate(network_id, height)
+            .map_err(storage_error)?
+        else {
+            return Ok(PendingSlot::Free);
+        };
+
+        let pending_id = pending.hash();
+        let status = self
+            .state
+            .get_certificate_header(&pending_id)
+            .map_err(storage_error)?
+            .map(|header| header.status);
+
+        match status {
+            Some(CertificateStatus::InError { .. }) | None if pending_id == certificate.hash() => {
+                Ok(PendingSlot::Free)
+            }
+            Some(CertificateStatus::InError { .. }) | None => {
+                Ok(PendingSlot::Replacing(pending_id))
+            }
+            Some(_) if pending_id == certificate.hash() => Ok(PendingSlot::AlreadyPending),
+            Some(status) => Err(Error::ConflictingCertificate {
+                network_id,
+                height,
+                existing_certificate_id: pending_id,
+                status,
+            }),
+        }
+    }
+
+    /// Load the persisted local network state of a network.
+    fn local_network_state(&self, network_id: NetworkId) -> RpcResult<LocalNetworkStateData> {
+        match self.state.read_local_network_state(network_id) {
+            Ok(Some(state)) => Ok(state),
+            Ok(None) => Err(Error::resource_not_found(format!(
+                "LocalNetworkState({})",
+                network_id
+            ))),
+            Err(error) => {
+                error!("Failed to read the local network state: {}", error);
+
+                Err(Error::internal("Unable to read the local network state"))
+            }
+        }
+    }
+
     pub(crate) async fn start(self) -> anyhow::Result<ServerHandle> {
         // Create the RPC service
         let config = self.config.clone();
         let mut service = self.into_rpc();
 
-        // Register the system_health method to serve health checks.
-        service.register_method(
-            "system_health",
-            |_, _, _| serde_json::json!({ "health": true }),
-        )?;
+        // Register the system_health method to report the health of the node
+        // components.
+        service.register_async_method("system_health", |_, agglayer, _| async move {
+            Ok::<_, ErrorObjectOwned>(agglayer.health_checks.report().await)
+        })?;
+
+        // Register the system_ready method to serve readiness checks, failing
+        // as soon as one of the components is unhealthy.
+        service.register_async_method("system_ready", |_, agglayer, _| async move {
+            let report = agglayer.health_checks.report().await;
+            if report.health {
+                Ok(report)
+            } else {
+                Err(ErrorObjectOwned::owned(
+                    INTERNAL_ERROR_CODE,
+                    "The node isn't ready",
+                    Some(report),
+                ))
+            }
+        })?;
+
+        // Register the rpc.discover method to serve the OpenRPC document.
+        service.register_method("rpc.discover", |_, _, _| openrpc::document())?;
 
         // Create the RPC server.
         let mut server_builder = ServerBuilder::new()
@@ -167,10 +813,11 @@ where
             .allow_origin(tower_http::cors::Any)
             .allow_headers([hyper::header::CONTENT_TYPE]);
 
-        // Create a middleware stack with the CORS middleware and a proxy layer for
-        // health checks.
+        // Create a middleware stack with the CORS middleware and proxy layers for
+        // health and readiness checks.
         let middleware = tower::ServiceBuilder::new()
             .layer(ProxyGetRequestLayer::new("/health", "system_health")?)
+            .layer(ProxyGetRequestLayer::new("/ready", "system_ready")?)
             .layer(cors);
 
         let addr = config.rpc_addr();
@@ -188,13 +835,14 @@ where
 }
 
 #[async_trait]
-impl<Rpc, PendingStore, StateStore, DebugStore> AgglayerServer
-    for AgglayerImpl<Rpc, PendingStore, StateStore, DebugStore>
+impl<Rpc, PendingStore, StateStore, DebugStore, EpochsStore> AgglayerServer
+    for AgglayerImpl<Rpc, PendingStore, StateStore, DebugStore, EpochsStore>
 where
     Rpc: Middleware + 'static,
     PendingStore: PendingCertificateWriter + PendingCertificateReader + 'static,
-    StateStore: StateReader + StateWriter + 'static,
+    StateStore: StateReader + StateWriter + MetadataReader + 'static,
     DebugStore: DebugReader + DebugWriter + 'static,
+    EpochsStore: EpochStoreReader + 'static,
 {
     #[instrument(skip(self, tx), fields(hash, rollup_id = tx.tx.rollup_id), level = "info")]
     async fn send_tx(&self, tx: SignedTx) -> RpcResult<H256> {
@@ -329,6 +977,33 @@ where
             "Received certificate {hash} for rollup {} at height {}", *certificate.network_id, certificate.height
         );
 
+        if let Err(error) = self.check_paused_network(certificate.network_id) {
+            warn!(%hash, "Rejected certificate {hash}: {error}");
+
+            return Err(error);
+        }
+
+        if let Err(error) = self.check_certificate_ingress(&certificate).await {
+            warn!(%hash, "Rejected certificate {hash}: {error}");
+
+            return Err(error);
+        }
+
+        let replaced = match self.check_pending_slot(&certificate) {
+            Ok(PendingSlot::Free) => None,
+            Ok(PendingSlot::AlreadyPending) => {
+                debug!(%hash, "Certificate {hash} is already pending");
+
+                return Ok(hash);
+            }
+            Ok(PendingSlot::Replacing(replaced)) => Some(replaced),
+            Err(error) => {
+                warn!(%hash, "Rejected certificate {hash}: {error}");
+
+                return Err(error);
+            }
+        };
+
         // TODO: Batch the different queries.
         // Insert the certificate header into the state store.
         _ = self
@@ -343,11 +1018,43 @@ where
         _ = self
             .pending_store
             .insert_pending_certificate(certificate.network_id, certificate.height, &certificate)
+            .and_then(|_| {
+                self.pending_store.set_pending_since(
+                    certificate.network_id,
+                    certificate.height,
+                    self.clock_ref.current_epoch(),
+                )
+            })
             .map_err(|e| {
                 error!("Failed to insert certificate into pending store: {e}");
                 Error::internal(e.to_string())
             })?;
 
+        // Keep the replaced certificate around, pointing to its replacement.
+        if let Some(replaced) = replaced {
+            self.pending_store
+                .remove_generated_proof(&replaced)
+                .and_then(|_| {
+                    self.state.update_certificate_header_status(
+                        &replaced,
+                        &CertificateStatus::InError {
+                            error: CertificateStatusError::Superseded(hash),
+                        },
+                    )
+                })
+                .map_err(|e| {
+                    error!("Failed to mark certificate {replaced} as superseded: {e}");
+                    Error::internal(e.to_string())
+                })?;
+
+            warn!(
+                %hash,
+                "Certificate {replaced} in error superseded by {hash} for rollup {} at height {}",
+                *certificate.network_id,
+                certificate.height
+            );
+        }
+
         _ = self.debug_store.add_certificate(&certificate).map_err(|e| {
             error!("Failed to insert certificate into debug store: {e}");
             Error::internal(e.to_string())
@@ -370,6 +1077,56 @@ where
         Ok(hash)
     }
 
+    async fn validate_certificate(
+        &self,
+        certificate: Certificate,
+    ) -> RpcResult<PessimisticProofOutput> {
+        let network_id = certificate.network_id;
+        debug!(
+            "Received request to validate the certificate {} of rollup {network_id} at height {}",
+            certificate.hash(),
+            certificate.height
+        );
+
+        self.check_certificate_signer(&certificate).await?;
+
+        // The certificate is applied on top of the latest persisted state of the
+        // network, or on an empty state for its first certificate.
+        let mut state = self
+            .state
+            .read_local_network_state(network_id)
+            .map_err(|e| {
+                error!("Failed to read the local network state: {e}");
+                Error::internal("Unable to read the local network state")
+            })?
+            .unwrap_or_default();
+
+        let (_, _, output) = execute_pessimistic_proof(
+            Arc::new(self.kernel.get_l1_rpc_client()),
+            &self.config,
+            &mut state,
+            &certificate,
+        )
+        .await
+        .map_err(|error| match error {
+            CertificationError::L1InfoRootNotFound(_, leaf_count) => {
+                CertificateValidationError::L1InfoRootNotFound { leaf_count }.into()
+            }
+            CertificationError::Types { source } => {
+                CertificateValidationError::InvalidCertificate(source).into()
+            }
+            CertificationError::NativeExecutionFailed { source } => {
+                CertificateValidationError::NativeExecution(source).into()
+            }
+            error => {
+                error!("Failed to execute the certificate: {error}");
+                Error::internal("Unable to execute the certificate")
+            }
+        })?;
+
+        Ok(output)
+    }
+
     async fn get_certificate_header(
         &self,
         certificate_id: CertificateId,
@@ -477,6 +1234,253 @@ where
         }
     }
 
+    async fn get_certificate_header_by_height(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> RpcResult<CertificateHeader> {
+        trace!(
+            "Received request to get certificate header for rollup {network_id} at height {height}"
+        );
+
+        match self.certificate_header_at_height(network_id, height) {
+            Ok(Some(header)) => Ok(header),
+            Ok(None) => Err(Error::resource_not_found(format!(
+                "Certificate(network_id: {}, height: {})",
+                network_id, height
+            ))),
+            Err(error) => {
+                error!("Failed to get certificate header: {}", error);
+
+                Err(Error::internal("Unable to get certificate header"))
+            }
+        }
+    }
+
+    async fn get_certificate_headers(
+        &self,
+        network_id: NetworkId,
+        from_height: Height,
+        limit: usize,
+        status_filter: Option<CertificateStatusKind>,
+    ) -> RpcResult<CertificateHeadersPage> {
+        debug!(
+            "Received request to get {limit} certificate headers for rollup {network_id} from \
+             height {from_height}"
+        );
+
+        let limit = limit.min(MAX_CERTIFICATE_HEADERS_PER_PAGE);
+
+        let settled = self
+            .state
+            .get_certificate_headers_by_cursor(
+                network_id,
+                from_height,
+                MAX_CERTIFICATE_HEIGHTS_SCANNED_PER_PAGE,
+            )
+            .map_err(|e| {
+                error!("Failed to get settled certificate headers: {e}");
+                Error::internal(e.to_string())
+            })?;
+
+        let latest_known_height = self
+            .latest_known_height(network_id)
+            .map_err(|e| {
+                error!("Failed to get the latest known height: {e}");
+                Error::internal(e.to_string())
+            })?
+            .into_iter()
+            .chain(settled.last().map(|header| header.height))
+            .max();
+
+        let Some(latest_known_height) = latest_known_height else {
+            return Ok(CertificateHeadersPage {
+                headers: Vec::new(),
+                next_height: None,
+            });
+        };
+
+        let mut settled = settled.into_iter().peekable();
+        let mut headers = Vec::new();
+        let mut height = from_height;
+        let mut scanned = 0;
+
+        // Heights without a known certificate, such as the one of a certificate
+        // removed from the pending queue, are skipped.
+        while headers.len() < limit
+            && height <= latest_known_height
+            && scanned < MAX_CERTIFICATE_HEIGHTS_SCANNED_PER_PAGE
+        {
+            let header = match settled.next_if(|header| header.height == height) {
+                Some(header) => Some(header),
+                None => self
+                    .non_settled_certificate_header_at_height(network_id, height)
+                    .map_err(|e| {
+                        error!("Failed to get certificate header: {e}");
+                        Error::internal(e.to_string())
+                    })?,
+            };
+
+            if let Some(header) = header {
+                let kind = CertificateStatusKind::from(&header.status);
+                if status_filter.is_none_or(|filter| filter == kind) {
+                    headers.push(header);
+                }
+            }
+
+            height += 1;
+            scanned += 1;
+        }
+
+        let next_height = (height <= latest_known_height).then_some(height);
+
+        Ok(CertificateHeadersPage {
+            headers,
+            next_height,
+        })
+    }
+
+    async fn get_local_network_state_roots(
+        &self,
+        network_id: NetworkId,
+    ) -> RpcResult<StateCommitment> {
+        trace!("Received request to get the local network state roots for rollup {network_id}");
+
+        Ok(self.local_network_state(network_id)?.get_roots())
+    }
+
+    async fn get_token_balance(
+        &self,
+        network_id: NetworkId,
+        token_info: TokenInfo,
+    ) -> RpcResult<TokenBalance> {
+        trace!("Received request to get the balance of {token_info:?} for rollup {network_id}");
+
+        let mut state = self.local_network_state(network_id)?;
+        let (balance, proof) = state.get_balance_proof(token_info).map_err(|e| {
+            error!("Failed to generate the balance proof: {e}");
+            Error::internal(e.to_string())
+        })?;
+
+        Ok(TokenBalance {
+            token_info,
+            balance,
+            balance_root: state.balance_tree.root.into(),
+            proof,
+        })
+    }
+
+    async fn get_nullifier_status(
+        &self,
+        network_id: NetworkId,
+        global_index: GlobalIndex,
+    ) -> RpcResult<NullifierStatus> {
+        trace!(
+            "Received request to get the nullifier status of {global_index:?} for rollup \
+             {network_id}"
+        );
+
+        let state = self.local_network_state(network_id)?;
+        let proof = state.get_nullifier_proof(global_index).map_err(|e| {
+            error!("Failed to generate the nullifier proof: {e}");
+            Error::internal(e.to_string())
+        })?;
+
+        Ok(NullifierStatus {
+            global_index,
+            claimed: proof.is_claimed(),
+            nullifier_root: state.nullifier_tree.root.into(),
+            proof,
+        })
+    }
+
+    async fn get_local_exit_tree_proof(
+        &self,
+        network_id: NetworkId,
+        leaf_index: u32,
+        leaf_count: Option<u32>,
+    ) -> RpcResult<LocalExitTreeProof> {
+        trace!(
+            "Received request to get the local exit tree proof of the leaf {leaf_index} for \
+             rollup {network_id} at leaf count {leaf_count:?}"
+        );
+
+        match self
+            .state
+            .get_local_exit_tree_proof(network_id, leaf_index, leaf_count)
+        {
+            Ok(Some(proof)) => Ok(proof),
+            Ok(None) => Err(Error::resource_not_found(format!(
+                "LocalExitTreeLeaf(network_id: {}, leaf_index: {}, leaf_count: {:?})",
+                network_id, leaf_index, leaf_count
+            ))),
+            Err(error) => {
+                error!("Failed to get the local exit tree proof: {}", error);
+
+                Err(Error::internal("Unable to get the local exit tree proof"))
+            }
+        }
+    }
+
+    async fn get_certificate_proof(
+        &self,
+        certificate_id: CertificateId,
+        format: Option<CertificateProofFormat>,
+    ) -> RpcResult<CertificateProofResponse> {
+        debug!("Received request to get the proof of certificate {certificate_id}");
+
+        let (certificate, proof) = self.certificate_with_proof(certificate_id)?;
+
+        let public_values = proof.pessimistic_proof_output().map_err(|error| {
+            error!("Failed to decode the public values of certificate {certificate_id}: {error}");
+            Error::internal("Unable to decode the proof public values")
+        })?;
+
+        // Computing the verification key is expensive the first time.
+        let vkey = tokio::task::spawn_blocking(pessimistic_proof_vkey)
+            .await
+            .map_err(|error| {
+                error!("Failed to compute the pessimistic proof vkey: {error}");
+                Error::internal("Unable to compute the pessimistic proof vkey")
+            })?
+            .to_string();
+
+        match format.unwrap_or_default() {
+            CertificateProofFormat::Proof => {
+                Ok(CertificateProofResponse::Proof(CertificateProof {
+                    certificate_id,
+                    proof: proof.onchain_bytes().map(Bytes::from),
+                    public_values,
+                    sp1_version: proof.sp1_version().to_string(),
+                    vkey,
+                }))
+            }
+            CertificateProofFormat::Fixture => {
+                let onchain_proof = proof.onchain_bytes().ok_or_else(|| {
+                    Error::resource_not_found(format!("OnchainProof({})", certificate_id))
+                })?;
+                let signer = certificate
+                    .signer()
+                    .ok_or_else(|| Error::internal("Unable to recover the certificate signer"))?;
+
+                Ok(CertificateProofResponse::Fixture(PessimisticProofFixture {
+                    certificate,
+                    pp_inputs: public_values.into(),
+                    signer,
+                    vkey,
+                    public_values: format!("0x{}", hex::encode(proof.public_values())),
+                    proof: format!("0x{}", hex::encode(onchain_proof)),
+                }))
+            }
+        }
+    }
+
+    async fn get_node_info(&self) -> RpcResult<NodeInfo> {
+        debug!("Received request to get the node information");
+
+        Ok(self.node_info.clone())
+    }
+
     async fn debug_get_certificate(
         &self,
         certificate_id: CertificateId,
@@ -504,6 +1508,86 @@ where
             }
         }
     }
+
+    async fn subscribe_certificate_status(
+        &self,
+        pending: PendingSubscriptionSink,
+        filter: CertificateStatusFilter,
+    ) -> SubscriptionResult {
+        debug!("Received subscription to the certificate status with filter {filter:?}");
+
+        let receiver = self.certificate_status_sender.subscribe();
+        let sink = pending.accept().await?;
+
+        forward_broadcast(sink, receiver, |header| {
+            filter.matches(&header).then_some(header)
+        })
+        .await
+    }
+
+    async fn subscribe_epochs(&self, pending: PendingSubscriptionSink) -> SubscriptionResult {
+        debug!("Received subscription to the epoch events");
+
+        let receiver = match self.clock_ref.subscribe() {
+            Ok(receiver) => receiver,
+            Err(error) => {
+                error!("Failed to subscribe to the clock events: {error}");
+                pending
+                    .reject(Error::internal("Unable to subscribe to the epoch events"))
+                    .await;
+
+                return Ok(());
+            }
+        };
+        let sink = pending.accept().await?;
+
+        forward_broadcast(sink, receiver, |event| match event {
+            agglayer_clock::Event::EpochEnded(epoch) => Some(EpochEvent::EpochEnded(epoch)),
+            agglayer_clock::Event::L1Reorg(from_block) => Some(EpochEvent::L1Reorg(from_block)),
+        })
+        .await
+    }
+}
+
+/// Forward the messages of a broadcast channel to a subscription sink until
+/// either the subscriber or the channel goes away.
+///
+/// Messages for which `map` returns `None` are not sent to the subscriber.
+async fn forward_broadcast<T, I, F>(
+    sink: SubscriptionSink,
+    mut receiver: broadcast::Receiver<T>,
+    map: F,
+) -> SubscriptionResult
+where
+    T: Clone,
+    I: Serialize,
+    F: Fn(T) -> Option<I>,
+{
+    loop {
+        tokio::select! {
+            _ = sink.closed() => break,
+            message = receiver.recv() => match message {
+                Ok(message) => {
+                    let Some(item) = map(message) else {
+                        continue;
+                    };
+
+                    if sink.send(SubscriptionMessage::from_json(&item)?).await.is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!(
+                        "Subscription {:?} lagged behind, {skipped} messages skipped",
+                        sink.subscription_id()
+                    );
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+
+    Ok(())
 }
 
 fn decode_contract_error<M: Middleware, E: ContractRevert + std::fmt::Debug>(
@@ -513,4 +1597,4 @@ fn decode_contract_error<M: Middleware, E: ContractRevert + std::fmt::Debug>(
         .map(|err| format!("{:?}", err))
 }
 
-type TxStatus = String;
+pub type TxStatus = String;
@@ -0,0 +1,501 @@
+//! OpenRPC description of the `interop` namespace.
+//!
+//! The document is served through `rpc.discover` and can be dumped with the
+//! `agglayer openrpc` command. The schemas describe the serde output of the
+//! request and response types: any change to these types must be reflected
+//! here, the tests of this module check that both stay in sync.
+
+use serde_json::{json, Value};
+
+/// Version of the OpenRPC specification the document conforms to.
+const OPENRPC_VERSION: &str = "1.2.6";
+
+/// Build the OpenRPC document describing the `interop` namespace.
+pub fn document() -> Value {
+    json!({
+        "openrpc": OPENRPC_VERSION,
+        "info": {
+            "title": "Agglayer interop API",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "methods": methods(),
+        "components": {
+            "schemas": schemas(),
+        },
+    })
+}
+
+/// Reference to a schema defined in the components of the document.
+fn schema_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{name}") })
+}
+
+/// Schema of a value which may be `null`.
+fn nullable(schema: Value) -> Value {
+    json!({ "oneOf": [schema, { "type": "null" }] })
+}
+
+fn param(name: &str, schema: Value) -> Value {
+    json!({ "name": name, "required": true, "schema": schema })
+}
+
+fn optional_param(name: &str, schema: Value) -> Value {
+    json!({ "name": name, "required": false, "schema": schema })
+}
+
+fn method(name: &str, summary: &str, params: Vec<Value>, result: Value) -> Value {
+    json!({
+        "name": format!("interop_{name}"),
+        "summary": summary,
+        "paramStructure": "by-position",
+        "params": params,
+        "result": { "name": "result", "schema": result },
+    })
+}
+
+fn methods() -> Vec<Value> {
+    vec![
+        method(
+            "sendTx",
+            "Submit a signed transaction to be settled on L1.",
+            vec![param("tx", schema_ref("SignedTx"))],
+            schema_ref("H256"),
+        ),
+        method(
+            "getTxStatus",
+            "Get the status of a transaction submitted through sendTx.",
+            vec![param("hash", schema_ref("H256"))],
+            json!({ "type": "string" }),
+        ),
+        method(
+            "sendCertificate",
+            "Submit a certificate, returns its identifier.",
+            vec![param("certificate", schema_ref("Certificate"))],
+            schema_ref("CertificateId"),
+        ),
+        method(
+            "validateCertificate",
+            "Execute the pessimistic proof of a certificate without storing it.",
+            vec![param("certificate", schema_ref("Certificate"))],
+            schema_ref("PessimisticProofOutput"),
+        ),
+        method(
+            "getCertificateHeader",
+            "Get the header of a certificate.",
+            vec![param("certificate_id", schema_ref("CertificateId"))],
+            schema_ref("CertificateHeader"),
+        ),
+        method(
+            "getEpochConfiguration",
+            "Get the epoch configuration of the agglayer.",
+            vec![],
+            schema_ref("EpochConfiguration"),
+        ),
+        method(
+            "getLatestKnownCertificateHeader",
+            "Get the header of the latest certificate known for a network.",
+            vec![param("network_id", schema_ref("NetworkId"))],
+            nullable(schema_ref("CertificateHeader")),
+        ),
+        method(
+            "getCertificateHeaderByHeight",
+            "Get the header of the certificate of a network at a given height.",
+            vec![
+                param("network_id", schema_ref("NetworkId")),
+                param("height", schema_ref("Height")),
+            ],
+            schema_ref("CertificateHeader"),
+        ),
+        method(
+            "getCertificateHeaders",
+            "List the certificate headers of a network from a given height.",
+            vec![
+                param("network_id", schema_ref("NetworkId")),
+                param("from_height", schema_ref("Height")),
+                param("limit", json!({ "type": "integer", "minimum": 0 })),
+                optional_param("status_filter", schema_ref("CertificateStatusKind")),
+            ],
+            schema_ref("CertificateHeadersPage"),
+        ),
+        method(
+            "getLocalNetworkStateRoots",
+            "Get the roots of the local network state of a network.",
+            vec![param("network_id", schema_ref("NetworkId"))],
+            schema_ref("StateCommitment"),
+        ),
+        method(
+            "getTokenBalance",
+            "Get the balance of a token of a network along with its inclusion proof.",
+            vec![
+                param("network_id", schema_ref("NetworkId")),
+                param("token_info", schema_ref("TokenInfo")),
+            ],
+            schema_ref("TokenBalance"),
+        ),
+        method(
+            "getNullifierStatus",
+            "Get the claim status of a global index along with its proof.",
+            vec![
+                param("network_id", schema_ref("NetworkId")),
+                param("global_index", schema_ref("GlobalIndex")),
+            ],
+            schema_ref("NullifierStatus"),
+        ),
+        method(
+            "getLocalExitTreeProof",
+            "Get the inclusion proof of a leaf in the local exit tree of a network.",
+            vec![
+                param("network_id", schema_ref("NetworkId")),
+                param("leaf_index", schema_ref("U32")),
+                optional_param("leaf_count", schema_ref("U32")),
+            ],
+            schema_ref("LocalExitTreeProof"),
+        ),
+        method(
+            "getCertificateProof",
+            "Get the proof generated for a certificate.",
+            vec![
+                param("certificate_id", schema_ref("CertificateId")),
+                optional_param("format", schema_ref("CertificateProofFormat")),
+            ],
+            schema_ref("CertificateProofResponse"),
+        ),
+        method(
+            "getNodeInfo",
+            "Get the version, the keys and the contract wiring of the node.",
+            vec![],
+            schema_ref("NodeInfo"),
+        ),
+        method(
+            "debugGetCertificate",
+            "Get a certificate and its header, if any.",
+            vec![param("certificate_id", schema_ref("CertificateId"))],
+            json!({
+                "type": "array",
+                "items": [
+                    schema_ref("Certificate"),
+                    nullable(schema_ref("CertificateHeader")),
+                ],
+                "minItems": 2,
+                "maxItems": 2,
+            }),
+        ),
+        method(
+            "subscribeCertificateStatus",
+            "Subscribe to the status transitions of a certificate or of every certificate of a \
+             network. Notifications are sent through interop_certificateStatus with a \
+             CertificateHeader.",
+            vec![param("filter", schema_ref("CertificateStatusFilter"))],
+            schema_ref("SubscriptionId"),
+        ),
+        method(
+            "unsubscribeCertificateStatus",
+            "Cancel a certificate status subscription.",
+            vec![param("subscription", schema_ref("SubscriptionId"))],
+            json!({ "type": "boolean" }),
+        ),
+        method(
+            "subscribeEpochs",
+            "Subscribe to the end of every epoch and to the reorgs of the L1. Notifications are \
+             sent through interop_epochs with an EpochEvent.",
+            vec![],
+            schema_ref("SubscriptionId"),
+        ),
+        method(
+            "unsubscribeEpochs",
+            "Cancel an epoch subscription.",
+            vec![param("subscription", schema_ref("SubscriptionId"))],
+            json!({ "type": "boolean" }),
+        ),
+    ]
+}
+
+/// Schema of a struct whose fields are all required.
+fn object(properties: Value) -> Value {
+    let required: Vec<&String> = properties
+        .as_object()
+        .expect("properties must be an object")
+        .keys()
+        .collect();
+
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+/// Schema of an externally tagged enum variant holding some data.
+fn variant(name: &str, schema: Value) -> Value {
+    object(json!({ name: schema }))
+}
+
+/// Schema of an enum whose variants are described by their serde
+/// representation only, without their payload.
+fn tagged_enum(description: &str) -> Value {
+    json!({
+        "description": description,
+        "oneOf": [
+            { "type": "string" },
+            { "type": "object", "minProperties": 1, "maxProperties": 1 },
+        ],
+    })
+}
+
+fn schemas() -> Value {
+    json!({
+        "U32": { "type": "integer", "minimum": 0, "maximum": u32::MAX },
+        "U64": { "type": "integer", "minimum": 0 },
+        "U256": {
+            "description": "256-bit unsigned integer, as a 0x-prefixed hexadecimal string.",
+            "type": "string",
+            "pattern": "^(0x[0-9a-fA-F]+|[0-9]+)$",
+        },
+        "Hash": {
+            "type": "string",
+            "pattern": "^0x[0-9a-f]{64}$",
+        },
+        "H256": schema_ref("Hash"),
+        "Digest": {
+            "description": "32-byte digest, as an array of bytes.",
+            "type": "array",
+            "items": { "type": "integer", "minimum": 0, "maximum": 255 },
+            "minItems": 32,
+            "maxItems": 32,
+        },
+        "Bytes": {
+            "type": "array",
+            "items": { "type": "integer", "minimum": 0, "maximum": 255 },
+        },
+        "HexBytes": {
+            "type": "string",
+            "pattern": "^0x([0-9a-f]{2})*$",
+        },
+        "Address": {
+            "type": "string",
+            "pattern": "^0x[0-9a-fA-F]{40}$",
+        },
+        "NetworkId": schema_ref("U32"),
+        "Height": schema_ref("U64"),
+        "EpochNumber": schema_ref("U64"),
+        "CertificateIndex": schema_ref("U64"),
+        "CertificateId": schema_ref("Hash"),
+        "Metadata": schema_ref("Hash"),
+        "SubscriptionId": {
+            "oneOf": [{ "type": "string" }, { "type": "integer", "minimum": 0 }],
+        },
+
+        "Signature": object(json!({
+            "r": schema_ref("U256"),
+            "s": schema_ref("U256"),
+            "odd_y_parity": { "type": "boolean" },
+        })),
+        "LeafType": { "enum": ["Transfer", "Message"] },
+        "TokenInfo": object(json!({
+            "origin_network": schema_ref("NetworkId"),
+            "origin_token_address": schema_ref("Address"),
+        })),
+        "BridgeExit": object(json!({
+            "leaf_type": schema_ref("LeafType"),
+            "token_info": schema_ref("TokenInfo"),
+            "dest_network": schema_ref("NetworkId"),
+            "dest_address": schema_ref("Address"),
+            "amount": schema_ref("U256"),
+            "metadata": schema_ref("Bytes"),
+        })),
+        "GlobalIndex": object(json!({
+            "mainnet_flag": { "type": "boolean" },
+            "rollup_index": schema_ref("U32"),
+            "leaf_index": schema_ref("U32"),
+        })),
+        "MerkleProof": object(json!({
+            "proof": object(json!({
+                "siblings": {
+                    "type": "array",
+                    "items": schema_ref("Digest"),
+                    "minItems": 32,
+                    "maxItems": 32,
+                },
+            })),
+            "root": schema_ref("Digest"),
+        })),
+        "L1InfoTreeLeaf": object(json!({
+            "l1_info_tree_index": schema_ref("U32"),
+            "rer": schema_ref("Digest"),
+            "mer": schema_ref("Digest"),
+            "inner": object(json!({
+                "global_exit_root": schema_ref("Digest"),
+                "block_hash": schema_ref("Digest"),
+                "timestamp": schema_ref("U64"),
+            })),
+        })),
+        "Claim": {
+            "oneOf": [
+                variant("Mainnet", object(json!({
+                    "proof_leaf_mer": schema_ref("MerkleProof"),
+                    "proof_ger_l1root": schema_ref("MerkleProof"),
+                    "l1_leaf": schema_ref("L1InfoTreeLeaf"),
+                }))),
+                variant("Rollup", object(json!({
+                    "proof_leaf_ler": schema_ref("MerkleProof"),
+                    "proof_ler_rer": schema_ref("MerkleProof"),
+                    "proof_ger_l1root": schema_ref("MerkleProof"),
+                    "l1_leaf": schema_ref("L1InfoTreeLeaf"),
+                }))),
+            ],
+        },
+        "ImportedBridgeExit": object(json!({
+            "bridge_exit": schema_ref("BridgeExit"),
+            "claim_data": schema_ref("Claim"),
+            "global_index": schema_ref("GlobalIndex"),
+        })),
+        "Certificate": object(json!({
+            "network_id": schema_ref("NetworkId"),
+            "height": schema_ref("Height"),
+            "prev_local_exit_root": schema_ref("Digest"),
+            "new_local_exit_root": schema_ref("Digest"),
+            "bridge_exits": { "type": "array", "items": schema_ref("BridgeExit") },
+            "imported_bridge_exits": {
+                "type": "array",
+                "items": schema_ref("ImportedBridgeExit"),
+            },
+            "signature": schema_ref("Signature"),
+            "metadata": schema_ref("Metadata"),
+        })),
+
+        "GenerationType": { "enum": ["Native", "Prover"] },
+        "ProofError": tagged_enum("Error raised by the pessimistic proof program."),
+        "ProofVerificationError": {
+            "oneOf": [
+                variant("VersionMismatch", json!({ "type": "string" })),
+                variant("Core", json!({ "type": "string" })),
+                variant("Recursion", json!({ "type": "string" })),
+                variant("Plonk", json!({ "type": "string" })),
+                variant("Groth16", json!({ "type": "string" })),
+                { "const": "InvalidPublicValues" },
+            ],
+        },
+        "TypeConversionError": tagged_enum(
+            "Error raised while building the pessimistic proof witness from the certificate.",
+        ),
+        "CertificateStatusError": {
+            "oneOf": [
+                variant("ProofGenerationError", object(json!({
+                    "generation_type": schema_ref("GenerationType"),
+                    "source": schema_ref("ProofError"),
+                }))),
+                variant("ProofVerificationFailed", schema_ref("ProofVerificationError")),
+                variant("TypeConversionError", schema_ref("TypeConversionError")),
+                variant("TrustedSequencerNotFound", schema_ref("NetworkId")),
+                variant("InternalError", json!({ "type": "string" })),
+                variant("SettlementError", json!({ "type": "string" })),
+                variant("L1InfoRootNotFound", schema_ref("U32")),
+                variant("Superseded", schema_ref("CertificateId")),
+                variant("Expired", schema_ref("U64")),
+            ],
+        },
+        "CertificateStatus": {
+            "oneOf": [
+                { "enum": ["Pending", "Proven", "Candidate", "Settled"] },
+                variant("InError", object(json!({
+                    "error": schema_ref("CertificateStatusError"),
+                }))),
+            ],
+        },
+        "CertificateStatusKind": {
+            "enum": ["Pending", "Proven", "Candidate", "InError", "Settled"],
+        },
+        "CertificateHeader": object(json!({
+            "network_id": schema_ref("NetworkId"),
+            "height": schema_ref("Height"),
+            "epoch_number": nullable(schema_ref("EpochNumber")),
+            "certificate_index": nullable(schema_ref("CertificateIndex")),
+            "certificate_id": schema_ref("CertificateId"),
+            "prev_local_exit_root": schema_ref("Hash"),
+            "new_local_exit_root": schema_ref("Hash"),
+            "metadata": schema_ref("Metadata"),
+            "status": schema_ref("CertificateStatus"),
+            "settlement_tx_hash": nullable(schema_ref("Hash")),
+        })),
+        "CertificateHeadersPage": object(json!({
+            "headers": { "type": "array", "items": schema_ref("CertificateHeader") },
+            "next_height": nullable(schema_ref("Height")),
+        })),
+        "CertificateStatusFilter": {
+            "oneOf": [schema_ref("CertificateId"), schema_ref("NetworkId")],
+        },
+        "EpochConfiguration": object(json!({
+            "genesis_block": schema_ref("U64"),
+            "epoch_duration": schema_ref("U64"),
+        })),
+        "EpochEvent": {
+            "oneOf": [
+                variant("epochEnded", schema_ref("EpochNumber")),
+                variant("l1Reorg", schema_ref("U64")),
+            ],
+        },
+
+        "StateCommitment": object(json!({
+            "exit_root": schema_ref("Digest"),
+            "balance_root": schema_ref("Digest"),
+            "nullifier_root": schema_ref("Digest"),
+        })),
+        "PessimisticProofOutput": object(json!({
+            "prev_local_exit_root": schema_ref("Digest"),
+            "prev_pessimistic_root": schema_ref("Digest"),
+            "l1_info_root": schema_ref("Digest"),
+            "origin_network": schema_ref("NetworkId"),
+            "consensus_hash": schema_ref("Digest"),
+            "new_local_exit_root": schema_ref("Digest"),
+            "new_pessimistic_root": schema_ref("Digest"),
+        })),
+        "CertificateProofFormat": { "enum": ["proof", "fixture"] },
+        "CertificateProof": object(json!({
+            "certificate_id": schema_ref("CertificateId"),
+            "proof": nullable(schema_ref("HexBytes")),
+            "public_values": schema_ref("PessimisticProofOutput"),
+            "sp1_version": { "type": "string" },
+            "vkey": { "type": "string" },
+        })),
+        "CertificateProofResponse": {
+            "oneOf": [
+                schema_ref("CertificateProof"),
+                {
+                    "description": "Fixture of the Solidity verifier, as written by ppgen.",
+                    "type": "object",
+                },
+            ],
+        },
+        "EpochClockKind": { "enum": ["blockClock", "timeClock"] },
+        "L1Info": object(json!({
+            "chain_id": schema_ref("U64"),
+            "rollup_manager_contract": schema_ref("Address"),
+            "global_exit_root_contract": schema_ref("Address"),
+        })),
+        "NodeInfo": object(json!({
+            "version": { "type": "string" },
+            "vkey": nullable({ "type": "string" }),
+            "settlement_signer": nullable(schema_ref("Address")),
+            "l1": schema_ref("L1Info"),
+            "epoch_clock": schema_ref("EpochClockKind"),
+            "debug_mode": { "type": "boolean" },
+        })),
+        "SignedTx": {
+            "description": "Proof manifest along with the signature of the trusted sequencer.",
+            "type": "object",
+        },
+        "TokenBalance": {
+            "description": "Balance of a token along with its inclusion proof.",
+            "type": "object",
+        },
+        "NullifierStatus": {
+            "description": "Claim status of a global index along with its proof.",
+            "type": "object",
+        },
+        "LocalExitTreeProof": {
+            "description": "Inclusion proof of a leaf in the local exit tree.",
+            "type": "object",
+        },
+    })
+}
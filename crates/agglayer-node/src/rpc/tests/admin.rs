@@ -0,0 +1,269 @@
+use std::sync::Arc;
+
+use agglayer_certificate_orchestrator::{
+    AdminCommand, NetworkTaskState, NetworkTaskStatus, OrchestratorState,
+};
+use agglayer_config::Config;
+use agglayer_storage::stores::{
+    pending::PendingStore, state::StateStore, PendingCertificateReader as _,
+    PendingCertificateWriter as _, StateReader as _, StateWriter as _,
+};
+use agglayer_types::{
+    Certificate, CertificateId, CertificateStatus, CertificateStatusError, Height, NetworkId,
+};
+use http_body_util::Full;
+use hyper_util::{client::legacy::Client, rt::TokioExecutor};
+use jsonrpsee::{rpc_params, MethodsError, RpcModule};
+use rstest::*;
+use tokio::sync::mpsc;
+
+use super::{next_available_addr, raw_rpc, DummyStore};
+use crate::rpc::{
+    admin::{AdminImpl, AdminServer as _},
+    error::code,
+    tests::RawRpcContext,
+};
+
+type AdminChannels = (
+    mpsc::Receiver<(NetworkId, Height, CertificateId)>,
+    mpsc::Receiver<AdminCommand>,
+);
+
+/// Build the admin RPC module on top of the stores of the raw RPC context.
+fn admin_rpc(
+    raw_rpc: &RawRpcContext,
+) -> (
+    RpcModule<AdminImpl<PendingStore, StateStore>>,
+    AdminChannels,
+) {
+    let (certificate_sender, certificate_receiver) = mpsc::channel(1);
+    let (admin_sender, admin_receiver) = mpsc::channel(1);
+
+    let admin = AdminImpl::new(
+        certificate_sender,
+        admin_sender,
+        raw_rpc.rpc.pending_store.clone(),
+        raw_rpc.rpc.state.clone(),
+        raw_rpc.config.clone(),
+    );
+
+    (admin.into_rpc(), (certificate_receiver, admin_receiver))
+}
+
+fn in_error() -> CertificateStatus {
+    CertificateStatus::InError {
+        error: CertificateStatusError::InternalError("failure".into()),
+    }
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn requeue_certificate_in_error(#[future] raw_rpc: RawRpcContext) {
+    let (rpc, (mut certificate_receiver, _)) = admin_rpc(&raw_rpc);
+    let certificate = Certificate::new_for_test(1.into(), 0);
+    let certificate_id = certificate.hash();
+
+    raw_rpc
+        .rpc
+        .state
+        .insert_certificate_header(&certificate, in_error())
+        .unwrap();
+    raw_rpc
+        .rpc
+        .pending_store
+        .insert_pending_certificate(1.into(), 0, &certificate)
+        .unwrap();
+
+    let _: () = rpc
+        .call("admin_requeueCertificate", rpc_params![certificate_id])
+        .await
+        .unwrap();
+
+    let header = raw_rpc
+        .rpc
+        .state
+        .get_certificate_header(&certificate_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(header.status, CertificateStatus::Pending);
+    assert_eq!(
+        certificate_receiver.try_recv().unwrap(),
+        (1.into(), 0, certificate_id)
+    );
+
+    // The certificate is no longer in error.
+    let error = rpc
+        .call::<_, ()>("admin_requeueCertificate", rpc_params![certificate_id])
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, MethodsError::JsonRpc(obj) if obj.code() == code::ADMIN));
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn remove_pending_certificate(#[future] raw_rpc: RawRpcContext) {
+    let (rpc, _channels) = admin_rpc(&raw_rpc);
+    let pending = Certificate::new_for_test(1.into(), 0);
+    let proven = Certificate::new_for_test(2.into(), 0);
+
+    for (certificate, status) in [(&pending, in_error()), (&proven, CertificateStatus::Proven)] {
+        raw_rpc
+            .rpc
+            .state
+            .insert_certificate_header(certificate, status)
+            .unwrap();
+        raw_rpc
+            .rpc
+            .pending_store
+            .insert_pending_certificate(certificate.network_id, 0, certificate)
+            .unwrap();
+    }
+
+    let _: () = rpc
+        .call("admin_removePendingCertificate", rpc_params![1, 0])
+        .await
+        .unwrap();
+
+    assert!(raw_rpc
+        .rpc
+        .pending_store
+        .get_certificate(1.into(), 0)
+        .unwrap()
+        .is_none());
+
+    // A proven certificate is being settled and can't be removed.
+    let error = rpc
+        .call::<_, ()>("admin_removePendingCertificate", rpc_params![2, 0])
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, MethodsError::JsonRpc(obj) if obj.code() == code::ADMIN));
+    assert!(raw_rpc
+        .rpc
+        .pending_store
+        .get_certificate(2.into(), 0)
+        .unwrap()
+        .is_some());
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn orchestrator_commands_are_forwarded(#[future] raw_rpc: RawRpcContext) {
+    let (rpc, (_, mut admin_receiver)) = admin_rpc(&raw_rpc);
+
+    tokio::spawn(async move {
+        while let Some(command) = admin_receiver.recv().await {
+            match command {
+                AdminCommand::PauseNetwork { response, .. } => _ = response.send(Ok(())),
+                AdminCommand::DumpState { response } => {
+                    _ = response.send(OrchestratorState {
+                        paused_networks: vec![1.into()],
+                        network_tasks: vec![NetworkTaskStatus {
+                            network_id: 2.into(),
+                            state: NetworkTaskState::BackingOff,
+                            restarts: 3,
+                            last_error: Some("boom".into()),
+                        }],
+                        ..Default::default()
+                    })
+                }
+                AdminCommand::RespawnNetworkTask { response, .. } => {
+                    _ = response.send(Err("boom".into()))
+                }
+                AdminCommand::ResumeNetwork { .. } => unreachable!(),
+            }
+        }
+    });
+
+    let _: () = rpc
+        .call("admin_pauseNetwork", rpc_params![1])
+        .await
+        .unwrap();
+
+    let state: OrchestratorState = rpc
+        .call("admin_getOrchestratorState", rpc_params![])
+        .await
+        .unwrap();
+    assert_eq!(state.paused_networks, vec![1.into()]);
+    assert_eq!(state.network_tasks[0].state, NetworkTaskState::BackingOff);
+    assert_eq!(state.network_tasks[0].restarts, 3);
+
+    let error = rpc
+        .call::<_, ()>("admin_respawnNetworkTask", rpc_params![1])
+        .await
+        .unwrap_err();
+    assert!(matches!(error, MethodsError::JsonRpc(obj) if obj.code() == code::ADMIN));
+}
+
+#[test_log::test(tokio::test)]
+async fn admin_server_requires_bearer_token() {
+    let mut config = Config::new_for_test();
+    config.rpc.admin.port = next_available_addr().port();
+    config.rpc.admin.bearer_token = Some("secret".into());
+    let config = Arc::new(config);
+
+    let (certificate_sender, _certificate_receiver) = mpsc::channel(1);
+    let (admin_sender, _admin_receiver) = mpsc::channel(1);
+
+    let _server_handle = AdminImpl::new(
+        certificate_sender,
+        admin_sender,
+        Arc::new(DummyStore {}),
+        Arc::new(DummyStore {}),
+        config.clone(),
+    )
+    .start()
+    .await
+    .unwrap();
+
+    let http_client = Client::builder(TokioExecutor::new()).build_http();
+    let uri = format!("http://{}/", config.admin_rpc_addr());
+    let body = r#"{"jsonrpc":"2.0","id":1,"method":"rpc.unknown","params":[]}"#;
+
+    for (authorization, expected_status) in [
+        (None, hyper::StatusCode::UNAUTHORIZED),
+        (Some("Bearer wrong"), hyper::StatusCode::UNAUTHORIZED),
+        (Some("Bearer secret"), hyper::StatusCode::OK),
+    ] {
+        let mut req = hyper::Request::builder()
+            .method("POST")
+            .uri(&uri)
+            .header(hyper::header::CONTENT_TYPE, "application/json");
+        if let Some(authorization) = authorization {
+            req = req.header(hyper::header::AUTHORIZATION, authorization);
+        }
+
+        let res = http_client
+            .request(req.body(Full::new(hyper::body::Bytes::from(body))).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), expected_status);
+    }
+}
+
+#[test_log::test(tokio::test)]
+async fn admin_server_refuses_public_bind_without_token() {
+    let mut config = Config::new_for_test();
+    config.rpc.admin.host = std::net::Ipv4Addr::UNSPECIFIED;
+    let config = Arc::new(config);
+
+    let (certificate_sender, _certificate_receiver) = mpsc::channel(1);
+    let (admin_sender, _admin_receiver) = mpsc::channel(1);
+
+    let result = AdminImpl::new(
+        certificate_sender,
+        admin_sender,
+        Arc::new(DummyStore {}),
+        Arc::new(DummyStore {}),
+        config,
+    )
+    .start()
+    .await;
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,96 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use agglayer_types::Hash;
+use jsonrpsee::core::ClientError;
+use rstest::*;
+
+use super::{context, next_available_addr, TestContext};
+use crate::client::{code, AgglayerClient as _, Client, Error, ErrorKind, RetryPolicy};
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn typed_call(#[future] context: TestContext) {
+    let client = Client::new(context.client.clone());
+
+    let configuration = client.get_epoch_configuration().await.unwrap();
+
+    assert_eq!(configuration.epoch_duration, 6);
+    assert_eq!(configuration.genesis_block, 0);
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn decode_error_code(#[future] context: TestContext) {
+    let client = Client::new(context.client.clone());
+
+    let error = client
+        .get_certificate_header(Hash([0; 32]))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.kind(), Some(ErrorKind::ResourceNotFound));
+    assert!(!error.is_transient());
+    assert!(matches!(
+        error,
+        Error::Rpc {
+            code: code::RESOURCE_NOT_FOUND,
+            data: Some(_),
+            ..
+        }
+    ));
+}
+
+#[test_log::test(tokio::test)]
+async fn retry_on_transient_error() {
+    // Nothing listens on this address.
+    let addr = next_available_addr();
+    let client = Client::http(format!("http://{addr}/"))
+        .unwrap()
+        .with_retry_policy(RetryPolicy {
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(1),
+        });
+
+    let attempts = AtomicUsize::new(0);
+    let error = client
+        .call(|client| {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            client.get_epoch_configuration()
+        })
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, Error::Client(ClientError::Transport(_))));
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn every_code_is_decoded() {
+    for value in [
+        code::ROLLUP_NOT_REGISTERED,
+        code::SIGNATURE_MISMATCH,
+        code::VALIDATION_FAILURE,
+        code::SETTLEMENT_ERROR,
+        code::STATUS_ERROR,
+        code::SEND_CERTIFICATE,
+        code::RATE_LIMITED,
+        code::RESOURCE_NOT_FOUND,
+        code::CERTIFICATE_VALIDATION,
+        code::ADMIN,
+        code::INVALID_CERTIFICATE_SIGNER,
+        code::UNEXPECTED_CERTIFICATE_HEIGHT,
+        code::PREV_LOCAL_EXIT_ROOT_MISMATCH,
+        code::CONFLICTING_CERTIFICATE,
+        code::NETWORK_PAUSED,
+    ] {
+        assert_ne!(ErrorKind::from_code(value), ErrorKind::Unknown, "{value}");
+    }
+
+    assert_eq!(ErrorKind::from_code(-1), ErrorKind::Unknown);
+}
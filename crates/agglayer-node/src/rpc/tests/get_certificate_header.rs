@@ -1,4 +1,7 @@
-use agglayer_types::{Certificate, CertificateHeader, CertificateId, CertificateStatus, Hash};
+use agglayer_storage::stores::StateWriter as _;
+use agglayer_types::{
+    Certificate, CertificateHeader, CertificateId, CertificateStatus, CertificateStatusError, Hash,
+};
 use insta::assert_snapshot;
 use jsonrpsee::{
     core::{client::ClientT, ClientError},
@@ -12,6 +15,12 @@ use super::raw_rpc;
 use super::TestContext;
 use crate::rpc::{tests::RawRpcContext, AgglayerServer};
 
+fn in_error() -> CertificateStatus {
+    CertificateStatus::InError {
+        error: CertificateStatusError::InternalError("failure".into()),
+    }
+}
+
 #[rstest]
 #[awt]
 #[test_log::test(tokio::test)]
@@ -290,6 +299,12 @@ async fn debug_get_certificate_after_overwrite() {
     assert_eq!(recv_cert.hash(), id);
     assert_eq!(header.status, CertificateStatus::Pending);
 
+    // Only a certificate in error can be replaced.
+    context
+        .state
+        .update_certificate_header_status(&id, &in_error())
+        .unwrap();
+
     let mut certificate = Certificate::new_for_test(1.into(), 0);
     certificate.prev_local_exit_root = [2; 32];
     let id2 = certificate.hash();
@@ -314,7 +329,12 @@ async fn debug_get_certificate_after_overwrite() {
     let header = header.unwrap();
     assert_eq!(header.certificate_id, id);
     assert_eq!(recv_cert.hash(), id);
-    assert_eq!(header.status, CertificateStatus::Pending);
+    assert_eq!(
+        header.status,
+        CertificateStatus::InError {
+            error: CertificateStatusError::Superseded(id2)
+        }
+    );
 
     // Retrieve 2
     let (recv_cert, header): (Certificate, Option<CertificateHeader>) = context
@@ -360,6 +380,11 @@ async fn debug_get_certificate_after_overwrite_with_debug_false() {
     let expected_message = format!("Resource not found: Certificate({:#})", id);
     assert!(matches!(error, ClientError::Call(obj) if obj.message() == expected_message));
 
+    context
+        .state
+        .update_certificate_header_status(&id, &in_error())
+        .unwrap();
+
     let mut certificate = Certificate::new_for_test(1.into(), 0);
     certificate.prev_local_exit_root = [2; 32];
     let id2 = certificate.hash();
@@ -0,0 +1,212 @@
+use agglayer_storage::stores::{PendingCertificateWriter as _, StateWriter as _};
+use agglayer_types::{Certificate, CertificateHeader, CertificateStatus, NetworkId};
+use jsonrpsee::{rpc_params, MethodsError};
+use rstest::*;
+
+use super::raw_rpc;
+use crate::rpc::{
+    tests::RawRpcContext, AgglayerServer, CertificateHeadersPage, CertificateStatusKind,
+};
+
+/// Populate the stores with two settled certificates, one proven certificate
+/// and one pending certificate for the network 1.
+fn populate(raw_rpc: &RawRpcContext) -> Vec<Certificate> {
+    let network_id: NetworkId = 1.into();
+    let certificates = (0..4)
+        .map(|height| Certificate::new_for_test(network_id, height))
+        .collect::<Vec<_>>();
+
+    let state = &raw_rpc.rpc.state;
+    let pending_store = &raw_rpc.rpc.pending_store;
+
+    for certificate in &certificates[..2] {
+        state
+            .insert_certificate_header(certificate, CertificateStatus::Settled)
+            .unwrap();
+    }
+
+    state
+        .insert_certificate_header(&certificates[2], CertificateStatus::Proven)
+        .unwrap();
+    pending_store
+        .set_latest_proven_certificate_per_network(&network_id, &2, &certificates[2].hash())
+        .unwrap();
+
+    state
+        .insert_certificate_header(&certificates[3], CertificateStatus::Pending)
+        .unwrap();
+    pending_store
+        .insert_pending_certificate(network_id, 3, &certificates[3])
+        .unwrap();
+
+    // Another network that must not leak into the results.
+    state
+        .insert_certificate_header(
+            &Certificate::new_for_test(2.into(), 0),
+            CertificateStatus::Settled,
+        )
+        .unwrap();
+
+    certificates
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn fetch_certificate_header_by_height(#[future] raw_rpc: RawRpcContext) {
+    let certificates = populate(&raw_rpc);
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    for (height, expected_status) in [
+        (0, CertificateStatus::Settled),
+        (2, CertificateStatus::Proven),
+        (3, CertificateStatus::Pending),
+    ] {
+        let header: CertificateHeader = rpc
+            .call(
+                "interop_getCertificateHeaderByHeight",
+                rpc_params![1, height],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(header.certificate_id, certificates[height as usize].hash());
+        assert_eq!(header.status, expected_status);
+    }
+
+    let result = rpc
+        .call::<_, CertificateHeader>("interop_getCertificateHeaderByHeight", rpc_params![1, 4])
+        .await;
+
+    assert!(matches!(result, Err(MethodsError::JsonRpc(_))));
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn fetch_unknown_certificate_header_by_height(#[future] raw_rpc: RawRpcContext) {
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let error = rpc
+        .call::<_, CertificateHeader>("interop_getCertificateHeaderByHeight", rpc_params![1, 0])
+        .await
+        .unwrap_err();
+
+    let expected_message = "Resource not found: Certificate(network_id: 1, height: 0)";
+    assert!(matches!(error, MethodsError::JsonRpc(obj) if obj.message() == expected_message));
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn fetch_certificate_headers_page(#[future] raw_rpc: RawRpcContext) {
+    let certificates = populate(&raw_rpc);
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let page: CertificateHeadersPage = rpc
+        .call(
+            "interop_getCertificateHeaders",
+            rpc_params![1, 0, 3, Option::<CertificateStatusKind>::None],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(page.next_height, Some(3));
+    assert_eq!(
+        page.headers
+            .iter()
+            .map(|header| header.certificate_id)
+            .collect::<Vec<_>>(),
+        certificates[..3]
+            .iter()
+            .map(Certificate::hash)
+            .collect::<Vec<_>>()
+    );
+
+    let page: CertificateHeadersPage = rpc
+        .call(
+            "interop_getCertificateHeaders",
+            rpc_params![1, 3, 10, Option::<CertificateStatusKind>::None],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(page.next_height, None);
+    assert_eq!(page.headers.len(), 1);
+    assert_eq!(page.headers[0].certificate_id, certificates[3].hash());
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn fetch_certificate_headers_with_status_filter(#[future] raw_rpc: RawRpcContext) {
+    let certificates = populate(&raw_rpc);
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let page: CertificateHeadersPage = rpc
+        .call(
+            "interop_getCertificateHeaders",
+            rpc_params![1, 0, 10, CertificateStatusKind::Settled],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(page.next_height, None);
+    assert_eq!(
+        page.headers
+            .iter()
+            .map(|header| header.certificate_id)
+            .collect::<Vec<_>>(),
+        certificates[..2]
+            .iter()
+            .map(Certificate::hash)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn fetch_certificate_headers_across_a_gap(#[future] raw_rpc: RawRpcContext) {
+    let certificates = populate(&raw_rpc);
+
+    // No certificate is known at height 4.
+    let network_id: NetworkId = 1.into();
+    let after_gap = Certificate::new_for_test(network_id, 5);
+    raw_rpc
+        .rpc
+        .state
+        .insert_certificate_header(&after_gap, CertificateStatus::Pending)
+        .unwrap();
+    raw_rpc
+        .rpc
+        .pending_store
+        .insert_pending_certificate(network_id, 5, &after_gap)
+        .unwrap();
+
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let page: CertificateHeadersPage = rpc
+        .call(
+            "interop_getCertificateHeaders",
+            rpc_params![1, 3, 1, Option::<CertificateStatusKind>::None],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(page.next_height, Some(4));
+    assert_eq!(page.headers.len(), 1);
+    assert_eq!(page.headers[0].certificate_id, certificates[3].hash());
+
+    let page: CertificateHeadersPage = rpc
+        .call(
+            "interop_getCertificateHeaders",
+            rpc_params![1, 4, 10, Option::<CertificateStatusKind>::None],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(page.next_height, None);
+    assert_eq!(page.headers.len(), 1);
+    assert_eq!(page.headers[0].certificate_id, after_gap.hash());
+}
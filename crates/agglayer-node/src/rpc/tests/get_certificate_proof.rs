@@ -0,0 +1,138 @@
+use agglayer_storage::stores::{
+    EpochStoreWriter as _, PendingCertificateWriter as _, PerEpochWriter as _, StateReader as _,
+    StateWriter as _,
+};
+use agglayer_types::{Certificate, CertificateStatus, Hash, Proof};
+use jsonrpsee::{rpc_params, MethodsError};
+use pessimistic_proof::PessimisticProofOutput;
+use rstest::*;
+
+use super::raw_rpc;
+use crate::rpc::{tests::RawRpcContext, AgglayerServer, CertificateProof, CertificateProofFormat};
+
+fn output(certificate: &Certificate) -> PessimisticProofOutput {
+    PessimisticProofOutput {
+        prev_local_exit_root: certificate.prev_local_exit_root,
+        prev_pessimistic_root: [0; 32],
+        l1_info_root: [0; 32],
+        origin_network: certificate.network_id,
+        consensus_hash: [0; 32],
+        new_local_exit_root: certificate.new_local_exit_root,
+        new_pessimistic_root: [1; 32],
+    }
+}
+
+/// Store a proven certificate for the network 1 at height 0 along with its
+/// proof.
+fn populate(raw_rpc: &RawRpcContext) -> Certificate {
+    let certificate = Certificate::new_for_test(1.into(), 0);
+    let certificate_id = certificate.hash();
+
+    raw_rpc
+        .rpc
+        .state
+        .insert_certificate_header(&certificate, CertificateStatus::Proven)
+        .unwrap();
+    raw_rpc
+        .rpc
+        .pending_store
+        .insert_pending_certificate(1.into(), 0, &certificate)
+        .unwrap();
+    raw_rpc
+        .rpc
+        .pending_store
+        .insert_generated_proof(
+            &certificate_id,
+            &Proof::new_for_test_with_public_values(&output(&certificate)),
+        )
+        .unwrap();
+
+    certificate
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn fetch_proof_of_proven_certificate(#[future] raw_rpc: RawRpcContext) {
+    let certificate = populate(&raw_rpc);
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let proof: CertificateProof = rpc
+        .call(
+            "interop_getCertificateProof",
+            rpc_params![certificate.hash(), Option::<CertificateProofFormat>::None],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(proof.certificate_id, certificate.hash());
+    assert_eq!(
+        proof.public_values.new_local_exit_root,
+        certificate.new_local_exit_root
+    );
+    assert_eq!(proof.public_values.new_pessimistic_root, [1; 32]);
+    assert!(!proof.vkey.is_empty());
+    // A core proof can't be verified onchain.
+    assert!(proof.proof.is_none());
+
+    let error = rpc
+        .call::<_, CertificateProof>(
+            "interop_getCertificateProof",
+            rpc_params![certificate.hash(), CertificateProofFormat::Fixture],
+        )
+        .await
+        .unwrap_err();
+
+    let expected_message = format!("Resource not found: OnchainProof({:#})", certificate.hash());
+    assert!(matches!(error, MethodsError::JsonRpc(obj) if obj.message() == expected_message));
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn fetch_proof_of_certificate_in_epoch(#[future] raw_rpc: RawRpcContext) {
+    let certificate = populate(&raw_rpc);
+
+    // Move the certificate and its proof to the storage of the epoch 0.
+    let epoch = raw_rpc.rpc.epochs_store.open(0).unwrap();
+    epoch.add_certificate(1.into(), 0).unwrap();
+    drop(epoch);
+
+    let header = raw_rpc
+        .rpc
+        .state
+        .get_certificate_header(&certificate.hash())
+        .unwrap()
+        .unwrap();
+    assert_eq!(header.epoch_number, Some(0));
+
+    let rpc = raw_rpc.rpc.into_rpc();
+    let proof: CertificateProof = rpc
+        .call(
+            "interop_getCertificateProof",
+            rpc_params![certificate.hash(), CertificateProofFormat::Proof],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(proof.certificate_id, certificate.hash());
+    assert_eq!(proof.public_values.new_pessimistic_root, [1; 32]);
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn fetch_proof_of_unknown_certificate(#[future] raw_rpc: RawRpcContext) {
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let error = rpc
+        .call::<_, CertificateProof>(
+            "interop_getCertificateProof",
+            rpc_params![Hash([0; 32]), Option::<CertificateProofFormat>::None],
+        )
+        .await
+        .unwrap_err();
+
+    let expected_message = format!("Resource not found: Certificate({:#})", Hash([0; 32]));
+    assert!(matches!(error, MethodsError::JsonRpc(obj) if obj.message() == expected_message));
+}
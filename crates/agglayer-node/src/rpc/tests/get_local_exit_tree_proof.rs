@@ -0,0 +1,51 @@
+use agglayer_storage::stores::StateWriter as _;
+use agglayer_types::{Hash, LocalExitTreeProof, LocalNetworkStateData};
+use jsonrpsee::{rpc_params, MethodsError};
+use rstest::*;
+
+use super::raw_rpc;
+use crate::rpc::{tests::RawRpcContext, AgglayerServer};
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn fetch_local_exit_tree_proof(#[future] raw_rpc: RawRpcContext) {
+    let mut state = LocalNetworkStateData::default();
+    let leaves = (0..4u8).map(|i| Hash([i; 32])).collect::<Vec<_>>();
+    let mut roots = Vec::new();
+    for leaf in &leaves {
+        state.exit_tree.add_leaf(leaf.0).unwrap();
+        roots.push(Hash(state.exit_tree.get_root()));
+    }
+
+    raw_rpc
+        .rpc
+        .state
+        .write_local_network_state(&1.into(), &0, &state, &leaves)
+        .unwrap();
+
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    for (leaf_count, expected_root) in [(None, roots[3]), (Some(2), roots[1])] {
+        let proof: LocalExitTreeProof = rpc
+            .call(
+                "interop_getLocalExitTreeProof",
+                rpc_params![1, 1, leaf_count],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(proof.leaf, leaves[1]);
+        assert_eq!(proof.root, expected_root);
+        assert!(proof.proof.verify(proof.leaf.0, 1, proof.root.0));
+    }
+
+    let error = rpc
+        .call::<_, LocalExitTreeProof>("interop_getLocalExitTreeProof", rpc_params![1, 4])
+        .await
+        .unwrap_err();
+
+    let expected_message =
+        "Resource not found: LocalExitTreeLeaf(network_id: 1, leaf_index: 4, leaf_count: None)";
+    assert!(matches!(error, MethodsError::JsonRpc(obj) if obj.message() == expected_message));
+}
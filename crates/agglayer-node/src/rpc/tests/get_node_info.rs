@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use agglayer_config::{epoch::TimeClockConfig, Epoch};
+use agglayer_types::Address;
+use jsonrpsee::{core::client::ClientT, rpc_params};
+use rstest::*;
+
+use super::{context, raw_rpc, TestContext};
+use crate::rpc::{tests::RawRpcContext, AgglayerServer, EpochClockKind, NodeInfo};
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn node_info_from_config(#[future] context: TestContext) {
+    let config = TestContext::get_default_config();
+
+    let node_info: NodeInfo = context
+        .client
+        .request("interop_getNodeInfo", rpc_params![])
+        .await
+        .unwrap();
+
+    assert_eq!(node_info.version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(node_info.vkey, None);
+    assert_eq!(node_info.settlement_signer, None);
+    assert_eq!(node_info.l1.chain_id, config.l1.chain_id);
+    assert_eq!(
+        node_info.l1.rollup_manager_contract,
+        Address::new(config.l1.rollup_manager_contract.0)
+    );
+    assert_eq!(
+        node_info.l1.global_exit_root_contract,
+        Address::new(config.l1.polygon_zkevm_global_exit_root_v2_contract.0)
+    );
+    assert_eq!(node_info.epoch_clock, EpochClockKind::BlockClock);
+    assert!(!node_info.debug_mode);
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn node_info_with_settlement_identity(#[future] raw_rpc: RawRpcContext) {
+    let signer = Address::new([1; 20]);
+    let rpc = raw_rpc
+        .rpc
+        .with_settlement_identity("0x01".to_string(), signer)
+        .into_rpc();
+
+    let node_info: NodeInfo = rpc
+        .call("interop_getNodeInfo", rpc_params![])
+        .await
+        .unwrap();
+
+    assert_eq!(node_info.vkey.as_deref(), Some("0x01"));
+    assert_eq!(node_info.settlement_signer, Some(signer));
+}
+
+#[test_log::test(tokio::test)]
+async fn node_info_with_time_clock() {
+    let mut config = TestContext::get_default_config();
+    config.epoch = Epoch::TimeClock(TimeClockConfig {
+        epoch_duration: Duration::from_secs(1),
+    });
+    config.debug_mode = true;
+
+    let context = TestContext::new_with_config(config).await;
+
+    let node_info: NodeInfo = context
+        .client
+        .request("interop_getNodeInfo", rpc_params![])
+        .await
+        .unwrap();
+
+    assert_eq!(node_info.epoch_clock, EpochClockKind::TimeClock);
+    assert!(node_info.debug_mode);
+}
@@ -15,7 +15,7 @@ use jsonrpsee::core::ClientError;
 use jsonrpsee::http_client::HttpClientBuilder;
 use jsonrpsee::rpc_params;
 
-use super::next_available_addr;
+use super::{dummy_clock_ref, next_available_addr};
 use crate::rpc::tests::DummyStore;
 use crate::rpc::{self, TxStatus};
 use crate::{kernel::Kernel, rpc::AgglayerImpl};
@@ -57,7 +57,10 @@ async fn check_tx_status() {
         Arc::new(DummyStore {}),
         Arc::new(DummyStore {}),
         Arc::new(DummyStore {}),
+        Arc::new(DummyStore {}),
         config.clone(),
+        dummy_clock_ref(),
+        tokio::sync::broadcast::channel(1).0,
     )
     .start()
     .await
@@ -106,6 +109,7 @@ async fn check_tx_status_fail() {
     let store = Arc::new(PendingStore::new(db));
     let state = Arc::new(StateStore::new(store_db));
     let debug = Arc::new(DebugStore::new_with_path(&tmp.path.join("debug")).unwrap());
+    let certificate_status_sender = state.certificate_status_sender();
 
     let _server_handle = AgglayerImpl::new(
         kernel,
@@ -113,7 +117,10 @@ async fn check_tx_status_fail() {
         store,
         state,
         debug,
+        Arc::new(DummyStore {}),
         config.clone(),
+        dummy_clock_ref(),
+        certificate_status_sender,
     )
     .start()
     .await
@@ -0,0 +1,144 @@
+use agglayer_storage::stores::StateWriter as _;
+use agglayer_types::{address, LocalNetworkStateData, NullifierProof, U256};
+use jsonrpsee::{rpc_params, MethodsError};
+use pessimistic_proof::{
+    bridge_exit::TokenInfo,
+    global_index::GlobalIndex,
+    keccak::Digest,
+    local_state::StateCommitment,
+    nullifier_tree::{FromBool as _, NullifierKey},
+};
+use rstest::*;
+
+use super::raw_rpc;
+use crate::rpc::{tests::RawRpcContext, AgglayerServer, NullifierStatus, TokenBalance};
+
+fn token() -> TokenInfo {
+    TokenInfo {
+        origin_network: 0.into(),
+        origin_token_address: address!("0000000000000000000000000000000000000001"),
+    }
+}
+
+const CLAIMED: GlobalIndex = GlobalIndex {
+    mainnet_flag: true,
+    rollup_index: 0,
+    leaf_index: 1,
+};
+
+/// Persist a local network state for the network 1 holding a balance of 10
+/// for [`token`] and the [`CLAIMED`] global index in its nullifier tree.
+fn populate(raw_rpc: &RawRpcContext) -> LocalNetworkStateData {
+    let mut state = LocalNetworkStateData::default();
+    state
+        .balance_tree
+        .insert(token(), U256::from(10).to_be_bytes())
+        .unwrap();
+    state
+        .nullifier_tree
+        .insert(NullifierKey::from(CLAIMED), Digest::from_bool(true))
+        .unwrap();
+
+    raw_rpc
+        .rpc
+        .state
+        .write_local_network_state(&1.into(), &0, &state, &[])
+        .unwrap();
+
+    state
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn fetch_local_network_state_roots(#[future] raw_rpc: RawRpcContext) {
+    let state = populate(&raw_rpc);
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let roots: StateCommitment = rpc
+        .call("interop_getLocalNetworkStateRoots", rpc_params![1])
+        .await
+        .unwrap();
+
+    assert_eq!(roots, state.get_roots());
+
+    let error = rpc
+        .call::<_, StateCommitment>("interop_getLocalNetworkStateRoots", rpc_params![2])
+        .await
+        .unwrap_err();
+
+    let expected_message = "Resource not found: LocalNetworkState(2)";
+    assert!(matches!(error, MethodsError::JsonRpc(obj) if obj.message() == expected_message));
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn fetch_token_balance_with_proof(#[future] raw_rpc: RawRpcContext) {
+    let state = populate(&raw_rpc);
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let balance: TokenBalance = rpc
+        .call("interop_getTokenBalance", rpc_params![1, token()])
+        .await
+        .unwrap();
+
+    assert_eq!(balance.balance, U256::from(10));
+    assert_eq!(balance.balance_root, state.balance_tree.root.into());
+    assert!(balance.proof.verify(
+        token(),
+        U256::from(10).to_be_bytes(),
+        state.balance_tree.root
+    ));
+
+    // An unknown token has a zero balance which is still provable.
+    let unknown_token = TokenInfo {
+        origin_network: 0.into(),
+        origin_token_address: address!("0000000000000000000000000000000000000002"),
+    };
+    let balance: TokenBalance = rpc
+        .call("interop_getTokenBalance", rpc_params![1, unknown_token])
+        .await
+        .unwrap();
+
+    assert_eq!(balance.balance, U256::ZERO);
+    assert!(balance
+        .proof
+        .verify(unknown_token, Digest::default(), state.balance_tree.root));
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn fetch_nullifier_status_with_proof(#[future] raw_rpc: RawRpcContext) {
+    let state = populate(&raw_rpc);
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let status: NullifierStatus = rpc
+        .call("interop_getNullifierStatus", rpc_params![1, CLAIMED])
+        .await
+        .unwrap();
+
+    assert!(status.claimed);
+    assert_eq!(status.nullifier_root, state.nullifier_tree.root.into());
+    let NullifierProof::Inclusion(proof) = status.proof else {
+        panic!("Expected an inclusion proof for a claimed global index");
+    };
+    assert!(proof.verify(
+        NullifierKey::from(CLAIMED),
+        Digest::from_bool(true),
+        state.nullifier_tree.root
+    ));
+
+    let unclaimed = GlobalIndex {
+        leaf_index: 2,
+        ..CLAIMED
+    };
+    let status: NullifierStatus = rpc
+        .call("interop_getNullifierStatus", rpc_params![1, unclaimed])
+        .await
+        .unwrap();
+
+    assert!(!status.claimed);
+    assert!(matches!(status.proof, NullifierProof::NonInclusion(_)));
+}
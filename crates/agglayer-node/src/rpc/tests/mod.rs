@@ -1,13 +1,20 @@
 use std::net::IpAddr;
+use std::num::NonZeroU64;
+use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
+use std::time::Duration;
 
+use agglayer_clock::{ClockRef, Event};
 use agglayer_config::Config;
 use agglayer_storage::columns::latest_settled_certificate_per_network::SettledCertificate;
 use agglayer_storage::storage::{pending_db_cf_definitions, state_db_cf_definitions, DB};
 use agglayer_storage::stores::debug::DebugStore;
+use agglayer_storage::stores::epochs::EpochsStore;
 use agglayer_storage::stores::pending::PendingStore;
 use agglayer_storage::stores::state::StateStore;
-use agglayer_storage::stores::{DebugReader, DebugWriter, PendingCertificateReader};
+use agglayer_storage::stores::{
+    DebugReader, DebugWriter, EpochStoreReader, MetadataReader, PendingCertificateReader,
+};
 use agglayer_storage::{
     stores::{PendingCertificateWriter, StateReader, StateWriter},
     tests::TempDBDir,
@@ -21,19 +28,31 @@ use jsonrpsee::http_client::HttpClientBuilder;
 use jsonrpsee::server::ServerHandle;
 use rstest::*;
 
-use crate::{kernel::Kernel, rpc::AgglayerImpl};
+use crate::{
+    health::{HealthChecks, TaskHealthCheck},
+    kernel::Kernel,
+    rpc::AgglayerImpl,
+};
 
+mod admin;
+mod client;
 mod errors;
 mod get_certificate_header;
+mod get_certificate_headers;
+mod get_certificate_proof;
 mod get_epoch_configuration;
 mod get_latest_known_certificate_header;
+mod get_local_exit_tree_proof;
+mod get_node_info;
 mod get_tx_status;
+mod local_network_state;
+mod openrpc;
 mod send_certificate;
+mod subscriptions;
+mod validate_certificate;
 
-#[test_log::test(tokio::test)]
-async fn healthcheck_method_can_be_called() {
-    use hyper::Request;
-
+/// Start an RPC server backed by dummy stores, with the given health checks.
+async fn start_dummy_server(health_checks: HealthChecks) -> (Arc<Config>, ServerHandle) {
     let mut config = Config::new_for_test();
     let addr = next_available_addr();
     if let std::net::IpAddr::V4(ip) = addr.ip() {
@@ -47,42 +66,102 @@ async fn healthcheck_method_can_be_called() {
 
     let kernel = Kernel::new(Arc::new(provider), config.clone());
 
-    let _server_handle = AgglayerImpl::new(
+    let server_handle = AgglayerImpl::new(
         kernel,
         certificate_sender,
         Arc::new(DummyStore {}),
         Arc::new(DummyStore {}),
         Arc::new(DummyStore {}),
+        Arc::new(DummyStore {}),
         config.clone(),
+        dummy_clock_ref(),
+        tokio::sync::broadcast::channel(1).0,
     )
+    .with_health_checks(health_checks)
     .start()
     .await
     .unwrap();
 
+    (config, server_handle)
+}
+
+async fn http_get(uri: &str) -> (hyper::StatusCode, String) {
+    use hyper::Request;
+
     let http_client = Client::builder(TokioExecutor::new()).build_http();
-    let uri = format!("http://{}/health", config.rpc_addr());
 
     let req = Request::builder()
         .method("GET")
-        .uri(&uri)
+        .uri(uri)
         .body(Empty::<hyper::body::Bytes>::new())
         .expect("request builder");
     let res = http_client.request(req).await.unwrap();
-
-    assert!(res.status().is_success());
+    let status = res.status();
 
     let bytes = http_body_util::BodyExt::collect(res.into_body())
         .await
         .unwrap();
-    let out = String::from_utf8(bytes.to_bytes().to_vec()).unwrap();
-    assert_eq!(out.as_str(), "{\"health\":true}");
+
+    (
+        status,
+        String::from_utf8(bytes.to_bytes().to_vec()).unwrap(),
+    )
+}
+
+#[test_log::test(tokio::test)]
+async fn healthcheck_method_can_be_called() {
+    let (config, _server_handle) =
+        start_dummy_server(HealthChecks::new(Duration::from_secs(1))).await;
+
+    let (status, out) = http_get(&format!("http://{}/health", config.rpc_addr())).await;
+
+    assert!(status.is_success());
+    assert_eq!(out.as_str(), "{\"health\":true,\"components\":{}}");
+}
+
+#[test_log::test(tokio::test)]
+async fn readiness_reports_unhealthy_components() {
+    let finished_task = tokio::spawn(async {});
+    let health_checks = HealthChecks::new(Duration::from_secs(1))
+        .with_check("task", TaskHealthCheck::new(finished_task.abort_handle()));
+    finished_task.await.unwrap();
+
+    let (config, _server_handle) = start_dummy_server(health_checks).await;
+
+    let (status, out) = http_get(&format!("http://{}/health", config.rpc_addr())).await;
+    assert!(status.is_success());
+    let report: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(report["health"], false);
+    assert_eq!(report["components"]["task"]["healthy"], false);
+
+    let (status, _) = http_get(&format!("http://{}/ready", config.rpc_addr())).await;
+    assert_eq!(status, hyper::StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[test_log::test(tokio::test)]
+async fn readiness_succeeds_when_healthy() {
+    let (config, _server_handle) =
+        start_dummy_server(HealthChecks::new(Duration::from_secs(1))).await;
+
+    let (status, out) = http_get(&format!("http://{}/ready", config.rpc_addr())).await;
+
+    assert!(status.is_success());
+    assert_eq!(out.as_str(), "{\"health\":true,\"components\":{}}");
 }
 
 pub(crate) struct RawRpcContext {
-    pub(crate) rpc: AgglayerImpl<Provider<MockProvider>, PendingStore, StateStore, DebugStore>,
+    pub(crate) rpc: AgglayerImpl<
+        Provider<MockProvider>,
+        PendingStore,
+        StateStore,
+        DebugStore,
+        EpochsStore<PendingStore, StateStore>,
+    >,
     config: Arc<Config>,
     pub(crate) certificate_receiver:
         tokio::sync::mpsc::Receiver<(NetworkId, Height, CertificateId)>,
+    pub(crate) clock_sender: tokio::sync::broadcast::Sender<Event>,
+    pub(crate) l1_mock: MockProvider,
 }
 
 pub(crate) struct TestContext {
@@ -90,6 +169,7 @@ pub(crate) struct TestContext {
     pub(crate) client: jsonrpsee::http_client::HttpClient,
     pub(crate) certificate_receiver:
         tokio::sync::mpsc::Receiver<(NetworkId, Height, CertificateId)>,
+    pub(crate) state: Arc<StateStore>,
 }
 
 impl TestContext {
@@ -100,6 +180,7 @@ impl TestContext {
 
     async fn new_with_config(config: Config) -> Self {
         let raw_rpc = Self::new_raw_rpc_with_config(config).await;
+        let state = raw_rpc.rpc.state.clone();
         let server_handle = raw_rpc.rpc.start().await.unwrap();
 
         let url = format!("http://{}/", raw_rpc.config.rpc_addr());
@@ -109,12 +190,16 @@ impl TestContext {
             server_handle,
             client,
             certificate_receiver: raw_rpc.certificate_receiver,
+            state,
         }
     }
 
     pub(crate) fn get_default_config() -> Config {
         let tmp = TempDBDir::new();
-        Config::new(&tmp.path)
+        let mut config = Config::new(&tmp.path);
+        config.proof_signers.insert(1, test_signer());
+
+        config
     }
 
     async fn new_raw_rpc() -> RawRpcContext {
@@ -139,15 +224,32 @@ impl TestContext {
         );
 
         let state_store = Arc::new(StateStore::new(state_db));
+        let certificate_status_sender = state_store.certificate_status_sender();
         let pending_store = Arc::new(PendingStore::new(pending_db));
         let debug_store = if config.debug_mode {
             Arc::new(DebugStore::new_with_path(&config.storage.debug_db_path).unwrap())
         } else {
             Arc::new(DebugStore::Disabled)
         };
-        let (provider, _mock) = providers::Provider::mocked();
+        let epochs_store = Arc::new(
+            EpochsStore::new(
+                config.clone(),
+                0,
+                pending_store.clone(),
+                state_store.clone(),
+            )
+            .unwrap(),
+        );
+        let (provider, l1_mock) = providers::Provider::mocked();
         let (certificate_sender, certificate_receiver) = tokio::sync::mpsc::channel(1);
 
+        let (clock_sender, _clock_receiver) = tokio::sync::broadcast::channel(10);
+        let clock_ref = ClockRef::new(
+            clock_sender.clone(),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(NonZeroU64::new(3).unwrap()),
+        );
+
         let kernel = Kernel::new(Arc::new(provider), config.clone());
 
         let rpc = AgglayerImpl::new(
@@ -156,13 +258,18 @@ impl TestContext {
             pending_store,
             state_store,
             debug_store,
+            epochs_store,
             config.clone(),
+            clock_ref,
+            certificate_status_sender,
         );
 
         RawRpcContext {
             rpc,
             config,
             certificate_receiver,
+            clock_sender,
+            l1_mock,
         }
     }
 }
@@ -183,6 +290,23 @@ async fn raw_rpc() -> RawRpcContext {
     TestContext::new_raw_rpc().await
 }
 
+/// The address signing the test certificates, as expected in the
+/// `proof_signers` configuration.
+fn test_signer() -> ethers::types::Address {
+    ethers::types::Address::from_slice(Certificate::test_signer().as_slice())
+}
+
+/// Build a [`ClockRef`] that never emits any event.
+fn dummy_clock_ref() -> ClockRef {
+    let (sender, _receiver) = tokio::sync::broadcast::channel(1);
+
+    ClockRef::new(
+        sender,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(NonZeroU64::new(3).unwrap()),
+    )
+}
+
 fn next_available_addr() -> std::net::SocketAddr {
     use std::net::{TcpListener, TcpStream};
 
@@ -216,6 +340,23 @@ impl DebugReader for DummyStore {
         Ok(None)
     }
 }
+impl EpochStoreReader for DummyStore {
+    fn get_certificate_in_epoch(
+        &self,
+        _epoch_number: agglayer_types::EpochNumber,
+        _certificate_index: agglayer_types::CertificateIndex,
+    ) -> Result<Option<Certificate>, agglayer_storage::error::Error> {
+        Ok(None)
+    }
+
+    fn get_proof_in_epoch(
+        &self,
+        _epoch_number: agglayer_types::EpochNumber,
+        _certificate_index: agglayer_types::CertificateIndex,
+    ) -> Result<Option<agglayer_types::Proof>, agglayer_storage::error::Error> {
+        Ok(None)
+    }
+}
 impl DebugWriter for DummyStore {
     fn add_certificate(
         &self,
@@ -248,6 +389,13 @@ impl StateWriter for DummyStore {
     ) -> Result<(), agglayer_storage::error::Error> {
         Ok(())
     }
+    fn update_certificate_header_settlement_tx_hash(
+        &self,
+        _certificate_id: &agglayer_types::CertificateId,
+        _tx_hash: &agglayer_types::Hash,
+    ) -> Result<(), agglayer_storage::error::Error> {
+        Ok(())
+    }
     fn set_latest_settled_certificate_for_network(
         &self,
         _network_id: &NetworkId,
@@ -262,11 +410,32 @@ impl StateWriter for DummyStore {
     fn write_local_network_state(
         &self,
         _network_id: &NetworkId,
+        _height: &Height,
         _new_state: &agglayer_types::LocalNetworkStateData,
         _new_leaves: &[agglayer_types::Hash],
     ) -> Result<(), agglayer_storage::error::Error> {
         todo!()
     }
+
+    fn rewind_local_network_state(
+        &self,
+        _network_id: &NetworkId,
+        _height: &Height,
+    ) -> Result<(), agglayer_storage::error::Error> {
+        todo!()
+    }
+}
+
+impl MetadataReader for DummyStore {
+    fn get_latest_settled_epoch(&self) -> Result<Option<u64>, agglayer_storage::error::Error> {
+        todo!()
+    }
+
+    fn get_paused_networks(
+        &self,
+    ) -> Result<std::collections::BTreeSet<NetworkId>, agglayer_storage::error::Error> {
+        Ok(Default::default())
+    }
 }
 
 impl StateReader for DummyStore {
@@ -277,7 +446,7 @@ impl StateReader for DummyStore {
         &self,
         _network_id: &NetworkId,
     ) -> Result<Option<(NetworkId, SettledCertificate)>, agglayer_storage::error::Error> {
-        todo!()
+        Ok(None)
     }
 
     fn get_certificate_header(
@@ -295,6 +464,15 @@ impl StateReader for DummyStore {
         todo!()
     }
 
+    fn get_certificate_headers_by_cursor(
+        &self,
+        _network_id: NetworkId,
+        _from_height: agglayer_types::Height,
+        _limit: usize,
+    ) -> Result<Vec<agglayer_types::CertificateHeader>, agglayer_storage::error::Error> {
+        todo!()
+    }
+
     fn get_current_settled_height(
         &self,
     ) -> Result<Vec<(NetworkId, SettledCertificate)>, agglayer_storage::error::Error> {
@@ -307,6 +485,15 @@ impl StateReader for DummyStore {
     ) -> Result<Option<agglayer_types::LocalNetworkStateData>, agglayer_storage::error::Error> {
         todo!()
     }
+
+    fn get_local_exit_tree_proof(
+        &self,
+        _network_id: NetworkId,
+        _leaf_index: u32,
+        _leaf_count: Option<u32>,
+    ) -> Result<Option<agglayer_types::LocalExitTreeProof>, agglayer_storage::error::Error> {
+        todo!()
+    }
 }
 impl PendingCertificateWriter for DummyStore {
     fn insert_pending_certificate(
@@ -318,6 +505,15 @@ impl PendingCertificateWriter for DummyStore {
         Ok(())
     }
 
+    fn set_pending_since(
+        &self,
+        _network_id: NetworkId,
+        _height: Height,
+        _epoch_number: agglayer_types::EpochNumber,
+    ) -> Result<(), agglayer_storage::error::Error> {
+        Ok(())
+    }
+
     fn insert_generated_proof(
         &self,
         _certificate_id: &agglayer_types::CertificateId,
@@ -362,7 +558,7 @@ impl PendingCertificateReader for DummyStore {
         _network_id: NetworkId,
         _height: Height,
     ) -> Result<Option<Certificate>, agglayer_storage::error::Error> {
-        todo!()
+        Ok(None)
     }
 
     fn get_proof(
@@ -406,6 +602,27 @@ impl PendingCertificateReader for DummyStore {
         &self,
         _network_id: &NetworkId,
     ) -> Result<Option<(NetworkId, Height, CertificateId)>, agglayer_storage::error::Error> {
+        Ok(None)
+    }
+
+    fn get_pending_since(
+        &self,
+        _network_id: NetworkId,
+        _height: Height,
+    ) -> Result<Option<agglayer_types::EpochNumber>, agglayer_storage::error::Error> {
         todo!()
     }
+
+    fn get_networks_with_pending_certificates(
+        &self,
+    ) -> Result<Vec<NetworkId>, agglayer_storage::error::Error> {
+        Ok(vec![])
+    }
+
+    fn get_pending_certificates_for_network(
+        &self,
+        _network_id: &NetworkId,
+    ) -> Result<Vec<Certificate>, agglayer_storage::error::Error> {
+        Ok(vec![])
+    }
 }
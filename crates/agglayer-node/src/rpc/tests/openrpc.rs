@@ -0,0 +1,360 @@
+use std::collections::BTreeSet;
+
+use agglayer_types::{
+    Address, Certificate, CertificateHeader, CertificateStatus, CertificateStatusError,
+    EpochConfiguration, GenerationType, Hash, ProofVerificationError,
+};
+use jsonrpsee::{core::client::ClientT, rpc_params};
+use pessimistic_proof::ProofError;
+use pessimistic_proof_test_suite::sample_data::load_certificate;
+use rstest::*;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::{context, raw_rpc, TestContext};
+use crate::rpc::{openrpc, tests::RawRpcContext, AgglayerServer, NodeInfo};
+
+/// Check that a value conforms to a schema of the OpenRPC document.
+///
+/// Only the subset of JSON Schema used by the document is supported.
+fn validate(document: &Value, schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        let name = reference
+            .strip_prefix("#/components/schemas/")
+            .ok_or_else(|| format!("{path}: unsupported reference {reference}"))?;
+        let schema = &document["components"]["schemas"][name];
+        if schema.is_null() {
+            return Err(format!("{path}: unknown schema {name}"));
+        }
+
+        return validate(document, schema, value, path);
+    }
+
+    if let Some(schemas) = schema.get("oneOf").and_then(Value::as_array) {
+        let matching = schemas
+            .iter()
+            .filter(|schema| validate(document, schema, value, path).is_ok())
+            .count();
+        if matching != 1 {
+            return Err(format!("{path}: {value} matches {matching} variants"));
+        }
+    }
+
+    if let Some(variants) = schema.get("enum").and_then(Value::as_array) {
+        if !variants.contains(value) {
+            return Err(format!("{path}: {value} is not one of {variants:?}"));
+        }
+    }
+
+    if let Some(constant) = schema.get("const") {
+        if constant != value {
+            return Err(format!("{path}: {value} is not {constant}"));
+        }
+    }
+
+    if let Some(expected) = schema.get("type").and_then(Value::as_str) {
+        let valid = match expected {
+            "null" => value.is_null(),
+            "boolean" => value.is_boolean(),
+            "string" => value.is_string(),
+            "integer" => value.is_u64() || value.is_i64(),
+            "array" => value.is_array(),
+            "object" => value.is_object(),
+            _ => return Err(format!("{path}: unsupported type {expected}")),
+        };
+        if !valid {
+            return Err(format!("{path}: {value} is not of type {expected}"));
+        }
+    }
+
+    if let Some(integer) = value.as_i64() {
+        if let Some(minimum) = schema.get("minimum").and_then(Value::as_i64) {
+            if integer < minimum {
+                return Err(format!("{path}: {integer} is lower than {minimum}"));
+            }
+        }
+        if let Some(maximum) = schema.get("maximum").and_then(Value::as_i64) {
+            if integer > maximum {
+                return Err(format!("{path}: {integer} is greater than {maximum}"));
+            }
+        }
+    }
+
+    if let Some(items) = value.as_array() {
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+            if (items.len() as u64) < min {
+                return Err(format!("{path}: less than {min} items"));
+            }
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+            if (items.len() as u64) > max {
+                return Err(format!("{path}: more than {max} items"));
+            }
+        }
+        for (index, item) in items.iter().enumerate() {
+            let item_schema = match &schema["items"] {
+                Value::Array(schemas) => &schemas[index],
+                schema => schema,
+            };
+            if !item_schema.is_null() {
+                validate(document, item_schema, item, &format!("{path}[{index}]"))?;
+            }
+        }
+    }
+
+    if let Some(fields) = value.as_object() {
+        if let Some(min) = schema.get("minProperties").and_then(Value::as_u64) {
+            if (fields.len() as u64) < min {
+                return Err(format!("{path}: less than {min} properties"));
+            }
+        }
+        if let Some(max) = schema.get("maxProperties").and_then(Value::as_u64) {
+            if (fields.len() as u64) > max {
+                return Err(format!("{path}: more than {max} properties"));
+            }
+        }
+        if let Some(required) = schema.get("required").and_then(Value::as_array) {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !fields.contains_key(name) {
+                    return Err(format!("{path}: missing property {name}"));
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (name, field) in fields {
+            let field_path = format!("{path}.{name}");
+            match properties.and_then(|properties| properties.get(name)) {
+                Some(field_schema) => validate(document, field_schema, field, &field_path)?,
+                None if schema["additionalProperties"] == Value::Bool(false) => {
+                    return Err(format!("{field_path}: unexpected property"));
+                }
+                None => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[track_caller]
+fn assert_conforms<T: Serialize>(schema: &str, value: &T) {
+    let document = openrpc::document();
+    let value = serde_json::to_value(value).unwrap();
+    let reference = serde_json::json!({ "$ref": format!("#/components/schemas/{schema}") });
+
+    if let Err(error) = validate(&document, &reference, &value, schema) {
+        panic!("{error}");
+    }
+}
+
+/// One instance of every [`CertificateStatusError`] variant.
+///
+/// The match below doesn't compile when a variant is added, as a reminder to
+/// describe it in the OpenRPC document.
+fn every_certificate_status_error() -> Vec<CertificateStatusError> {
+    let errors = vec![
+        CertificateStatusError::ProofGenerationError {
+            generation_type: GenerationType::Native,
+            source: ProofError::InvalidNullifierPath,
+        },
+        CertificateStatusError::ProofGenerationError {
+            generation_type: GenerationType::Prover,
+            source: ProofError::InvalidPreviousLocalExitRoot {
+                declared: [1; 32].into(),
+                computed: [2; 32].into(),
+            },
+        },
+        CertificateStatusError::ProofVerificationFailed(ProofVerificationError::Core(
+            "failure".into(),
+        )),
+        CertificateStatusError::ProofVerificationFailed(
+            ProofVerificationError::InvalidPublicValues,
+        ),
+        CertificateStatusError::TypeConversionError(agglayer_types::Error::MultipleL1InfoRoot),
+        CertificateStatusError::TypeConversionError(
+            agglayer_types::Error::MismatchNewLocalExitRoot {
+                computed: Hash([1; 32]),
+                declared: Hash([2; 32]),
+            },
+        ),
+        CertificateStatusError::TrustedSequencerNotFound(1.into()),
+        CertificateStatusError::InternalError("failure".into()),
+        CertificateStatusError::SettlementError("failure".into()),
+        CertificateStatusError::L1InfoRootNotFound(1),
+        CertificateStatusError::Superseded(Hash([1; 32])),
+        CertificateStatusError::Expired(1),
+    ];
+
+    for error in &errors {
+        match error {
+            CertificateStatusError::ProofGenerationError { .. }
+            | CertificateStatusError::ProofVerificationFailed(_)
+            | CertificateStatusError::TypeConversionError(_)
+            | CertificateStatusError::TrustedSequencerNotFound(_)
+            | CertificateStatusError::InternalError(_)
+            | CertificateStatusError::SettlementError(_)
+            | CertificateStatusError::L1InfoRootNotFound(_)
+            | CertificateStatusError::Superseded(_)
+            | CertificateStatusError::Expired(_) => {}
+        }
+    }
+
+    errors
+}
+
+fn header(certificate: &Certificate, status: CertificateStatus) -> CertificateHeader {
+    CertificateHeader {
+        network_id: certificate.network_id,
+        height: certificate.height,
+        epoch_number: None,
+        certificate_index: None,
+        certificate_id: certificate.hash(),
+        prev_local_exit_root: certificate.prev_local_exit_root.into(),
+        new_local_exit_root: certificate.new_local_exit_root.into(),
+        metadata: certificate.metadata,
+        status,
+        settlement_tx_hash: None,
+    }
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn document_describes_every_method(#[future] raw_rpc: RawRpcContext) {
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let registered: BTreeSet<&str> = rpc
+        .method_names()
+        .filter(|name| name.starts_with("interop_"))
+        .collect();
+
+    let document = openrpc::document();
+    let described: BTreeSet<&str> = document["methods"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|method| method["name"].as_str().unwrap())
+        .collect();
+
+    assert_eq!(registered, described);
+}
+
+#[test]
+fn document_references_are_defined() {
+    fn check(document: &Value, value: &Value) {
+        match value {
+            Value::Object(fields) => {
+                if let Some(reference) = fields.get("$ref").and_then(Value::as_str) {
+                    let name = reference.trim_start_matches("#/components/schemas/");
+                    assert!(
+                        !document["components"]["schemas"][name].is_null(),
+                        "undefined schema {name}"
+                    );
+                }
+                fields.values().for_each(|value| check(document, value));
+            }
+            Value::Array(items) => items.iter().for_each(|value| check(document, value)),
+            _ => {}
+        }
+    }
+
+    let document = openrpc::document();
+    check(&document, &document);
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn rpc_discover_serves_the_document(#[future] context: TestContext) {
+    let document: Value = context
+        .client
+        .request("rpc.discover", rpc_params![])
+        .await
+        .unwrap();
+
+    assert_eq!(document, openrpc::document());
+}
+
+#[test]
+fn certificate_status_conforms_to_schema() {
+    for status in [
+        CertificateStatus::Pending,
+        CertificateStatus::Proven,
+        CertificateStatus::Candidate,
+        CertificateStatus::Settled,
+    ] {
+        assert_conforms("CertificateStatus", &status);
+    }
+
+    for error in every_certificate_status_error() {
+        assert_conforms("CertificateStatusError", &error);
+        assert_conforms("CertificateStatus", &CertificateStatus::InError { error });
+    }
+}
+
+#[test]
+fn certificate_conforms_to_schema() {
+    assert_conforms("Certificate", &Certificate::new_for_test(1.into(), 0));
+    // Certificate with bridge exits and imported bridge exits.
+    assert_conforms("Certificate", &load_certificate("n15-cert_h1.json"));
+}
+
+#[test]
+fn certificate_header_conforms_to_schema() {
+    let certificate = Certificate::new_for_test(1.into(), 0);
+
+    assert_conforms(
+        "CertificateHeader",
+        &header(&certificate, CertificateStatus::Pending),
+    );
+    assert_conforms(
+        "CertificateHeader",
+        &CertificateHeader {
+            epoch_number: Some(1),
+            certificate_index: Some(0),
+            settlement_tx_hash: Some([1; 32].into()),
+            ..header(&certificate, CertificateStatus::Settled)
+        },
+    );
+    assert_conforms(
+        "CertificateHeader",
+        &CertificateHeader {
+            epoch_number: Some(1),
+            certificate_index: Some(0),
+            ..header(
+                &certificate,
+                CertificateStatus::InError {
+                    error: CertificateStatusError::InternalError("failure".into()),
+                },
+            )
+        },
+    );
+}
+
+#[test]
+fn epoch_configuration_conforms_to_schema() {
+    assert_conforms(
+        "EpochConfiguration",
+        &EpochConfiguration {
+            genesis_block: 1,
+            epoch_duration: 10,
+        },
+    );
+}
+
+#[test]
+fn node_info_conforms_to_schema() {
+    let config = TestContext::get_default_config();
+    let node_info = NodeInfo::from_config(&config);
+    assert_conforms("NodeInfo", &node_info);
+
+    assert_conforms(
+        "NodeInfo",
+        &NodeInfo {
+            vkey: Some("0x01".to_string()),
+            settlement_signer: Some(Address::new([1; 20])),
+            ..node_info
+        },
+    );
+}
@@ -1,14 +1,22 @@
 use std::{net::IpAddr, sync::Arc};
 
-use agglayer_config::Config;
-use agglayer_types::{Certificate, CertificateId};
-use ethers::providers;
-use jsonrpsee::{core::client::ClientT, http_client::HttpClientBuilder, rpc_params};
+use agglayer_config::{certificate_orchestrator::PausedNetworkPolicy, Config};
+use agglayer_storage::stores::{
+    MetadataWriter as _, PendingCertificateReader as _, StateReader as _, StateWriter as _,
+};
+use agglayer_types::{Certificate, CertificateId, CertificateStatus, CertificateStatusError};
+use ethers::{providers, types::H160};
+use jsonrpsee::{core::client::ClientT, http_client::HttpClientBuilder, rpc_params, MethodsError};
+use rstest::*;
 
-use super::next_available_addr;
+use super::{dummy_clock_ref, next_available_addr, raw_rpc, test_signer};
 use crate::{
     kernel::Kernel,
-    rpc::{tests::DummyStore, AgglayerImpl},
+    rpc::{
+        error::code,
+        tests::{DummyStore, RawRpcContext, TestContext},
+        AgglayerImpl, AgglayerServer,
+    },
 };
 
 #[test_log::test(tokio::test)]
@@ -23,6 +31,7 @@ async fn send_certificate_method_can_be_called() {
         config.rpc.host = ip;
     }
     config.rpc.port = addr.port();
+    config.proof_signers.insert(1, test_signer());
 
     let config = Arc::new(config);
 
@@ -37,7 +46,10 @@ async fn send_certificate_method_can_be_called() {
         Arc::new(DummyStore {}),
         Arc::new(DummyStore {}),
         Arc::new(DummyStore {}),
+        Arc::new(DummyStore {}),
         config.clone(),
+        dummy_clock_ref(),
+        tokio::sync::broadcast::channel(1).0,
     )
     .start()
     .await
@@ -79,7 +91,10 @@ async fn send_certificate_method_can_be_called_and_fail() {
         Arc::new(DummyStore {}),
         Arc::new(DummyStore {}),
         Arc::new(DummyStore {}),
+        Arc::new(DummyStore {}),
         config.clone(),
+        dummy_clock_ref(),
+        tokio::sync::broadcast::channel(1).0,
     )
     .start()
     .await
@@ -99,3 +114,218 @@ async fn send_certificate_method_can_be_called_and_fail() {
 
     assert!(res.is_err());
 }
+
+#[test_log::test(tokio::test)]
+async fn send_certificate_rejects_untrusted_signer() {
+    let mut config = TestContext::get_default_config();
+    config.proof_signers.insert(1, H160::repeat_byte(0x11));
+
+    let raw_rpc = TestContext::new_raw_rpc_with_config(config).await;
+    let pending_store = raw_rpc.rpc.pending_store.clone();
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let error = rpc
+        .call::<_, CertificateId>(
+            "interop_sendCertificate",
+            rpc_params![Certificate::new_for_test(1.into(), 0)],
+        )
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(error, MethodsError::JsonRpc(obj) if obj.code() == code::INVALID_CERTIFICATE_SIGNER)
+    );
+    assert!(pending_store
+        .get_certificate(1.into(), 0)
+        .unwrap()
+        .is_none());
+}
+
+#[test_log::test(tokio::test)]
+async fn send_certificate_rejects_paused_network() {
+    let mut config = TestContext::get_default_config();
+    config.certificate_orchestrator.paused_network_policy = PausedNetworkPolicy::Reject;
+
+    let raw_rpc = TestContext::new_raw_rpc_with_config(config).await;
+    raw_rpc
+        .rpc
+        .state
+        .set_network_paused(1.into(), true)
+        .unwrap();
+    let pending_store = raw_rpc.rpc.pending_store.clone();
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let error = rpc
+        .call::<_, CertificateId>(
+            "interop_sendCertificate",
+            rpc_params![Certificate::new_for_test(1.into(), 0)],
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, MethodsError::JsonRpc(obj) if obj.code() == code::NETWORK_PAUSED));
+    assert!(pending_store
+        .get_certificate(1.into(), 0)
+        .unwrap()
+        .is_none());
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn send_certificate_rejects_unexpected_height(#[future] raw_rpc: RawRpcContext) {
+    let pending_store = raw_rpc.rpc.pending_store.clone();
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let error = rpc
+        .call::<_, CertificateId>(
+            "interop_sendCertificate",
+            rpc_params![Certificate::new_for_test(1.into(), 1)],
+        )
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(error, MethodsError::JsonRpc(obj) if obj.code() == code::UNEXPECTED_CERTIFICATE_HEIGHT)
+    );
+    assert!(pending_store
+        .get_certificate(1.into(), 1)
+        .unwrap()
+        .is_none());
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn send_certificate_checks_prev_local_exit_root(#[future] raw_rpc: RawRpcContext) {
+    let settled = Certificate::new_for_test(1.into(), 0);
+    let state = raw_rpc.rpc.state.clone();
+    state
+        .insert_certificate_header(&settled, CertificateStatus::Settled)
+        .unwrap();
+    state
+        .set_latest_settled_certificate_for_network(&1.into(), &0, &settled.hash(), &0, &0)
+        .unwrap();
+
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    // The settled certificate produced the local exit root `[1; 32]`.
+    let error = rpc
+        .call::<_, CertificateId>(
+            "interop_sendCertificate",
+            rpc_params![Certificate::new_for_test(1.into(), 1)],
+        )
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(error, MethodsError::JsonRpc(obj) if obj.code() == code::PREV_LOCAL_EXIT_ROOT_MISMATCH)
+    );
+
+    let mut certificate = Certificate::new_for_test(1.into(), 1);
+    certificate.prev_local_exit_root = settled.new_local_exit_root;
+
+    let certificate_id: CertificateId = rpc
+        .call("interop_sendCertificate", rpc_params![certificate.clone()])
+        .await
+        .unwrap();
+
+    assert_eq!(certificate_id, certificate.hash());
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn send_certificate_rejects_conflicting_certificate(#[future] mut raw_rpc: RawRpcContext) {
+    let pending_store = raw_rpc.rpc.pending_store.clone();
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let certificate = Certificate::new_for_test(1.into(), 0);
+    let certificate_id: CertificateId = rpc
+        .call("interop_sendCertificate", rpc_params![certificate.clone()])
+        .await
+        .unwrap();
+    assert!(raw_rpc.certificate_receiver.try_recv().is_ok());
+
+    // Sending the same certificate again is a no-op.
+    let resent_id: CertificateId = rpc
+        .call("interop_sendCertificate", rpc_params![certificate])
+        .await
+        .unwrap();
+    assert_eq!(resent_id, certificate_id);
+    assert!(raw_rpc.certificate_receiver.try_recv().is_err());
+
+    let mut conflicting = Certificate::new_for_test(1.into(), 0);
+    conflicting.prev_local_exit_root = [2; 32];
+
+    let error = rpc
+        .call::<_, CertificateId>("interop_sendCertificate", rpc_params![conflicting])
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(error, MethodsError::JsonRpc(obj) if obj.code() == code::CONFLICTING_CERTIFICATE)
+    );
+    assert_eq!(
+        pending_store
+            .get_certificate(1.into(), 0)
+            .unwrap()
+            .map(|certificate| certificate.hash()),
+        Some(certificate_id)
+    );
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+async fn send_certificate_supersedes_certificate_in_error(#[future] mut raw_rpc: RawRpcContext) {
+    let state = raw_rpc.rpc.state.clone();
+    let pending_store = raw_rpc.rpc.pending_store.clone();
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let certificate = Certificate::new_for_test(1.into(), 0);
+    let certificate_id: CertificateId = rpc
+        .call("interop_sendCertificate", rpc_params![certificate])
+        .await
+        .unwrap();
+    assert!(raw_rpc.certificate_receiver.try_recv().is_ok());
+
+    state
+        .update_certificate_header_status(
+            &certificate_id,
+            &CertificateStatus::InError {
+                error: CertificateStatusError::InternalError("failure".into()),
+            },
+        )
+        .unwrap();
+
+    let mut replacement = Certificate::new_for_test(1.into(), 0);
+    replacement.prev_local_exit_root = [2; 32];
+    let replacement_id: CertificateId = rpc
+        .call("interop_sendCertificate", rpc_params![replacement])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        raw_rpc.certificate_receiver.try_recv().unwrap(),
+        (1.into(), 0, replacement_id)
+    );
+    assert_eq!(
+        pending_store
+            .get_certificate(1.into(), 0)
+            .unwrap()
+            .map(|certificate| certificate.hash()),
+        Some(replacement_id)
+    );
+
+    let header = state
+        .get_certificate_header(&certificate_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        header.status,
+        CertificateStatus::InError {
+            error: CertificateStatusError::Superseded(replacement_id)
+        }
+    );
+}
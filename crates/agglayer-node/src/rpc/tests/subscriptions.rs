@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use agglayer_clock::Event;
+use agglayer_storage::stores::StateWriter as _;
+use agglayer_types::{Certificate, CertificateHeader, CertificateStatus};
+use jsonrpsee::rpc_params;
+use rstest::*;
+
+use super::raw_rpc;
+use crate::rpc::{tests::RawRpcContext, AgglayerServer, EpochEvent};
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+#[timeout(Duration::from_secs(5))]
+async fn subscribe_certificate_status_by_certificate_id(#[future] raw_rpc: RawRpcContext) {
+    let state = raw_rpc.rpc.state.clone();
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let certificate = Certificate::new_for_test(1.into(), 0);
+    let other_certificate = Certificate::new_for_test(1.into(), 1);
+    let certificate_id = certificate.hash();
+
+    let mut subscription = rpc
+        .subscribe_unbounded(
+            "interop_subscribeCertificateStatus",
+            rpc_params![certificate_id],
+        )
+        .await
+        .unwrap();
+
+    state
+        .insert_certificate_header(&other_certificate, CertificateStatus::Pending)
+        .unwrap();
+    state
+        .insert_certificate_header(&certificate, CertificateStatus::Pending)
+        .unwrap();
+    state
+        .update_certificate_header_status(&certificate_id, &CertificateStatus::Proven)
+        .unwrap();
+
+    let (header, _) = subscription
+        .next::<CertificateHeader>()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(header.certificate_id, certificate_id);
+    assert_eq!(header.status, CertificateStatus::Pending);
+
+    let (header, _) = subscription
+        .next::<CertificateHeader>()
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(header.certificate_id, certificate_id);
+    assert_eq!(header.status, CertificateStatus::Proven);
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+#[timeout(Duration::from_secs(5))]
+async fn subscribe_certificate_status_by_network_id(#[future] raw_rpc: RawRpcContext) {
+    let state = raw_rpc.rpc.state.clone();
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let other_network_certificate = Certificate::new_for_test(2.into(), 0);
+    let first = Certificate::new_for_test(1.into(), 0);
+    let second = Certificate::new_for_test(1.into(), 1);
+
+    let mut subscription = rpc
+        .subscribe_unbounded("interop_subscribeCertificateStatus", rpc_params![1])
+        .await
+        .unwrap();
+
+    for certificate in [&other_network_certificate, &first, &second] {
+        state
+            .insert_certificate_header(certificate, CertificateStatus::Pending)
+            .unwrap();
+    }
+
+    for expected in [&first, &second] {
+        let (header, _) = subscription
+            .next::<CertificateHeader>()
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(header.certificate_id, expected.hash());
+        assert_eq!(header.network_id, 1.into());
+    }
+}
+
+#[rstest]
+#[awt]
+#[test_log::test(tokio::test)]
+#[timeout(Duration::from_secs(5))]
+async fn subscribe_epochs(#[future] raw_rpc: RawRpcContext) {
+    let clock_sender = raw_rpc.clock_sender.clone();
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let mut subscription = rpc
+        .subscribe_unbounded("interop_subscribeEpochs", rpc_params![])
+        .await
+        .unwrap();
+
+    clock_sender.send(Event::EpochEnded(0)).unwrap();
+    clock_sender.send(Event::L1Reorg(12)).unwrap();
+    clock_sender.send(Event::EpochEnded(1)).unwrap();
+
+    for expected in [
+        EpochEvent::EpochEnded(0),
+        EpochEvent::L1Reorg(12),
+        EpochEvent::EpochEnded(1),
+    ] {
+        let (event, _) = subscription.next::<EpochEvent>().await.unwrap().unwrap();
+
+        assert_eq!(event, expected);
+    }
+}
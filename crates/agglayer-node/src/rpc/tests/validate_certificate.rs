@@ -0,0 +1,103 @@
+use agglayer_config::Config;
+use agglayer_contracts::polygon_zkevm_global_exit_root_v2::L1InfoRootMapReturn;
+use agglayer_storage::stores::StateReader as _;
+use agglayer_types::Certificate;
+use ethers::{abi::AbiEncode as _, providers::MockResponse, types::H160};
+use jsonrpsee::{rpc_params, types::error::INTERNAL_ERROR_CODE, MethodsError};
+use pessimistic_proof::PessimisticProofOutput;
+use pessimistic_proof_test_suite::sample_data::load_certificate;
+
+use super::TestContext;
+use crate::rpc::{error::code, AgglayerServer};
+
+/// Build a config trusting the signer of the given certificate.
+fn config_trusting(certificate: &Certificate) -> Config {
+    let signer = certificate.signer().unwrap();
+
+    let mut config = TestContext::get_default_config();
+    config
+        .proof_signers
+        .insert(*certificate.network_id, H160::from_slice(signer.as_slice()));
+
+    config
+}
+
+#[test_log::test(tokio::test)]
+async fn validate_certificate_without_storing_it() {
+    let certificate = load_certificate("n15-cert_h0.json");
+    let network_id = certificate.network_id;
+    let l1_info_root = certificate.l1_info_root().unwrap().unwrap_or_default();
+
+    let raw_rpc = TestContext::new_raw_rpc_with_config(config_trusting(&certificate)).await;
+    raw_rpc
+        .l1_mock
+        .push_response(MockResponse::Value(serde_json::Value::String(
+            L1InfoRootMapReturn {
+                l_1_info_root: l1_info_root,
+            }
+            .encode_hex(),
+        )));
+
+    let state = raw_rpc.rpc.state.clone();
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let output: PessimisticProofOutput = rpc
+        .call(
+            "interop_validateCertificate",
+            rpc_params![certificate.clone()],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(output.origin_network, network_id);
+    assert_eq!(output.l1_info_root, l1_info_root);
+    assert_eq!(output.new_local_exit_root, certificate.new_local_exit_root);
+
+    // Nothing has been persisted.
+    assert!(state
+        .read_local_network_state(network_id)
+        .unwrap()
+        .is_none());
+}
+
+#[test_log::test(tokio::test)]
+async fn validate_certificate_with_untrusted_signer() {
+    let certificate = load_certificate("n15-cert_h0.json");
+
+    let mut config = TestContext::get_default_config();
+    config
+        .proof_signers
+        .insert(*certificate.network_id, H160::repeat_byte(0x11));
+
+    let raw_rpc = TestContext::new_raw_rpc_with_config(config).await;
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let error = rpc
+        .call::<_, PessimisticProofOutput>("interop_validateCertificate", rpc_params![certificate])
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        MethodsError::JsonRpc(obj) if obj.code() == code::INVALID_CERTIFICATE_SIGNER
+    ));
+}
+
+#[test_log::test(tokio::test)]
+async fn validate_certificate_when_the_trusted_sequencer_lookup_fails() {
+    let certificate = load_certificate("n15-cert_h0.json");
+
+    // No signer is configured for the network and the L1 has no answer.
+    let raw_rpc = TestContext::new_raw_rpc_with_config(TestContext::get_default_config()).await;
+    let rpc = raw_rpc.rpc.into_rpc();
+
+    let error = rpc
+        .call::<_, PessimisticProofOutput>("interop_validateCertificate", rpc_params![certificate])
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        MethodsError::JsonRpc(obj) if obj.code() == INTERNAL_ERROR_CODE
+    ));
+}
@@ -3,7 +3,7 @@
 //! Systems that wish to submit proofs to the agglayer must produce a
 //! [`SignedTx`] conforming to the type definitions specified herein.
 use ethers::{prelude::*, utils::keccak256};
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_with::{serde_as, DisplayFromStr};
 use thiserror::Error;
 
@@ -14,11 +14,11 @@ pub(crate) const PROOF_LENGTH: usize = 24;
 ///
 /// This is a fixed-size array of fixed-size arrays, where each inner array is a
 /// 32-byte hash.
-#[derive(Debug)]
-pub(crate) struct Proof([[u8; HASH_LENGTH]; PROOF_LENGTH]);
+#[derive(Debug, Clone)]
+pub struct Proof([[u8; HASH_LENGTH]; PROOF_LENGTH]);
 
 #[derive(Error, Debug)]
-pub(crate) enum ProofEncodingError {
+pub enum ProofEncodingError {
     #[error("invalid proof length: expected {expected}, got {got}")]
     InvalidLength { expected: usize, got: usize },
     #[error("invalid hash at index {index}")]
@@ -27,7 +27,7 @@ pub(crate) enum ProofEncodingError {
 
 impl Proof {
     /// Convert the proof into a byte array.
-    pub(crate) fn as_bytes(&self) -> Vec<u8> {
+    pub fn as_bytes(&self) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(HASH_LENGTH * PROOF_LENGTH);
         for hash in &self.0 {
             bytes.extend_from_slice(&hash[..]);
@@ -41,7 +41,7 @@ impl Proof {
     }
 
     /// Convert a byte array into a proof.
-    pub(crate) fn try_from_slice(slice: &[u8]) -> Result<Self, ProofEncodingError> {
+    pub fn try_from_slice(slice: &[u8]) -> Result<Self, ProofEncodingError> {
         if slice.len() != HASH_LENGTH * PROOF_LENGTH {
             return Err(ProofEncodingError::InvalidLength {
                 expected: HASH_LENGTH * PROOF_LENGTH,
@@ -60,6 +60,15 @@ impl Proof {
     }
 }
 
+impl Serialize for Proof {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Bytes::from(self.as_bytes()).serialize(serializer)
+    }
+}
+
 impl<'de> Deserialize<'de> for Proof {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -70,24 +79,24 @@ impl<'de> Deserialize<'de> for Proof {
 }
 
 /// The zero-knowledge proof.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct Zkp {
-    pub(crate) new_state_root: H256,
-    pub(crate) new_local_exit_root: H256,
-    pub(crate) proof: Proof,
+pub struct Zkp {
+    pub new_state_root: H256,
+    pub new_local_exit_root: H256,
+    pub proof: Proof,
 }
 
 /// Proof metadata along with its zero-knowledge proof.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct ProofManifest {
+pub struct ProofManifest {
     #[serde(rename = "RollupID")]
-    pub(crate) rollup_id: u32,
-    pub(crate) last_verified_batch: U64,
-    pub(crate) new_verified_batch: U64,
+    pub rollup_id: u32,
+    pub last_verified_batch: U64,
+    pub new_verified_batch: U64,
     #[serde(rename = "ZKP")]
-    pub(crate) zkp: Zkp,
+    pub zkp: Zkp,
 }
 
 /// A [`SignedTx`] is the core input type of the agglayer.
@@ -95,11 +104,11 @@ pub(crate) struct ProofManifest {
 /// Systems that wish to submit proofs to the agglayer must produce a
 /// [`SignedTx`] conforming to the type definitions specified herein.
 #[serde_as]
-#[derive(Debug, Deserialize)]
-pub(crate) struct SignedTx {
-    pub(crate) tx: ProofManifest,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedTx {
+    pub tx: ProofManifest,
     #[serde_as(as = "DisplayFromStr")]
-    pub(crate) signature: Signature,
+    pub signature: Signature,
 }
 
 impl SignedTx {
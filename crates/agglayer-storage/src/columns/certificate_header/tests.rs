@@ -26,6 +26,7 @@ fn can_parse_value() {
         new_local_exit_root: [5; 32].into(),
         status: agglayer_types::CertificateStatus::Pending,
         metadata: [6; 32].into(),
+        settlement_tx_hash: None,
     };
 
     let encoded = value.encode().expect("Unable to encode value");
@@ -79,3 +80,74 @@ fn can_parse_value() {
     // end
     assert!(encoded[162..].is_empty());
 }
+
+#[test]
+fn settlement_tx_hash_is_not_encoded() {
+    let value = Value {
+        network_id: 1.into(),
+        certificate_id: [1; 32].into(),
+        height: 2,
+        epoch_number: Some(3),
+        certificate_index: Some(4),
+        prev_local_exit_root: [4; 32].into(),
+        new_local_exit_root: [5; 32].into(),
+        status: agglayer_types::CertificateStatus::Candidate,
+        metadata: [6; 32].into(),
+        settlement_tx_hash: Some([7; 32].into()),
+    };
+
+    let encoded = value.encode().expect("Unable to encode value");
+
+    assert_eq!(encoded.len(), 162);
+
+    let decoded = Value::decode(&encoded[..]).expect("Unable to decode value");
+
+    assert_eq!(
+        decoded,
+        Value {
+            settlement_tx_hash: None,
+            ..value
+        }
+    );
+}
+
+#[test]
+fn can_decode_a_header_written_before_the_settlement_tx_hash() {
+    let mut encoded = Vec::new();
+    // network_id
+    encoded.extend([0, 0, 0, 1]);
+    // height
+    encoded.extend([0, 0, 0, 0, 0, 0, 0, 2]);
+    // epoch_number
+    encoded.extend([1, 0, 0, 0, 0, 0, 0, 0, 3]);
+    // certificate_index
+    encoded.extend([1, 0, 0, 0, 0, 0, 0, 0, 4]);
+    // CertificateId
+    encoded.extend([1; 32]);
+    // prev_local_exit_root
+    encoded.extend([4; 32]);
+    // new_local_exit_root
+    encoded.extend([5; 32]);
+    // metadata
+    encoded.extend([6; 32]);
+    // certificate status
+    encoded.extend([0, 0, 0, 0]);
+
+    let value = Value::decode(&encoded[..]).expect("Unable to decode value");
+
+    assert_eq!(
+        value,
+        Value {
+            network_id: 1.into(),
+            certificate_id: [1; 32].into(),
+            height: 2,
+            epoch_number: Some(3),
+            certificate_index: Some(4),
+            prev_local_exit_root: [4; 32].into(),
+            new_local_exit_root: [5; 32].into(),
+            status: agglayer_types::CertificateStatus::Pending,
+            metadata: [6; 32].into(),
+            settlement_tx_hash: None,
+        }
+    );
+}
@@ -0,0 +1,21 @@
+use agglayer_types::{CertificateIndex, Hash};
+
+use crate::columns::PER_EPOCH_SETTLEMENT_TX_HASHES_PER_CERTIFICATE_INDEX_CF;
+
+/// Column family for the settlement transaction hashes per certificate index
+/// in an epoch, the replacements of the settlement transaction included.
+///
+/// ## Column definition
+///
+/// | key                | value       |
+/// | --                 | --          |
+/// | `CertificateIndex` | `Vec<Hash>` |
+pub struct SettlementTxHashesPerCertificateIndexColumn;
+
+impl crate::columns::ColumnSchema for SettlementTxHashesPerCertificateIndexColumn {
+    type Key = CertificateIndex;
+    type Value = Vec<Hash>;
+
+    const COLUMN_FAMILY_NAME: &'static str =
+        PER_EPOCH_SETTLEMENT_TX_HASHES_PER_CERTIFICATE_INDEX_CF;
+}
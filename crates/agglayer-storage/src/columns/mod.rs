@@ -14,6 +14,8 @@ pub const CERTIFICATE_PER_NETWORK_CF: &str = "certificate_per_network_cf";
 pub const NULLIFIER_TREE_PER_NETWORK_CF: &str = "nullifier_tree_per_network_cf";
 pub const BALANCE_TREE_PER_NETWORK_CF: &str = "balance_tree_per_network_cf";
 pub const LOCAL_EXIT_TREE_PER_NETWORK_CF: &str = "local_exit_tree_per_network_cf";
+pub const PREVIOUS_LOCAL_NETWORK_STATE_PER_NETWORK_CF: &str =
+    "previous_local_network_state_per_network_cf";
 
 // Metadata CFs
 pub const CERTIFICATE_HEADER_CF: &str = "certificate_header_cf";
@@ -22,6 +24,7 @@ pub const LATEST_PROVEN_CERTIFICATE_PER_NETWORK_CF: &str =
 pub const LATEST_SETTLED_CERTIFICATE_PER_NETWORK_CF: &str =
     "latest_settled_certificate_per_network_cf";
 pub const METADATA_CF: &str = "metadata_cf";
+pub const SETTLEMENT_TX_HASH_PER_CERTIFICATE_CF: &str = "settlement_tx_hash_per_certificate_cf";
 
 // epochs related CFs
 pub const PER_EPOCH_CERTIFICATES_CF: &str = "per_epoch_certificates_cf";
@@ -31,9 +34,12 @@ pub const PER_EPOCH_END_CHECKPOINT_CF: &str = "per_epoch_end_checkpoint_cf";
 pub const PER_EPOCH_START_CHECKPOINT_CF: &str = "per_epoch_start_checkpoint_cf";
 pub const PER_EPOCH_TRANSACTION_HASH_PER_CERTIFICATE_INDEX: &str =
     "per_epoch_transaction_hash_per_certificate_index";
+pub const PER_EPOCH_SETTLEMENT_TX_HASHES_PER_CERTIFICATE_INDEX_CF: &str =
+    "per_epoch_settlement_tx_hashes_per_certificate_index_cf";
 
 // Pending related CFs
 pub const PENDING_QUEUE_CF: &str = "pending_queue_cf";
+pub const PENDING_SINCE_CF: &str = "pending_since_cf";
 pub const PROOF_PER_CERTIFICATE_CF: &str = "proof_per_certificate_cf";
 
 // debug CFs
@@ -61,9 +67,11 @@ pub(crate) mod balance_tree_per_network;
 pub(crate) mod certificate_per_network;
 pub(crate) mod local_exit_tree_per_network;
 pub(crate) mod nullifier_tree_per_network;
+pub(crate) mod previous_local_network_state_per_network;
 
 // Pending
 pub(crate) mod pending_queue;
+pub(crate) mod pending_since;
 pub(crate) mod proof_per_certificate;
 
 // Metadata
@@ -71,6 +79,7 @@ pub(crate) mod certificate_header;
 pub mod latest_proven_certificate_per_network;
 pub mod latest_settled_certificate_per_network;
 pub(crate) mod metadata;
+pub(crate) mod settlement_tx_hash_per_certificate;
 
 // Debug
 pub(crate) mod debug_certificates;
@@ -81,6 +90,7 @@ pub mod epochs {
     pub mod end_checkpoint;
     pub(crate) mod metadata;
     pub(crate) mod proofs;
+    pub(crate) mod settlement_tx_hashes_per_certificate_index;
     pub(crate) mod start_checkpoint;
     mod transaction_hash_per_certificate_index;
 }
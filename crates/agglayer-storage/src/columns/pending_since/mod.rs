@@ -0,0 +1,22 @@
+use agglayer_types::EpochNumber;
+
+use super::{pending_queue::PendingQueueKey, Codec, ColumnSchema, PENDING_SINCE_CF};
+
+/// Column family for the epoch at which each certificate of the pending queue
+/// entered it.
+///
+/// ## Column definition
+///
+/// | key                     | value         |
+/// | --                      | --            |
+/// | (`NetworkId`, `Height`) | `EpochNumber` |
+pub(crate) struct PendingSinceColumn;
+
+impl Codec for EpochNumber {}
+
+impl ColumnSchema for PendingSinceColumn {
+    type Key = PendingQueueKey;
+    type Value = EpochNumber;
+
+    const COLUMN_FAMILY_NAME: &'static str = PENDING_SINCE_CF;
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    certificate_per_network::Key, Codec, ColumnSchema, PREVIOUS_LOCAL_NETWORK_STATE_PER_NETWORK_CF,
+};
+use crate::types::SmtValue;
+
+/// Column family for the local network state replaced by the settlement of
+/// the certificate at a given height, used to roll the state back when that
+/// settlement is reverted.
+///
+/// The nodes of the trees are never removed, the roots are enough to restore
+/// a previous state.
+///
+/// ## Column definition
+///
+/// | key                     | value   |
+/// | --                      | --      |
+/// | (`NetworkId`, `Height`) | `Value` |
+pub struct PreviousLocalNetworkStatePerNetworkColumn;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Value {
+    /// The network had no local network state yet.
+    Empty,
+    State {
+        leaf_count: u32,
+        frontier: [[u8; 32]; 32],
+        balance_tree_root: SmtValue,
+        nullifier_tree_root: SmtValue,
+    },
+}
+
+impl Codec for Value {}
+
+impl ColumnSchema for PreviousLocalNetworkStatePerNetworkColumn {
+    type Key = Key;
+    type Value = Value;
+
+    const COLUMN_FAMILY_NAME: &'static str = PREVIOUS_LOCAL_NETWORK_STATE_PER_NETWORK_CF;
+}
@@ -0,0 +1,20 @@
+use agglayer_types::{CertificateId, Hash};
+
+use super::{ColumnSchema, SETTLEMENT_TX_HASH_PER_CERTIFICATE_CF};
+
+/// Column family for the hash of the latest L1 transaction sent to settle a
+/// certificate, kept apart from the certificate header.
+///
+/// ## Column definition
+///
+/// | key             | value  |
+/// | --              | --     |
+/// | `CertificateId` | `Hash` |
+pub struct SettlementTxHashPerCertificateColumn;
+
+impl ColumnSchema for SettlementTxHashPerCertificateColumn {
+    type Key = CertificateId;
+    type Value = Hash;
+
+    const COLUMN_FAMILY_NAME: &'static str = SETTLEMENT_TX_HASH_PER_CERTIFICATE_CF;
+}
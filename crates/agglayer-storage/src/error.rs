@@ -45,6 +45,9 @@ pub enum Error {
 
     #[error("Smt node not found")]
     SmtNodeNotFound,
+
+    #[error("No local network state to rewind to for network {0} at height {1}")]
+    NoPreviousLocalNetworkState(NetworkId, Height),
 }
 
 #[derive(Debug, thiserror::Error)]
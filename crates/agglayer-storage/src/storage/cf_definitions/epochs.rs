@@ -1,10 +1,11 @@
 use agglayer_types::NetworkId;
 use rocksdb::ColumnFamilyDescriptor;
 
-pub const CFS: [&str; 3] = [
+pub const CFS: [&str; 4] = [
     crate::columns::PER_EPOCH_CERTIFICATES_CF,
     crate::columns::PER_EPOCH_METADATA_CF,
     crate::columns::PER_EPOCH_PROOFS_CF,
+    crate::columns::PER_EPOCH_SETTLEMENT_TX_HASHES_PER_CERTIFICATE_INDEX_CF,
 ];
 
 const CHECKPOINTS: [&str; 2] = [
@@ -14,7 +15,21 @@ const CHECKPOINTS: [&str; 2] = [
 
 /// Definitions for the column families in the epochs storage.
 pub fn epochs_db_cf_definitions() -> Vec<ColumnFamilyDescriptor> {
-    let mut vec = super::default_db_cf_definitions(&CFS);
+    cf_definitions(|_| true)
+}
+
+/// Definitions for the column families of an existing epochs storage, among
+/// the `existing` ones.
+///
+/// Epochs created before a column family was added lack it, and a read-only
+/// instance can't create it.
+pub fn existing_epochs_db_cf_definitions(existing: &[String]) -> Vec<ColumnFamilyDescriptor> {
+    cf_definitions(|cf| existing.iter().any(|name| name == cf))
+}
+
+fn cf_definitions(keep: impl Fn(&str) -> bool) -> Vec<ColumnFamilyDescriptor> {
+    let cfs: Vec<_> = CFS.into_iter().filter(|cf| keep(cf)).collect();
+    let mut vec = super::default_db_cf_definitions(&cfs);
 
     let mut cfg = rocksdb::Options::default();
 
@@ -24,8 +39,8 @@ pub fn epochs_db_cf_definitions() -> Vec<ColumnFamilyDescriptor> {
         NetworkId::BITS,
     ));
 
-    for cf in &CHECKPOINTS {
-        vec.push(ColumnFamilyDescriptor::new(*cf, cfg.clone()));
+    for cf in CHECKPOINTS.into_iter().filter(|cf| keep(cf)) {
+        vec.push(ColumnFamilyDescriptor::new(cf, cfg.clone()));
     }
 
     vec
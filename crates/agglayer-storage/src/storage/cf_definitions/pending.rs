@@ -1,8 +1,9 @@
 use rocksdb::ColumnFamilyDescriptor;
 
-pub const CFS: [&str; 3] = [
+pub const CFS: [&str; 4] = [
     crate::columns::LATEST_PROVEN_CERTIFICATE_PER_NETWORK_CF,
     crate::columns::PENDING_QUEUE_CF,
+    crate::columns::PENDING_SINCE_CF,
     crate::columns::PROOF_PER_CERTIFICATE_CF,
 ];
 
@@ -1,13 +1,15 @@
 use rocksdb::ColumnFamilyDescriptor;
 
-pub const CFS: [&str; 7] = [
+pub const CFS: [&str; 9] = [
     crate::columns::CERTIFICATE_HEADER_CF,
     crate::columns::CERTIFICATE_PER_NETWORK_CF,
     crate::columns::LATEST_SETTLED_CERTIFICATE_PER_NETWORK_CF,
     crate::columns::METADATA_CF,
+    crate::columns::SETTLEMENT_TX_HASH_PER_CERTIFICATE_CF,
     crate::columns::LOCAL_EXIT_TREE_PER_NETWORK_CF,
     crate::columns::BALANCE_TREE_PER_NETWORK_CF,
     crate::columns::NULLIFIER_TREE_PER_NETWORK_CF,
+    crate::columns::PREVIOUS_LOCAL_NETWORK_STATE_PER_NETWORK_CF,
 ];
 
 /// Definitions for the column families in the state storage.
@@ -17,7 +17,7 @@ pub(crate) mod cf_definitions;
 pub(crate) mod iterators;
 
 pub use cf_definitions::debug::debug_db_cf_definitions;
-pub use cf_definitions::epochs::epochs_db_cf_definitions;
+pub use cf_definitions::epochs::{epochs_db_cf_definitions, existing_epochs_db_cf_definitions};
 pub use cf_definitions::pending::pending_db_cf_definitions;
 pub use cf_definitions::state::state_db_cf_definitions;
 
@@ -39,6 +39,23 @@ impl DB {
         })
     }
 
+    /// Open an existing RocksDB instance at the given path in read-only mode.
+    ///
+    /// The instance can be opened while another one holds it in read-write
+    /// mode, in which case the writes done after the opening aren't visible.
+    pub fn open_cf_readonly(path: &Path, cfs: Vec<ColumnFamilyDescriptor>) -> Result<DB, Error> {
+        let options = Options::default();
+
+        Ok(DB {
+            rocksdb: rocksdb::DB::open_cf_descriptors_read_only(&options, path, cfs, false)?,
+        })
+    }
+
+    /// List the column families of the RocksDB instance at the given path.
+    pub fn list_cf(path: &Path) -> Result<Vec<String>, Error> {
+        Ok(rocksdb::DB::list_cf(&Options::default(), path)?)
+    }
+
     /// Try to get the value for the given key.
     pub fn get<C: ColumnSchema>(&self, key: &C::Key) -> Result<Option<C::Value>, Error> {
         let key = key.encode()?;
@@ -96,6 +113,14 @@ impl DB {
         Ok(())
     }
 
+    /// Flush and sync the write-ahead log to disk, so that the writes done so
+    /// far survive the node being stopped.
+    pub fn flush_wal(&self) -> Result<(), Error> {
+        self.rocksdb.flush_wal(true)?;
+
+        Ok(())
+    }
+
     pub fn write_batch(&self, batch: WriteBatch) -> Result<(), Error> {
         self.rocksdb.write(batch)?;
 
@@ -125,6 +150,24 @@ impl DB {
         Ok(())
     }
 
+    pub fn multi_delete_batch<'a, C: ColumnSchema + 'a>(
+        &self,
+        keys: impl IntoIterator<Item = &'a C::Key>,
+        batch: &mut WriteBatch,
+    ) -> Result<(), Error> {
+        let cf = self
+            .rocksdb
+            .cf_handle(C::COLUMN_FAMILY_NAME)
+            .ok_or(Error::ColumnFamilyNotFound)?;
+
+        keys.into_iter().try_for_each::<_, Result<_, Error>>(|k| {
+            batch.delete_cf(&cf, k.encode()?);
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+
     pub fn multi_insert<'a, C: ColumnSchema + 'a>(
         &self,
         key_val_pairs: impl IntoIterator<Item = (&'a C::Key, &'a C::Value)>,
@@ -3,14 +3,21 @@ use std::{
     sync::Arc,
 };
 
-use agglayer_types::{Height, NetworkId};
+use agglayer_types::{Certificate, CertificateIndex, EpochNumber, Height, NetworkId, Proof};
 use parking_lot::RwLock;
 
 use super::{
     per_epoch::PerEpochStore, EpochStoreReader, EpochStoreWriter, MetadataWriter,
     PendingCertificateReader, PendingCertificateWriter, StateReader, StateWriter,
 };
-use crate::error::Error;
+use crate::{
+    columns::epochs::{certificates::CertificatePerIndexColumn, proofs::ProofPerIndexColumn},
+    error::Error,
+    storage::{existing_epochs_db_cf_definitions, DB},
+};
+
+#[cfg(test)]
+mod tests;
 
 pub struct EpochsStore<PendingStore, StateStore> {
     config: Arc<agglayer_config::Config>,
@@ -70,9 +77,49 @@ where
     }
 }
 
+impl<PendingStore, StateStore> EpochsStore<PendingStore, StateStore> {
+    /// Open the storage of an epoch in read-only mode, if it exists.
+    fn open_readonly(&self, epoch_number: EpochNumber) -> Result<Option<DB>, Error> {
+        let path = self
+            .config
+            .storage
+            .epochs_db_path
+            .join(format!("{}", epoch_number));
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let existing = DB::list_cf(&path)?;
+
+        DB::open_cf_readonly(&path, existing_epochs_db_cf_definitions(&existing)).map(Some)
+    }
+}
+
 impl<PendingStore, StateStore> EpochStoreReader for EpochsStore<PendingStore, StateStore>
 where
     PendingStore: PendingCertificateReader + PendingCertificateWriter,
     StateStore: StateWriter + MetadataWriter + StateReader,
 {
+    fn get_certificate_in_epoch(
+        &self,
+        epoch_number: EpochNumber,
+        certificate_index: CertificateIndex,
+    ) -> Result<Option<Certificate>, Error> {
+        match self.open_readonly(epoch_number)? {
+            Some(db) => db.get::<CertificatePerIndexColumn>(&certificate_index),
+            None => Ok(None),
+        }
+    }
+
+    fn get_proof_in_epoch(
+        &self,
+        epoch_number: EpochNumber,
+        certificate_index: CertificateIndex,
+    ) -> Result<Option<Proof>, Error> {
+        match self.open_readonly(epoch_number)? {
+            Some(db) => db.get::<ProofPerIndexColumn>(&certificate_index),
+            None => Ok(None),
+        }
+    }
 }
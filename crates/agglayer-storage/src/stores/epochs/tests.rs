@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use agglayer_config::Config;
+use agglayer_types::Certificate;
+
+use crate::{
+    columns::{
+        epochs::certificates::CertificatePerIndexColumn, PER_EPOCH_CERTIFICATES_CF,
+        PER_EPOCH_END_CHECKPOINT_CF, PER_EPOCH_METADATA_CF, PER_EPOCH_PROOFS_CF,
+        PER_EPOCH_START_CHECKPOINT_CF,
+    },
+    storage::{existing_epochs_db_cf_definitions, DB},
+    stores::{
+        epochs::EpochsStore, pending::PendingStore, state::StateStore, EpochStoreReader as _,
+    },
+    tests::TempDBDir,
+};
+
+#[test]
+fn epoch_missing_a_column_family_can_be_read() {
+    let tmp = TempDBDir::new();
+    let config = Arc::new(Config::new(&tmp.path));
+    let pending_store =
+        Arc::new(PendingStore::new_with_path(&config.storage.pending_db_path).unwrap());
+    let state_store = Arc::new(StateStore::new_with_path(&config.storage.state_db_path).unwrap());
+
+    // An epoch created before the settlement tx hashes column family was added.
+    let older_cfs = [
+        PER_EPOCH_CERTIFICATES_CF,
+        PER_EPOCH_METADATA_CF,
+        PER_EPOCH_PROOFS_CF,
+        PER_EPOCH_START_CHECKPOINT_CF,
+        PER_EPOCH_END_CHECKPOINT_CF,
+    ]
+    .map(String::from);
+    let certificate = Certificate::new_for_test(1.into(), 0);
+    {
+        let db = DB::open_cf(
+            &config.storage.epochs_db_path.join("0"),
+            existing_epochs_db_cf_definitions(&older_cfs),
+        )
+        .unwrap();
+        db.put::<CertificatePerIndexColumn>(&0, &certificate)
+            .unwrap();
+    }
+
+    let store = EpochsStore::new(config, 1, pending_store, state_store).unwrap();
+
+    assert_eq!(
+        store
+            .get_certificate_in_epoch(0, 0)
+            .unwrap()
+            .map(|certificate| certificate.hash()),
+        Some(certificate.hash())
+    );
+}
@@ -1,8 +1,8 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use agglayer_types::{
-    Certificate, CertificateHeader, CertificateId, CertificateIndex, Height, LocalNetworkStateData,
-    NetworkId, Proof,
+    Certificate, CertificateHeader, CertificateId, CertificateIndex, EpochNumber, Hash, Height,
+    LocalExitTreeProof, LocalNetworkStateData, NetworkId, Proof,
 };
 
 use crate::{
@@ -18,7 +18,21 @@ pub trait DebugReader: Send + Sync {
         -> Result<Option<Certificate>, Error>;
 }
 
-pub trait EpochStoreReader: Send + Sync {}
+pub trait EpochStoreReader: Send + Sync {
+    /// Get the certificate stored at an index of an epoch.
+    fn get_certificate_in_epoch(
+        &self,
+        epoch_number: EpochNumber,
+        certificate_index: CertificateIndex,
+    ) -> Result<Option<Certificate>, Error>;
+
+    /// Get the proof stored at an index of an epoch.
+    fn get_proof_in_epoch(
+        &self,
+        epoch_number: EpochNumber,
+        certificate_index: CertificateIndex,
+    ) -> Result<Option<Proof>, Error>;
+}
 
 pub trait PendingCertificateReader: Send + Sync {
     fn get_latest_pending_certificate_for_network(
@@ -50,11 +64,32 @@ pub trait PendingCertificateReader: Send + Sync {
         &self,
         network_id: &NetworkId,
     ) -> Result<Option<(NetworkId, Height, CertificateId)>, Error>;
+
+    /// Get the networks having at least one certificate in the pending queue.
+    fn get_networks_with_pending_certificates(&self) -> Result<Vec<NetworkId>, Error>;
+
+    /// Get the certificates of a network in the pending queue, in height
+    /// order.
+    fn get_pending_certificates_for_network(
+        &self,
+        network_id: &NetworkId,
+    ) -> Result<Vec<Certificate>, Error>;
+
+    /// Get the epoch at which the certificate of a network at a height
+    /// entered the pending queue.
+    fn get_pending_since(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> Result<Option<EpochNumber>, Error>;
 }
 
 pub trait MetadataReader: Send + Sync {
     /// Get the latest settled epoch.
     fn get_latest_settled_epoch(&self) -> Result<Option<u64>, Error>;
+
+    /// Get the networks for which the certification is paused.
+    fn get_paused_networks(&self) -> Result<BTreeSet<NetworkId>, Error>;
 }
 
 pub trait StateReader: Send + Sync {
@@ -72,6 +107,15 @@ pub trait StateReader: Send + Sync {
         height: Height,
     ) -> Result<Option<CertificateHeader>, Error>;
 
+    /// Get up to `limit` settled certificate headers of a network, ordered by
+    /// height and starting at `from_height`.
+    fn get_certificate_headers_by_cursor(
+        &self,
+        network_id: NetworkId,
+        from_height: Height,
+        limit: usize,
+    ) -> Result<Vec<CertificateHeader>, Error>;
+
     fn get_current_settled_height(&self) -> Result<Vec<(NetworkId, SettledCertificate)>, Error>;
     fn get_latest_settled_certificate_per_network(
         &self,
@@ -83,6 +127,19 @@ pub trait StateReader: Send + Sync {
         &self,
         network_id: NetworkId,
     ) -> Result<Option<LocalNetworkStateData>, Error>;
+
+    /// Get the inclusion proof of a leaf of the local exit tree of a network
+    /// against the root of the tree with `leaf_count` leaves, defaulting to
+    /// the current leaf count.
+    ///
+    /// Returns `None` if the network is unknown or if the leaf isn't part of
+    /// the tree with `leaf_count` leaves.
+    fn get_local_exit_tree_proof(
+        &self,
+        network_id: NetworkId,
+        leaf_index: u32,
+        leaf_count: Option<u32>,
+    ) -> Result<Option<LocalExitTreeProof>, Error>;
 }
 
 pub trait PerEpochReader: Send + Sync {
@@ -100,6 +157,11 @@ pub trait PerEpochReader: Send + Sync {
         index: CertificateIndex,
     ) -> Result<Option<Certificate>, Error>;
     fn get_proof_at_index(&self, index: CertificateIndex) -> Result<Option<Proof>, Error>;
+    /// Get the transactions sent to settle the certificate at an index.
+    fn get_settlement_tx_hashes(
+        &self,
+        certificate_index: CertificateIndex,
+    ) -> Result<Vec<Hash>, Error>;
     /// Get the height of a network's end checkpoint
     fn get_end_checkpoint_height_per_network(
         &self,
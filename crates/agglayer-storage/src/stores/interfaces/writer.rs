@@ -18,6 +18,12 @@ pub trait PerEpochWriter: Send + Sync {
         height: Height,
     ) -> Result<(EpochNumber, CertificateIndex), Error>;
     fn start_packing(&self) -> Result<(), Error>;
+    /// Record the transactions sent to settle the certificate at an index.
+    fn set_settlement_tx_hashes(
+        &self,
+        certificate_index: CertificateIndex,
+        tx_hashes: &[Hash],
+    ) -> Result<(), Error>;
 }
 
 pub trait EpochStoreWriter: Send + Sync {
@@ -34,6 +40,9 @@ pub trait EpochStoreWriter: Send + Sync {
 pub trait MetadataWriter: Send + Sync {
     /// Set the latest settled epoch.
     fn set_latest_settled_epoch(&self, value: u64) -> Result<(), Error>;
+
+    /// Pause or resume the certification of a network.
+    fn set_network_paused(&self, network_id: NetworkId, paused: bool) -> Result<(), Error>;
 }
 
 pub trait StateWriter: Send + Sync {
@@ -49,6 +58,12 @@ pub trait StateWriter: Send + Sync {
         status: &CertificateStatus,
     ) -> Result<(), Error>;
 
+    fn update_certificate_header_settlement_tx_hash(
+        &self,
+        certificate_id: &CertificateId,
+        tx_hash: &Hash,
+    ) -> Result<(), Error>;
+
     fn assign_certificate_to_epoch(
         &self,
         certificate_id: &CertificateId,
@@ -65,12 +80,24 @@ pub trait StateWriter: Send + Sync {
         certificate_index: &CertificateIndex,
     ) -> Result<(), Error>;
 
+    /// Write the local network state resulting from the settlement of the
+    /// certificate at `height`, keeping the replaced state so that it can be
+    /// restored by [`StateWriter::rewind_local_network_state`].
     fn write_local_network_state(
         &self,
         network_id: &NetworkId,
+        height: &Height,
         new_state: &LocalNetworkStateData,
         new_leaves: &[Hash],
     ) -> Result<(), Error>;
+
+    /// Restore the local network state as it was before the settlement of the
+    /// certificate at `height`.
+    fn rewind_local_network_state(
+        &self,
+        network_id: &NetworkId,
+        height: &Height,
+    ) -> Result<(), Error>;
 }
 
 pub trait PendingCertificateWriter: Send + Sync {
@@ -89,6 +116,15 @@ pub trait PendingCertificateWriter: Send + Sync {
         certificate: &Certificate,
     ) -> Result<(), Error>;
 
+    /// Record the epoch at which the certificate of a network at a height
+    /// entered the pending queue, removed along with the certificate.
+    fn set_pending_since(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+        epoch_number: EpochNumber,
+    ) -> Result<(), Error>;
+
     fn insert_generated_proof(
         &self,
         certificate_id: &CertificateId,
@@ -1,7 +1,7 @@
-use std::{path::Path, sync::Arc};
+use std::{collections::BTreeSet, path::Path, sync::Arc};
 
-use agglayer_types::{Certificate, CertificateId, Height, NetworkId, Proof};
-use rocksdb::{Direction, ReadOptions};
+use agglayer_types::{Certificate, CertificateId, EpochNumber, Height, NetworkId, Proof};
+use rocksdb::{Direction, ReadOptions, WriteBatch};
 
 use super::{PendingCertificateReader, PendingCertificateWriter};
 use crate::{
@@ -10,6 +10,7 @@ use crate::{
             LatestProvenCertificatePerNetworkColumn, ProvenCertificate,
         },
         pending_queue::{PendingQueueColumn, PendingQueueKey},
+        pending_since::PendingSinceColumn,
         proof_per_certificate::ProofPerCertificateColumn,
     },
     error::Error,
@@ -42,8 +43,14 @@ impl PendingCertificateWriter for PendingStore {
         network_id: NetworkId,
         height: Height,
     ) -> Result<(), Error> {
+        let key = PendingQueueKey(network_id, height);
+        let mut batch = WriteBatch::default();
         self.db
-            .delete::<PendingQueueColumn>(&PendingQueueKey(network_id, height))
+            .multi_delete_batch::<PendingQueueColumn>([&key], &mut batch)?;
+        self.db
+            .multi_delete_batch::<PendingSinceColumn>([&key], &mut batch)?;
+
+        self.db.write_batch(batch)
     }
 
     fn insert_pending_certificate(
@@ -56,6 +63,16 @@ impl PendingCertificateWriter for PendingStore {
             .put::<PendingQueueColumn>(&PendingQueueKey(network_id, height), certificate)
     }
 
+    fn set_pending_since(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+        epoch_number: EpochNumber,
+    ) -> Result<(), Error> {
+        self.db
+            .put::<PendingSinceColumn>(&PendingQueueKey(network_id, height), &epoch_number)
+    }
+
     fn insert_generated_proof(
         &self,
         certificate_id: &agglayer_types::CertificateId,
@@ -141,6 +158,47 @@ impl PendingCertificateReader for PendingStore {
             .map(|v| v.map(|ProvenCertificate(id, network, height)| (network, height, id)))
     }
 
+    fn get_networks_with_pending_certificates(&self) -> Result<Vec<NetworkId>, Error> {
+        let networks: BTreeSet<NetworkId> = self
+            .db
+            .keys::<PendingQueueColumn>()?
+            .filter_map(|v| v.ok())
+            .map(|PendingQueueKey(network_id, _height)| network_id)
+            .collect();
+
+        Ok(networks.into_iter().collect())
+    }
+
+    fn get_pending_certificates_for_network(
+        &self,
+        network_id: &NetworkId,
+    ) -> Result<Vec<Certificate>, Error> {
+        let mut iterator = self.db.iter_with_direction::<PendingQueueColumn>(
+            ReadOptions::default(),
+            Direction::Forward,
+        )?;
+        iterator.seek(&PendingQueueKey(*network_id, 0))?;
+
+        iterator
+            .take_while(|v| {
+                v.as_ref()
+                    .map_or(true, |(PendingQueueKey(key_network_id, _), _)| {
+                        key_network_id == network_id
+                    })
+            })
+            .map(|v| v.map(|(_, certificate)| certificate))
+            .collect()
+    }
+
+    fn get_pending_since(
+        &self,
+        network_id: NetworkId,
+        height: Height,
+    ) -> Result<Option<EpochNumber>, Error> {
+        self.db
+            .get::<PendingSinceColumn>(&PendingQueueKey(network_id, height))
+    }
+
     fn multi_get_certificate(
         &self,
         keys: &[(NetworkId, Height)],
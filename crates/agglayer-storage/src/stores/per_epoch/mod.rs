@@ -6,7 +6,7 @@ use std::{
     },
 };
 
-use agglayer_types::{Certificate, CertificateIndex, EpochNumber, Height, NetworkId, Proof};
+use agglayer_types::{Certificate, CertificateIndex, EpochNumber, Hash, Height, NetworkId, Proof};
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use rocksdb::ReadOptions;
 use tracing::{debug, error, warn};
@@ -18,7 +18,9 @@ use super::{
 use crate::{
     columns::epochs::{
         certificates::CertificatePerIndexColumn, end_checkpoint::EndCheckpointColumn,
-        proofs::ProofPerIndexColumn, start_checkpoint::StartCheckpointColumn,
+        proofs::ProofPerIndexColumn,
+        settlement_tx_hashes_per_certificate_index::SettlementTxHashesPerCertificateIndexColumn,
+        start_checkpoint::StartCheckpointColumn,
     },
     error::{CertificateCandidateError, Error},
     storage::{epochs_db_cf_definitions, DB},
@@ -27,12 +29,11 @@ use crate::{
 #[cfg(test)]
 mod tests;
 
-const MAX_CERTIFICATE_PER_EPOCH: u64 = 1;
-
 /// A logical store for an Epoch.
 pub struct PerEpochStore<PendingStore, StateStore> {
     pub epoch_number: Arc<u64>,
     db: Arc<DB>,
+    config: Arc<agglayer_config::Config>,
     pending_store: Arc<PendingStore>,
     state_store: Arc<StateStore>,
     next_certificate_index: AtomicU64,
@@ -131,6 +132,7 @@ impl<PendingStore, StateStore> PerEpochStore<PendingStore, StateStore> {
         Ok(Self {
             epoch_number: Arc::new(epoch_number),
             db,
+            config,
             next_certificate_index,
             pending_store,
             state_store,
@@ -149,6 +151,19 @@ impl<PendingStore, StateStore> PerEpochStore<PendingStore, StateStore> {
     }
 }
 
+impl<PendingStore, StateStore> Drop for PerEpochStore<PendingStore, StateStore> {
+    fn drop(&mut self) {
+        // The epoch databases aren't known to the node, so their write-ahead log
+        // is flushed here, once the epoch is replaced or the node shuts down.
+        if let Err(error) = self.db.flush_wal() {
+            error!(
+                "Failed to flush the write-ahead log of the epoch {} database: {error}",
+                self.epoch_number
+            );
+        }
+    }
+}
+
 impl<PendingStore, StateStore> PerEpochWriter for PerEpochStore<PendingStore, StateStore>
 where
     PendingStore: PendingCertificateReader + PendingCertificateWriter,
@@ -174,6 +189,11 @@ where
             network_id, height
         );
         let end_checkpoint_entry = end_checkpoint.entry(network_id);
+        let max_certificates = self
+            .config
+            .certificate_orchestrator
+            .max_certificates_per_epoch_for(*network_id)
+            .get();
 
         let end_checkpoint_entry_assigment;
 
@@ -215,10 +235,26 @@ where
                 ))?
             }
             // If the network is found in the end checkpoint and the height minus one is equal to
-            // the current network height. We can add the certificate.
+            // the current network height. We can add the certificate as long as the network
+            // doesn't exceed its number of certificates for this epoch.
             (Some(start_height), Entry::Occupied(current_height))
                 if *current_height.get() == height - 1
-                    && height - start_height <= MAX_CERTIFICATE_PER_EPOCH =>
+                    && height - start_height <= max_certificates =>
+            {
+                debug!(
+                    "Certificate candidate for network {} at height {} accepted",
+                    network_id, height
+                );
+
+                end_checkpoint_entry_assigment = Some(height);
+            }
+            // If the network is only found in the end checkpoint, its first certificate was
+            // added during this epoch, heights 0 to the current network height are already
+            // part of this epoch.
+            (None, Entry::Occupied(current_height))
+                if height > 0
+                    && *current_height.get() == height - 1
+                    && height < max_certificates =>
             {
                 debug!(
                     "Certificate candidate for network {} at height {} accepted",
@@ -335,6 +371,17 @@ where
 
         Ok(())
     }
+
+    fn set_settlement_tx_hashes(
+        &self,
+        certificate_index: CertificateIndex,
+        tx_hashes: &[Hash],
+    ) -> Result<(), Error> {
+        self.db.put::<SettlementTxHashesPerCertificateIndexColumn>(
+            &certificate_index,
+            &tx_hashes.to_vec(),
+        )
+    }
 }
 
 impl<PendingStore, StateStore> PerEpochReader for PerEpochStore<PendingStore, StateStore>
@@ -356,6 +403,16 @@ where
         self.db.get::<ProofPerIndexColumn>(&index)
     }
 
+    fn get_settlement_tx_hashes(
+        &self,
+        certificate_index: CertificateIndex,
+    ) -> Result<Vec<Hash>, Error> {
+        Ok(self
+            .db
+            .get::<SettlementTxHashesPerCertificateIndexColumn>(&certificate_index)?
+            .unwrap_or_default())
+    }
+
     fn get_start_checkpoint(&self) -> &BTreeMap<NetworkId, Height> {
         &self.start_checkpoint
     }
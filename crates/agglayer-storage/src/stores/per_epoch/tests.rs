@@ -5,12 +5,12 @@ use std::{
 
 use agglayer_config::Config;
 use agglayer_types::Certificate;
-use agglayer_types::{Height, NetworkId, Proof};
+use agglayer_types::{Hash, Height, NetworkId, Proof};
 use parking_lot::RwLock;
 use rstest::{fixture, rstest};
 
 use crate::stores::interfaces::writer::StateWriter;
-use crate::stores::{PendingCertificateWriter as _, StateReader};
+use crate::stores::{PendingCertificateWriter as _, PerEpochReader as _, StateReader};
 use crate::{
     error::Error,
     stores::{
@@ -42,6 +42,17 @@ fn cant_start_packing_a_packed_epoch(store: PerEpochStore<PendingStore, StateSto
     assert!(store.start_packing().is_err());
 }
 
+#[rstest]
+fn settlement_tx_hashes_are_recorded(store: PerEpochStore<PendingStore, StateStore>) {
+    assert!(store.get_settlement_tx_hashes(0).unwrap().is_empty());
+
+    let tx_hashes = [Hash([1; 32]), Hash([2; 32])];
+    store.set_settlement_tx_hashes(0, &tx_hashes).unwrap();
+
+    assert_eq!(store.get_settlement_tx_hashes(0).unwrap(), tx_hashes);
+    assert!(store.get_settlement_tx_hashes(1).unwrap().is_empty());
+}
+
 enum CheckpointState {
     Empty,
     WithCheckpoint(Vec<(NetworkId, Height)>),
@@ -201,3 +212,64 @@ fn adding_multiple_certificates(
         height += 1;
     }
 }
+
+#[rstest]
+#[case::when_network_has_a_start_checkpoint(
+    StartCheckpointState::WithCheckpoint(vec![(NetworkId::new(0), 0)]),
+    EndCheckpointState::WithCheckpoint(vec![(NetworkId::new(0), 0)]),
+    1)]
+#[case::when_network_starts_in_the_epoch(StartCheckpointState::Empty, EndCheckpointState::Empty, 0)]
+fn adding_certificates_up_to_the_configured_maximum(
+    #[case] start_checkpoint: StartCheckpointState,
+    #[case] end_checkpoint: EndCheckpointState,
+    #[case] first_height: Height,
+) {
+    let tmp = TempDBDir::new();
+    let mut config = Config::new(&tmp.path);
+    config
+        .certificate_orchestrator
+        .network_max_certificates_per_epoch
+        .insert(0, 3.try_into().unwrap());
+    let config = Arc::new(config);
+    let pending_store =
+        Arc::new(PendingStore::new_with_path(&config.storage.pending_db_path).unwrap());
+    let state_store = Arc::new(StateStore::new_with_path(&config.storage.state_db_path).unwrap());
+    let mut store =
+        PerEpochStore::try_open(config, 0, pending_store.clone(), state_store, None).unwrap();
+
+    let network = 0.into();
+    store.start_checkpoint = start_checkpoint.into();
+    store.end_checkpoint = RwLock::new(end_checkpoint.into());
+
+    for height in first_height..first_height + 4 {
+        let certificate = Certificate::new_for_test(network, height);
+        pending_store
+            .insert_pending_certificate(network, height, &certificate)
+            .unwrap();
+        pending_store
+            .insert_generated_proof(&certificate.hash(), &Proof::new_for_test())
+            .unwrap();
+
+        let result = store.add_certificate(network, height);
+        if height < first_height + 3 {
+            assert!(result.is_ok(), "{network}:{height} should be accepted");
+        } else {
+            assert!(
+                matches!(
+                    result,
+                    Err(Error::CertificateCandidateError(
+                        crate::error::CertificateCandidateError::UnexpectedHeight(..)
+                    ))
+                ),
+                "{network}:{height} should be rejected"
+            );
+        }
+    }
+
+    assert_eq!(
+        store
+            .get_end_checkpoint_height_per_network(network)
+            .unwrap(),
+        Some(first_height + 2)
+    );
+}
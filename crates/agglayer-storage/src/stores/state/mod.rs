@@ -6,15 +6,18 @@ use std::{
 
 use agglayer_types::{
     Certificate, CertificateHeader, CertificateId, CertificateIndex, CertificateStatus,
-    EpochNumber, Hash, Height, Keccak256Hasher, LocalNetworkStateData, NetworkId,
+    EpochNumber, Hash, Height, Keccak256Hasher, LocalExitTreeProof, LocalNetworkStateData,
+    NetworkId,
 };
+use parking_lot::Mutex;
 use pessimistic_proof::{
     local_balance_tree::LOCAL_BALANCE_TREE_DEPTH,
-    local_exit_tree::LocalExitTree,
+    local_exit_tree::{data::LocalExitTreeData, LocalExitTree, LocalExitTreeError},
     nullifier_tree::NULLIFIER_TREE_DEPTH,
     utils::smt::{Node, Smt},
 };
 use rocksdb::{Direction, ReadOptions, WriteBatch};
+use tokio::sync::broadcast;
 use tracing::{debug, warn};
 
 use self::LET::LocalExitTreePerNetworkColumn;
@@ -30,6 +33,10 @@ use crate::{
         local_exit_tree_per_network as LET,
         metadata::MetadataColumn,
         nullifier_tree_per_network::NullifierTreePerNetworkColumn,
+        previous_local_network_state_per_network::{
+            self as previous_state, PreviousLocalNetworkStatePerNetworkColumn,
+        },
+        settlement_tx_hash_per_certificate::SettlementTxHashPerCertificateColumn,
         ColumnSchema,
     },
     error::Error,
@@ -40,14 +47,83 @@ use crate::{
 #[cfg(test)]
 mod tests;
 
+/// Capacity of the certificate status broadcast channel.
+const CERTIFICATE_STATUS_CHANNEL_SIZE: usize = 1_000;
+
+/// Number of settlements per network whose replaced local network state is
+/// kept, bounding how far back the state can be rewound.
+const LOCAL_NETWORK_STATE_HISTORY: u64 = 256;
+
+/// Number of networks whose local exit tree is kept in memory to serve the
+/// local exit tree proofs.
+const LOCAL_EXIT_TREE_CACHE_SIZE: usize = 16;
+
+type SharedLocalExitTree = Arc<Mutex<LocalExitTreeData<Keccak256Hasher>>>;
+
+/// The local exit trees of the networks for which a proof has been requested,
+/// bounded to the most recently used ones.
+///
+/// Each tree has its own lock, so that extending the tree of a network from
+/// the database doesn't block the requests of the other networks.
+#[derive(Default)]
+struct LocalExitTreeCache {
+    /// The cached trees, along with the tick of their last use.
+    trees: BTreeMap<NetworkId, (u64, SharedLocalExitTree)>,
+    tick: u64,
+}
+
+impl LocalExitTreeCache {
+    /// Get the tree of a network, evicting the least recently used tree if the
+    /// cache is full.
+    fn get_or_default(&mut self, network_id: NetworkId) -> SharedLocalExitTree {
+        self.tick += 1;
+
+        if !self.trees.contains_key(&network_id) && self.trees.len() >= LOCAL_EXIT_TREE_CACHE_SIZE {
+            let least_recently_used = self
+                .trees
+                .iter()
+                .min_by_key(|(_, (last_use, _))| *last_use)
+                .map(|(network_id, _)| *network_id);
+
+            if let Some(network_id) = least_recently_used {
+                self.trees.remove(&network_id);
+            }
+        }
+
+        let (last_use, tree) = self.trees.entry(network_id).or_default();
+        *last_use = self.tick;
+
+        tree.clone()
+    }
+
+    fn remove(&mut self, network_id: &NetworkId) {
+        self.trees.remove(network_id);
+    }
+}
+
 /// A logical store for the state.
 pub struct StateStore {
     db: Arc<DB>,
+    /// Broadcast every [`CertificateHeader`] whose status has been written.
+    certificate_status_sender: broadcast::Sender<CertificateHeader>,
+    /// The local exit tree of the networks for which a proof has been
+    /// requested, extended with the new leaves on each request.
+    local_exit_trees: Mutex<LocalExitTreeCache>,
+    /// Serializes the updates of the paused networks, which are stored under
+    /// a single metadata key.
+    paused_networks_lock: Mutex<()>,
 }
 
 impl StateStore {
     pub fn new(db: Arc<DB>) -> Self {
-        Self { db }
+        let (certificate_status_sender, _) = broadcast::channel(CERTIFICATE_STATUS_CHANNEL_SIZE);
+
+        Self {
+            db,
+            certificate_status_sender,
+            local_exit_trees: Mutex::new(LocalExitTreeCache::default()),
+            paused_networks_lock: Mutex::new(()),
+        }
     }
 
     pub fn new_with_path(path: &Path) -> Result<Self, Error> {
@@ -56,7 +132,33 @@ impl StateStore {
             crate::storage::state_db_cf_definitions(),
         )?);
 
-        Ok(Self { db })
+        Ok(Self::new(db))
+    }
+
+    /// Returns the sender notified on every certificate status transition.
+    ///
+    /// Subscribers receive the updated [`CertificateHeader`] each time a
+    /// certificate header is inserted, assigned to an epoch or has its
+    /// status updated.
+    pub fn certificate_status_sender(&self) -> broadcast::Sender<CertificateHeader> {
+        self.certificate_status_sender.clone()
+    }
+
+    /// Fill in the settlement tx hash of the header, stored in its own column.
+    fn with_settlement_tx_hash(
+        &self,
+        mut certificate_header: CertificateHeader,
+    ) -> Result<CertificateHeader, Error> {
+        certificate_header.settlement_tx_hash = self
+            .db
+            .get::<SettlementTxHashPerCertificateColumn>(&certificate_header.certificate_id)?;
+
+        Ok(certificate_header)
+    }
+
+    fn notify_certificate_status(&self, certificate_header: CertificateHeader) {
+        // Sending only fails when there is no active subscriber.
+        _ = self.certificate_status_sender.send(certificate_header);
     }
 }
 
@@ -95,31 +197,43 @@ impl StateWriter for StateStore {
 
             self.db
                 .put::<CertificateHeaderColumn>(certificate_id, &certificate_header)?;
+
+            self.notify_certificate_status(certificate_header);
         }
 
         Ok(())
     }
 
+    fn update_certificate_header_settlement_tx_hash(
+        &self,
+        certificate_id: &CertificateId,
+        tx_hash: &Hash,
+    ) -> Result<(), Error> {
+        self.db
+            .put::<SettlementTxHashPerCertificateColumn>(certificate_id, tx_hash)
+    }
+
     fn insert_certificate_header(
         &self,
         certificate: &Certificate,
         status: CertificateStatus,
     ) -> Result<(), Error> {
+        let certificate_header = CertificateHeader {
+            certificate_id: certificate.hash(),
+            network_id: certificate.network_id,
+            height: certificate.height,
+            epoch_number: None,
+            certificate_index: None,
+            prev_local_exit_root: certificate.prev_local_exit_root.into(),
+            new_local_exit_root: certificate.new_local_exit_root.into(),
+            status: status.clone(),
+            metadata: certificate.metadata,
+            settlement_tx_hash: None,
+        };
+
         // TODO: make it a batch write
-        self.db.put::<CertificateHeaderColumn>(
-            &certificate.hash(),
-            &CertificateHeader {
-                certificate_id: certificate.hash(),
-                network_id: certificate.network_id,
-                height: certificate.height,
-                epoch_number: None,
-                certificate_index: None,
-                prev_local_exit_root: certificate.prev_local_exit_root.into(),
-                new_local_exit_root: certificate.new_local_exit_root.into(),
-                status: status.clone(),
-                metadata: certificate.metadata,
-            },
-        )?;
+        self.db
+            .put::<CertificateHeaderColumn>(&certificate.hash(), &certificate_header)?;
 
         if let CertificateStatus::Settled = status {
             // TODO: Check certificate conflict during insert (if conflict it's too late)
@@ -132,6 +246,8 @@ impl StateWriter for StateStore {
             )?;
         }
 
+        self.notify_certificate_status(certificate_header);
+
         Ok(())
     }
 
@@ -141,7 +257,7 @@ impl StateWriter for StateStore {
         status: &CertificateStatus,
     ) -> Result<(), Error> {
         // TODO: make lockguard for certificate_id
-        let certificate_header = self.db.get::<CertificateHeaderColumn>(certificate_id)?;
+        let certificate_header = self.get_certificate_header(certificate_id)?;
 
         if let Some(mut certificate_header) = certificate_header {
             certificate_header.status = status.clone();
@@ -157,6 +273,8 @@ impl StateWriter for StateStore {
                     &certificate_header.certificate_id,
                 )?;
             }
+
+            self.notify_certificate_status(certificate_header);
         }
 
         Ok(())
@@ -179,19 +297,47 @@ impl StateWriter for StateStore {
     fn write_local_network_state(
         &self,
         network_id: &NetworkId,
+        height: &Height,
         new_state: &LocalNetworkStateData,
         new_leaves: &[Hash],
     ) -> Result<(), Error> {
         let network_id: u32 = (*network_id).into();
+        let previous_state = self.read_local_network_state_roots(network_id.into())?;
 
         let mut atomic_batch = WriteBatch::default();
+        // Keep the replaced state, dropping the one that falls out of the history
+        {
+            self.db
+                .multi_insert_batch::<PreviousLocalNetworkStatePerNetworkColumn>(
+                    [(
+                        &certificate_per_network::Key {
+                            network_id,
+                            height: *height,
+                        },
+                        &previous_state,
+                    )],
+                    &mut atomic_batch,
+                )?;
+
+            if let Some(expired_height) = height.checked_sub(LOCAL_NETWORK_STATE_HISTORY) {
+                self.db
+                    .multi_delete_batch::<PreviousLocalNetworkStatePerNetworkColumn>(
+                        [&certificate_per_network::Key {
+                            network_id,
+                            height: expired_height,
+                        }],
+                        &mut atomic_batch,
+                    )?;
+            }
+        }
+
         // Store the LET
         {
             let new_leaf_count = new_state.exit_tree.leaf_count;
             let start_leaf_count = new_leaf_count - new_leaves.len() as u32;
 
-            if let Some(stored_exit_tree) = self.read_local_exit_tree(network_id.into())? {
-                if stored_exit_tree.leaf_count != start_leaf_count {
+            if let previous_state::Value::State { leaf_count, .. } = previous_state {
+                if leaf_count != start_leaf_count {
                     return Err(Error::InconsistentState {
                         network_id: network_id.into(),
                     });
@@ -259,11 +405,125 @@ impl StateWriter for StateStore {
             &mut atomic_batch,
         )?;
 
-        // Atomic write across the 3 cfs
+        // Atomic write across the 4 cfs
         self.db.write_batch(atomic_batch)?;
 
         Ok(())
     }
+
+    fn rewind_local_network_state(
+        &self,
+        network_id: &NetworkId,
+        height: &Height,
+    ) -> Result<(), Error> {
+        let network_id = *network_id;
+        let key = |height| certificate_per_network::Key {
+            network_id: network_id.into(),
+            height,
+        };
+
+        let previous_state = self
+            .db
+            .get::<PreviousLocalNetworkStatePerNetworkColumn>(&key(*height))?
+            .ok_or(Error::NoPreviousLocalNetworkState(network_id, *height))?;
+
+        let mut atomic_batch = WriteBatch::default();
+
+        let leaf_count_key = LET::Key {
+            network_id: network_id.into(),
+            key_type: LET::KeyType::LeafCount,
+        };
+        let root_key = SmtKey {
+            network_id: network_id.into(),
+            key_type: SmtKeyType::Root,
+        };
+
+        match previous_state {
+            previous_state::Value::Empty => {
+                let exit_tree_keys = std::iter::once(leaf_count_key)
+                    .chain((0..32).map(|layer| LET::Key {
+                        network_id: network_id.into(),
+                        key_type: LET::KeyType::Frontier(layer),
+                    }))
+                    .collect::<Vec<_>>();
+
+                self.db
+                    .multi_delete_batch::<LocalExitTreePerNetworkColumn>(
+                        &exit_tree_keys,
+                        &mut atomic_batch,
+                    )?;
+                self.db.multi_delete_batch::<BalanceTreePerNetworkColumn>(
+                    [&root_key],
+                    &mut atomic_batch,
+                )?;
+                self.db
+                    .multi_delete_batch::<NullifierTreePerNetworkColumn>(
+                        [&root_key],
+                        &mut atomic_batch,
+                    )?;
+            }
+            previous_state::Value::State {
+                leaf_count,
+                frontier,
+                balance_tree_root,
+                nullifier_tree_root,
+            } => {
+                // The leaves past the leaf count are overwritten by the next
+                // settlements, only the leaf count and the frontier are restored.
+                let mut exit_tree_writes = BTreeMap::new();
+                exit_tree_writes.insert(leaf_count_key, LET::Value::LeafCount(leaf_count));
+                (0..32).for_each(|layer| {
+                    exit_tree_writes.insert(
+                        LET::Key {
+                            network_id: network_id.into(),
+                            key_type: LET::KeyType::Frontier(layer),
+                        },
+                        LET::Value::Frontier(frontier[layer as usize]),
+                    );
+                });
+
+                self.db
+                    .multi_insert_batch::<LocalExitTreePerNetworkColumn>(
+                        exit_tree_writes.iter(),
+                        &mut atomic_batch,
+                    )?;
+                self.db.multi_insert_batch::<BalanceTreePerNetworkColumn>(
+                    [(&root_key, &balance_tree_root)],
+                    &mut atomic_batch,
+                )?;
+                self.db
+                    .multi_insert_batch::<NullifierTreePerNetworkColumn>(
+                        [(&root_key, &nullifier_tree_root)],
+                        &mut atomic_batch,
+                    )?;
+            }
+        }
+
+        // The states replaced by the settlements from this height onwards no
+        // longer apply.
+        let mut rewound_keys = Vec::new();
+        let mut rewound_height = *height;
+        while self
+            .db
+            .get::<PreviousLocalNetworkStatePerNetworkColumn>(&key(rewound_height))?
+            .is_some()
+        {
+            rewound_keys.push(key(rewound_height));
+            rewound_height += 1;
+        }
+        self.db
+            .multi_delete_batch::<PreviousLocalNetworkStatePerNetworkColumn>(
+                &rewound_keys,
+                &mut atomic_batch,
+            )?;
+
+        self.db.write_batch(atomic_batch)?;
+
+        // The cached tree may hold leaves that are no longer part of it.
+        self.local_exit_trees.lock().remove(&network_id);
+
+        Ok(())
+    }
 }
 
 impl StateStore {
@@ -311,21 +571,52 @@ impl StateStore {
         Ok(())
     }
 
+    /// Read the roots of the current local network state, which are enough to
+    /// restore it later on.
+    fn read_local_network_state_roots(
+        &self,
+        network_id: NetworkId,
+    ) -> Result<previous_state::Value, Error> {
+        let root_key = SmtKey {
+            network_id: network_id.into(),
+            key_type: SmtKeyType::Root,
+        };
+
+        let local_exit_tree = self.read_local_exit_tree(network_id)?;
+        let balance_tree_root = self.db.get::<BalanceTreePerNetworkColumn>(&root_key)?;
+        let nullifier_tree_root = self.db.get::<NullifierTreePerNetworkColumn>(&root_key)?;
+
+        match (local_exit_tree, balance_tree_root, nullifier_tree_root) {
+            (None, None, None) => Ok(previous_state::Value::Empty),
+            (Some(exit_tree), Some(balance_tree_root), Some(nullifier_tree_root)) => {
+                Ok(previous_state::Value::State {
+                    leaf_count: exit_tree.leaf_count,
+                    frontier: exit_tree.frontier,
+                    balance_tree_root,
+                    nullifier_tree_root,
+                })
+            }
+            _ => Err(Error::InconsistentState { network_id }),
+        }
+    }
+
+    fn read_local_exit_tree_leaf_count(&self, network_id: NetworkId) -> Result<Option<u32>, Error> {
+        match self.db.get::<LocalExitTreePerNetworkColumn>(&LET::Key {
+            network_id: network_id.into(),
+            key_type: LET::KeyType::LeafCount,
+        })? {
+            Some(LET::Value::LeafCount(leaf_count)) => Ok(Some(leaf_count)),
+            Some(_) => Err(Error::InconsistentFrontier),
+            None => Ok(None),
+        }
+    }
+
     fn read_local_exit_tree(
         &self,
         network_id: NetworkId,
     ) -> Result<Option<LocalExitTree<Keccak256Hasher>>, Error> {
         debug!("Reading local exit tree for network_id: {}", network_id);
-        let leaf_count = if let Some(leaf_count_value) =
-            self.db.get::<LocalExitTreePerNetworkColumn>(&LET::Key {
-                network_id: network_id.into(),
-                key_type: LET::KeyType::LeafCount,
-            })? {
-            match leaf_count_value {
-                LET::Value::LeafCount(leaf_count) => leaf_count,
-                _ => return Err(Error::InconsistentFrontier),
-            }
-        } else {
+        let Some(leaf_count) = self.read_local_exit_tree_leaf_count(network_id)? else {
             return Ok(None);
         };
 
@@ -438,7 +729,10 @@ impl StateReader for StateStore {
         &self,
         certificate_id: &CertificateId,
     ) -> Result<Option<CertificateHeader>, Error> {
-        self.db.get::<CertificateHeaderColumn>(certificate_id)
+        self.db
+            .get::<CertificateHeaderColumn>(certificate_id)?
+            .map(|certificate_header| self.with_settlement_tx_hash(certificate_header))
+            .transpose()
     }
 
     fn get_certificate_header_by_cursor(
@@ -466,6 +760,55 @@ impl StateReader for StateStore {
             })
     }
 
+    fn get_certificate_headers_by_cursor(
+        &self,
+        network_id: NetworkId,
+        from_height: Height,
+        limit: usize,
+    ) -> Result<Vec<CertificateHeader>, Error> {
+        let mut iterator = self.db.iter_with_direction::<CertificatePerNetworkColumn>(
+            ReadOptions::default(),
+            Direction::Forward,
+        )?;
+        iterator.seek(&certificate_per_network::Key {
+            network_id: *network_id,
+            height: from_height,
+        })?;
+
+        let certificate_ids = iterator
+            .filter_map(|v| v.ok())
+            .take_while(|(key, _)| key.network_id == *network_id)
+            .take(limit)
+            .map(|(_, certificate_id)| certificate_id)
+            .collect::<Vec<_>>();
+
+        let settlement_tx_hashes = self
+            .db
+            .multi_get::<SettlementTxHashPerCertificateColumn>(certificate_ids.iter().copied())?;
+
+        Ok(self
+            .db
+            .multi_get::<CertificateHeaderColumn>(certificate_ids.iter().copied())?
+            .into_iter()
+            .zip(settlement_tx_hashes)
+            .zip(certificate_ids)
+            .filter_map(|((header, settlement_tx_hash), certificate_id)| {
+                if header.is_none() {
+                    warn!(
+                        "Certificate header not found for certificate_id: {} while having a \
+                         reference in the CertificatePerNetworkColumn",
+                        certificate_id
+                    );
+                }
+
+                header.map(|header| CertificateHeader {
+                    settlement_tx_hash,
+                    ..header
+                })
+            })
+            .collect())
+    }
+
     fn get_current_settled_height(&self) -> Result<Vec<(NetworkId, SettledCertificate)>, Error> {
         Ok(self
             .db
@@ -510,6 +853,72 @@ impl StateReader for StateStore {
             _ => Err(Error::InconsistentState { network_id }),
         }
     }
+
+    fn get_local_exit_tree_proof(
+        &self,
+        network_id: NetworkId,
+        leaf_index: u32,
+        leaf_count: Option<u32>,
+    ) -> Result<Option<LocalExitTreeProof>, Error> {
+        let Some(current_leaf_count) = self.read_local_exit_tree_leaf_count(network_id)? else {
+            return Ok(None);
+        };
+
+        let leaf_count = leaf_count.unwrap_or(current_leaf_count);
+        if leaf_index >= leaf_count || leaf_count > current_leaf_count {
+            return Ok(None);
+        }
+
+        let local_exit_tree_error = |error: LocalExitTreeError| {
+            Error::Unexpected(format!(
+                "Unable to compute the local exit tree proof of network {network_id}: {error}"
+            ))
+        };
+
+        // Only the tree of this network stays locked while it is extended.
+        let tree = self.local_exit_trees.lock().get_or_default(network_id);
+        let mut tree = tree.lock();
+
+        // The leaves are only ever appended, the cached tree only lacks the
+        // leaves added since the previous request.
+        let cached_leaf_count = tree.layers[0].len() as u32;
+        if cached_leaf_count > current_leaf_count {
+            *tree = LocalExitTreeData::new();
+        }
+        let cached_leaf_count = tree.layers[0].len() as u32;
+
+        self.db
+            .multi_get::<LocalExitTreePerNetworkColumn>(
+                (cached_leaf_count..current_leaf_count).map(|index| LET::Key {
+                    network_id: network_id.into(),
+                    key_type: LET::KeyType::Leaf(index),
+                }),
+            )?
+            .into_iter()
+            .try_for_each(|v| match v {
+                Some(LET::Value::Leaf(leaf)) => tree
+                    .add_leaf(leaf)
+                    .map(|_| ())
+                    .map_err(local_exit_tree_error),
+                _ => Err(Error::InconsistentState { network_id }),
+            })
+            .inspect_err(|_| *tree = LocalExitTreeData::new())?;
+
+        let root = tree
+            .get_root_at(leaf_count)
+            .map_err(local_exit_tree_error)?;
+        let proof = tree
+            .get_proof_at(leaf_index, leaf_count)
+            .map_err(local_exit_tree_error)?;
+
+        Ok(Some(LocalExitTreeProof {
+            leaf_index,
+            leaf: Hash(tree.layers[0][leaf_index as usize]),
+            leaf_count,
+            root: Hash(root),
+            proof,
+        }))
+    }
 }
 
 impl MetadataWriter for StateStore {
@@ -527,6 +936,27 @@ impl MetadataWriter for StateStore {
             &MetadataValue::LatestSettledEpoch(value),
         )
     }
+
+    fn set_network_paused(&self, network_id: NetworkId, paused: bool) -> Result<(), Error> {
+        let _lock = self.paused_networks_lock.lock();
+
+        let mut paused_networks = self.get_paused_networks()?;
+
+        let changed = if paused {
+            paused_networks.insert(network_id)
+        } else {
+            paused_networks.remove(&network_id)
+        };
+
+        if !changed {
+            return Ok(());
+        }
+
+        self.db.put::<MetadataColumn>(
+            &MetadataKey::PausedNetworks,
+            &MetadataValue::PausedNetworks(paused_networks),
+        )
+    }
 }
 
 impl MetadataReader for StateStore {
@@ -544,4 +974,18 @@ impl MetadataReader for StateStore {
                 })
             })
     }
+
+    fn get_paused_networks(&self) -> Result<BTreeSet<NetworkId>, Error> {
+        match self
+            .db
+            .get::<MetadataColumn>(&MetadataKey::PausedNetworks)?
+        {
+            None => Ok(BTreeSet::new()),
+            Some(MetadataValue::PausedNetworks(paused_networks)) => Ok(paused_networks),
+            Some(_) => Err(Error::Unexpected(
+                "Wrong value type decoded, was expecting PausedNetworks, decoded another type"
+                    .to_string(),
+            )),
+        }
+    }
 }
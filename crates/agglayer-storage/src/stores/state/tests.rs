@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use agglayer_types::{Certificate, Hash, LocalNetworkStateData, NetworkId};
+use agglayer_types::{Certificate, CertificateStatus, Hash, LocalNetworkStateData, NetworkId};
 use pessimistic_proof::{generate_pessimistic_proof, LocalNetworkState};
 use rstest::{fixture, rstest};
 use tracing::info;
@@ -11,7 +11,10 @@ use crate::{
     },
     error::Error,
     storage::{state_db_cf_definitions, DB},
-    stores::{state::StateStore, StateReader as _, StateWriter as _},
+    stores::{
+        state::{StateStore, LOCAL_EXIT_TREE_CACHE_SIZE},
+        StateReader as _, StateWriter as _,
+    },
     tests::TempDBDir,
 };
 
@@ -32,6 +35,101 @@ fn can_retrieve_list_of_network() {
     assert!(store.get_active_networks().unwrap().len() == 1);
 }
 
+#[rstest]
+fn notifies_certificate_status_transitions(network_id: NetworkId, store: StateStore) {
+    let mut receiver = store.certificate_status_sender().subscribe();
+    let certificate = Certificate::new_for_test(network_id, 0);
+    let certificate_id = certificate.hash();
+
+    store
+        .insert_certificate_header(&certificate, CertificateStatus::Pending)
+        .unwrap();
+    store
+        .update_certificate_header_status(&certificate_id, &CertificateStatus::Proven)
+        .unwrap();
+
+    let header = receiver.try_recv().unwrap();
+    assert_eq!(header.certificate_id, certificate_id);
+    assert_eq!(header.status, CertificateStatus::Pending);
+
+    let header = receiver.try_recv().unwrap();
+    assert_eq!(header.certificate_id, certificate_id);
+    assert_eq!(header.status, CertificateStatus::Proven);
+
+    assert!(receiver.try_recv().is_err());
+}
+
+#[rstest]
+fn records_the_settlement_tx_hash(network_id: NetworkId, store: StateStore) {
+    let certificate = Certificate::new_for_test(network_id, 0);
+    let certificate_id = certificate.hash();
+
+    store
+        .insert_certificate_header(&certificate, CertificateStatus::Candidate)
+        .unwrap();
+    store
+        .update_certificate_header_settlement_tx_hash(&certificate_id, &Hash([1; 32]))
+        .unwrap();
+
+    let header = store
+        .get_certificate_header(&certificate_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(header.settlement_tx_hash, Some(Hash([1; 32])));
+    assert_eq!(header.status, CertificateStatus::Candidate);
+
+    // Rewriting the header keeps the settlement tx hash.
+    store
+        .update_certificate_header_status(&certificate_id, &CertificateStatus::Settled)
+        .unwrap();
+
+    let headers = store
+        .get_certificate_headers_by_cursor(network_id, 0, 1)
+        .unwrap();
+    assert_eq!(headers.len(), 1);
+    assert_eq!(headers[0].settlement_tx_hash, Some(Hash([1; 32])));
+    assert_eq!(headers[0].status, CertificateStatus::Settled);
+}
+
+#[rstest]
+fn can_retrieve_certificate_headers_by_cursor(store: StateStore) {
+    for network_id in [1, 2] {
+        for height in 0..5 {
+            store
+                .insert_certificate_header(
+                    &Certificate::new_for_test(network_id.into(), height),
+                    CertificateStatus::Settled,
+                )
+                .unwrap();
+        }
+    }
+
+    let heights = |headers: Vec<agglayer_types::CertificateHeader>| {
+        headers
+            .into_iter()
+            .map(|header| {
+                assert_eq!(header.network_id, 1.into());
+                header.height
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let headers = store
+        .get_certificate_headers_by_cursor(1.into(), 2, 10)
+        .unwrap();
+    assert_eq!(heights(headers), vec![2, 3, 4]);
+
+    let headers = store
+        .get_certificate_headers_by_cursor(1.into(), 1, 2)
+        .unwrap();
+    assert_eq!(heights(headers), vec![1, 2]);
+
+    assert!(store
+        .get_certificate_headers_by_cursor(3.into(), 0, 10)
+        .unwrap()
+        .is_empty());
+}
+
 fn equal_state(lhs: &LocalNetworkStateData, rhs: &LocalNetworkStateData) -> bool {
     // local exit tree
     assert_eq!(lhs.exit_tree.leaf_count, rhs.exit_tree.leaf_count);
@@ -71,7 +169,12 @@ fn can_handle_empty_state(#[from(network_id)] unknown_network_id: NetworkId, sto
 
     // can write one state from scratch
     assert!(store
-        .write_local_network_state(&unknown_network_id, &LocalNetworkStateData::default(), &[])
+        .write_local_network_state(
+            &unknown_network_id,
+            &0,
+            &LocalNetworkStateData::default(),
+            &[]
+        )
         .is_ok());
 }
 
@@ -85,7 +188,7 @@ fn can_retrieve_state(network_id: NetworkId, store: StateStore) {
     }
 
     assert!(store
-        .write_local_network_state(&network_id, &lns, leaves.as_slice())
+        .write_local_network_state(&network_id, &0, &lns, leaves.as_slice())
         .is_ok());
 
     // retrieve it
@@ -100,7 +203,7 @@ fn can_update_existing_state(network_id: NetworkId, store: StateStore) {
 
     // write initial state
     assert!(store
-        .write_local_network_state(&network_id, &lns, &[])
+        .write_local_network_state(&network_id, &0, &lns, &[])
         .is_ok());
 
     // update state
@@ -109,7 +212,7 @@ fn can_update_existing_state(network_id: NetworkId, store: StateStore) {
 
     // write new state
     assert!(store
-        .write_local_network_state(&network_id, &lns, &[Hash(bridge_exit)])
+        .write_local_network_state(&network_id, &1, &lns, &[Hash(bridge_exit)])
         .is_ok());
 
     // retrieve new state
@@ -124,7 +227,7 @@ fn can_detect_inconsistent_state(network_id: NetworkId, store: StateStore) {
 
     // write initial state
     assert!(store
-        .write_local_network_state(&network_id, &lns, &[])
+        .write_local_network_state(&network_id, &0, &lns, &[])
         .is_ok());
 
     // update state
@@ -133,11 +236,106 @@ fn can_detect_inconsistent_state(network_id: NetworkId, store: StateStore) {
 
     // write new state with missing leaves
     assert!(matches!(
-        store.write_local_network_state(&network_id, &lns, &[]),
+        store.write_local_network_state(&network_id, &1, &lns, &[]),
         Err(Error::InconsistentState { .. })
     ));
 }
 
+#[rstest]
+fn can_retrieve_local_exit_tree_proof(network_id: NetworkId, store: StateStore) {
+    let mut lns = LocalNetworkStateData::default();
+    let leaves = (0..10u8).map(|i| Hash([i; 32])).collect::<Vec<_>>();
+    let mut roots = Vec::new();
+    for l in &leaves {
+        lns.exit_tree.add_leaf(l.0).unwrap();
+        roots.push(Hash(lns.exit_tree.get_root()));
+    }
+
+    store
+        .write_local_network_state(&network_id, &0, &lns, leaves.as_slice())
+        .unwrap();
+
+    // proof against the current root
+    let proof = store
+        .get_local_exit_tree_proof(network_id, 3, None)
+        .unwrap()
+        .unwrap();
+    assert_eq!(proof.leaf, leaves[3]);
+    assert_eq!(proof.leaf_count, 10);
+    assert_eq!(proof.root, roots[9]);
+    assert!(proof.proof.verify(proof.leaf.0, 3, proof.root.0));
+
+    // proof against a previous root
+    let proof = store
+        .get_local_exit_tree_proof(network_id, 3, Some(5))
+        .unwrap()
+        .unwrap();
+    assert_eq!(proof.root, roots[4]);
+    assert!(proof.proof.verify(proof.leaf.0, 3, proof.root.0));
+
+    // leaves outside of the tree
+    assert!(store
+        .get_local_exit_tree_proof(network_id, 5, Some(5))
+        .unwrap()
+        .is_none());
+    assert!(store
+        .get_local_exit_tree_proof(network_id, 3, Some(11))
+        .unwrap()
+        .is_none());
+    assert!(store
+        .get_local_exit_tree_proof(1.into(), 0, None)
+        .unwrap()
+        .is_none());
+
+    // the leaves added afterwards extend the tree of the previous proofs
+    let new_leaves = (10..12u8).map(|i| Hash([i; 32])).collect::<Vec<_>>();
+    for l in &new_leaves {
+        lns.exit_tree.add_leaf(l.0).unwrap();
+        roots.push(Hash(lns.exit_tree.get_root()));
+    }
+
+    store
+        .write_local_network_state(&network_id, &1, &lns, new_leaves.as_slice())
+        .unwrap();
+
+    let proof = store
+        .get_local_exit_tree_proof(network_id, 11, None)
+        .unwrap()
+        .unwrap();
+    assert_eq!(proof.leaf, new_leaves[1]);
+    assert_eq!(proof.leaf_count, 12);
+    assert_eq!(proof.root, roots[11]);
+    assert!(proof.proof.verify(proof.leaf.0, 11, proof.root.0));
+}
+
+#[rstest]
+fn local_exit_tree_cache_is_bounded(store: StateStore) {
+    let mut lns = LocalNetworkStateData::default();
+    let leaf = Hash([1; 32]);
+    lns.exit_tree.add_leaf(leaf.0).unwrap();
+
+    let networks = (0..=LOCAL_EXIT_TREE_CACHE_SIZE as u32)
+        .map(NetworkId::new)
+        .collect::<Vec<_>>();
+    for network_id in &networks {
+        store
+            .write_local_network_state(network_id, &0, &lns, &[leaf])
+            .unwrap();
+    }
+
+    for network_id in &networks {
+        assert!(store
+            .get_local_exit_tree_proof(*network_id, 0, None)
+            .unwrap()
+            .is_some());
+    }
+
+    // The least recently used tree has been evicted.
+    let cache = store.local_exit_trees.lock();
+    assert_eq!(cache.trees.len(), LOCAL_EXIT_TREE_CACHE_SIZE);
+    assert!(!cache.trees.contains_key(&networks[0]));
+}
+
 use pessimistic_proof_test_suite::sample_data::{self as data};
 
 #[rstest]
@@ -210,7 +408,12 @@ fn can_read(network_id: NetworkId, store: StateStore) {
 
     // write state
     assert!(store
-        .write_local_network_state(&network_id, &before_going_through_disk, leaves.as_slice())
+        .write_local_network_state(
+            &network_id,
+            &3,
+            &before_going_through_disk,
+            leaves.as_slice()
+        )
         .is_ok());
 
     // read state
@@ -229,3 +432,70 @@ fn can_read(network_id: NetworkId, store: StateStore) {
         &after_going_through_disk
     ));
 }
+
+#[rstest]
+fn can_rewind_local_network_state(network_id: NetworkId, store: StateStore) {
+    let certificates: Vec<Certificate> = ["n15-cert_h0.json", "n15-cert_h1.json"]
+        .iter()
+        .map(|p| data::load_certificate(p))
+        .collect();
+
+    let mut lns = LocalNetworkStateData::default();
+    let mut states = Vec::new();
+
+    for certificate in &certificates {
+        let signer = certificate.signer().unwrap();
+        let l1_info_root = certificate.l1_info_root().unwrap().unwrap_or_default();
+        lns.apply_certificate(certificate, signer, l1_info_root)
+            .unwrap();
+
+        let mut state = lns.clone();
+        state.balance_tree.traverse_and_prune().unwrap();
+        state.nullifier_tree.traverse_and_prune().unwrap();
+
+        let leaves = certificate
+            .bridge_exits
+            .iter()
+            .map(|b| Hash(b.hash()))
+            .collect::<Vec<_>>();
+        store
+            .write_local_network_state(&network_id, &certificate.height, &state, &leaves)
+            .unwrap();
+
+        states.push(state);
+    }
+
+    // the settlement of the second certificate is reverted
+    store
+        .rewind_local_network_state(&network_id, &certificates[1].height)
+        .unwrap();
+    let rewound = store.read_local_network_state(network_id).unwrap().unwrap();
+    assert!(equal_state(&states[0], &rewound));
+
+    // a reverted settlement can't be rewound twice
+    assert!(matches!(
+        store.rewind_local_network_state(&network_id, &certificates[1].height),
+        Err(Error::NoPreviousLocalNetworkState(..))
+    ));
+
+    // the second certificate can be settled again on top of the rewound state
+    let leaves = certificates[1]
+        .bridge_exits
+        .iter()
+        .map(|b| Hash(b.hash()))
+        .collect::<Vec<_>>();
+    store
+        .write_local_network_state(&network_id, &certificates[1].height, &states[1], &leaves)
+        .unwrap();
+    let settled = store.read_local_network_state(network_id).unwrap().unwrap();
+    assert!(equal_state(&states[1], &settled));
+
+    // rewinding the first settlement goes back to the empty state
+    store
+        .rewind_local_network_state(&network_id, &certificates[0].height)
+        .unwrap();
+    assert!(store
+        .read_local_network_state(network_id)
+        .unwrap()
+        .is_none());
+}
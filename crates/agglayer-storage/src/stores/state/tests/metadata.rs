@@ -42,3 +42,50 @@ fn can_set_the_latest_epoch_settled() {
         Ok(Some(MetadataValue::LatestSettledEpoch(2)))
     ));
 }
+
+#[test]
+fn can_pause_and_resume_networks() {
+    let tmp = TempDBDir::new();
+    let db = Arc::new(DB::open_cf(tmp.path.as_path(), state_db_cf_definitions()).unwrap());
+
+    let store = StateStore::new(db.clone());
+    assert!(store.get_paused_networks().unwrap().is_empty());
+
+    store.set_network_paused(1.into(), true).unwrap();
+    store.set_network_paused(2.into(), true).unwrap();
+    store.set_network_paused(1.into(), false).unwrap();
+
+    assert_eq!(
+        store.get_paused_networks().unwrap(),
+        [2.into()].into_iter().collect()
+    );
+
+    // The flags survive a restart.
+    drop(store);
+    drop(db);
+    let db = Arc::new(DB::open_cf(tmp.path.as_path(), state_db_cf_definitions()).unwrap());
+    let store = StateStore::new(db);
+    assert_eq!(
+        store.get_paused_networks().unwrap(),
+        [2.into()].into_iter().collect()
+    );
+}
+
+#[test]
+fn can_pause_networks_concurrently() {
+    let tmp = TempDBDir::new();
+    let db = Arc::new(DB::open_cf(tmp.path.as_path(), state_db_cf_definitions()).unwrap());
+    let store = StateStore::new(db);
+
+    std::thread::scope(|scope| {
+        for network_id in 0..8u32 {
+            let store = &store;
+            scope.spawn(move || store.set_network_paused(network_id.into(), true).unwrap());
+        }
+    });
+
+    assert_eq!(
+        store.get_paused_networks().unwrap(),
+        (0..8u32).map(Into::into).collect()
+    );
+}
@@ -19,5 +19,16 @@ mock! {
         ) -> Result<MockPerEpochStore, Error>;
     }
 
-    impl EpochStoreReader for EpochsStore {}
+    impl EpochStoreReader for EpochsStore {
+        fn get_certificate_in_epoch(
+            &self,
+            epoch_number: agglayer_types::EpochNumber,
+            certificate_index: agglayer_types::CertificateIndex,
+        ) -> Result<Option<agglayer_types::Certificate>, Error>;
+        fn get_proof_in_epoch(
+            &self,
+            epoch_number: agglayer_types::EpochNumber,
+            certificate_index: agglayer_types::CertificateIndex,
+        ) -> Result<Option<agglayer_types::Proof>, Error>;
+    }
 }
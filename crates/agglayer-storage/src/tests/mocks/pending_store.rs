@@ -1,4 +1,4 @@
-use agglayer_types::{Certificate, CertificateId, Height, NetworkId, Proof};
+use agglayer_types::{Certificate, CertificateId, EpochNumber, Height, NetworkId, Proof};
 use mockall::mock;
 
 use crate::{
@@ -39,6 +39,19 @@ mock! {
             &self,
             network_id: &NetworkId,
         ) -> Result<Option<Height>, Error>;
+
+        fn get_networks_with_pending_certificates(&self) -> Result<Vec<NetworkId>, Error>;
+
+        fn get_pending_certificates_for_network(
+            &self,
+            network_id: &NetworkId,
+        ) -> Result<Vec<Certificate>, Error>;
+
+        fn get_pending_since(
+            &self,
+            network_id: NetworkId,
+            height: Height,
+        ) -> Result<Option<EpochNumber>, Error>;
     }
 
     impl PendingCertificateWriter for PendingStore {
@@ -57,6 +70,13 @@ mock! {
             certificate: &Certificate,
         ) -> Result<(), Error>;
 
+        fn set_pending_since(
+            &self,
+            network_id: NetworkId,
+            height: Height,
+            epoch_number: EpochNumber,
+        ) -> Result<(), Error>;
+
         fn insert_generated_proof(
             &self,
             certificate_id: &CertificateId,
@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-use agglayer_types::{Certificate, CertificateIndex, EpochNumber, Height, NetworkId, Proof};
+use agglayer_types::{Certificate, CertificateIndex, EpochNumber, Hash, Height, NetworkId, Proof};
 use mockall::mock;
 
 use crate::{
@@ -17,6 +17,7 @@ mock! {
         fn get_start_checkpoint(&self) -> &BTreeMap<NetworkId, Height>;
         fn get_end_checkpoint(&self) -> BTreeMap<NetworkId, Height>;
         fn get_proof_at_index(&self, index: CertificateIndex) -> Result<Option<Proof>, Error>;
+        fn get_settlement_tx_hashes(&self, certificate_index: CertificateIndex) -> Result<Vec<Hash>, Error>;
         fn get_certificate_at_index(&self, index: CertificateIndex) -> Result<Option<Certificate>, Error>;
         fn get_end_checkpoint_height_per_network(
             &self,
@@ -27,5 +28,6 @@ mock! {
     impl PerEpochWriter for PerEpochStore {
         fn add_certificate(&self, network_id: NetworkId, height: Height) -> Result<(EpochNumber, CertificateIndex), Error>;
         fn start_packing(&self) -> Result<(), Error>;
+        fn set_settlement_tx_hashes(&self, certificate_index: CertificateIndex, tx_hashes: &[Hash]) -> Result<(), Error>;
     }
 }
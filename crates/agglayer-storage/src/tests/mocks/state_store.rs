@@ -1,6 +1,6 @@
 use agglayer_types::{
     Certificate, CertificateHeader, CertificateId, CertificateStatus, EpochNumber, Hash, Height,
-    LocalNetworkStateData, NetworkId,
+    LocalExitTreeProof, LocalNetworkStateData, NetworkId,
 };
 use mockall::mock;
 
@@ -13,10 +13,12 @@ mock! {
     pub StateStore {}
     impl MetadataReader for StateStore {
         fn get_latest_settled_epoch(&self) -> Result<Option<u64>, Error>;
+        fn get_paused_networks(&self) -> Result<std::collections::BTreeSet<NetworkId>, Error>;
     }
 
     impl MetadataWriter for StateStore {
         fn set_latest_settled_epoch(&self, value: u64) -> Result<(), Error>;
+        fn set_network_paused(&self, network_id: NetworkId, paused: bool) -> Result<(), Error>;
     }
 
     impl StateWriter for StateStore {
@@ -39,6 +41,12 @@ mock! {
             status: &CertificateStatus,
         ) -> Result<(), Error>;
 
+        fn update_certificate_header_settlement_tx_hash(
+            &self,
+            certificate_id: &CertificateId,
+            tx_hash: &Hash,
+        ) -> Result<(), Error>;
+
         fn set_latest_settled_certificate_for_network(
             &self,
             network_id: &NetworkId,
@@ -51,9 +59,16 @@ mock! {
         fn write_local_network_state(
             &self,
             network_id: &NetworkId,
+            height: &Height,
             new_state: &LocalNetworkStateData,
             new_leaves: &[Hash],
         ) -> Result<(), Error>;
+
+        fn rewind_local_network_state(
+            &self,
+            network_id: &NetworkId,
+            height: &Height,
+        ) -> Result<(), Error>;
     }
 
     impl StateReader for StateStore {
@@ -74,11 +89,26 @@ mock! {
             network_id: NetworkId,
             height: Height,
         ) -> Result<Option<CertificateHeader>, Error>;
+
+        fn get_certificate_headers_by_cursor(
+            &self,
+            network_id: NetworkId,
+            from_height: Height,
+            limit: usize,
+        ) -> Result<Vec<CertificateHeader>, Error>;
+
         fn get_current_settled_height(&self) -> Result<Vec<(NetworkId, SettledCertificate)>, Error>;
 
         fn read_local_network_state(
             &self,
             network_id: NetworkId,
         ) -> Result<Option<LocalNetworkStateData>, Error>;
+
+        fn get_local_exit_tree_proof(
+            &self,
+            network_id: NetworkId,
+            leaf_index: u32,
+            leaf_count: Option<u32>,
+        ) -> Result<Option<LocalExitTreeProof>, Error>;
     }
 }
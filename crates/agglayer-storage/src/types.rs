@@ -1,10 +1,17 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use agglayer_types::Hash;
-use agglayer_types::{Certificate, CertificateHeader, CertificateId, Height, NetworkId, Proof};
+use agglayer_types::{
+    Certificate, CertificateHeader, CertificateId, CertificateIndex, CertificateStatus,
+    EpochNumber, Height, Metadata, NetworkId, Proof,
+};
+use bincode::Options as _;
 use serde::{Deserialize, Serialize};
 
-use crate::columns::Codec;
+use crate::{
+    columns::{default_bincode_options, Codec},
+    error::Error,
+};
 
 macro_rules! default_codec_impl {
     ($($ident: ident),+) => {
@@ -16,12 +23,14 @@ macro_rules! default_codec_impl {
 pub enum MetadataKey {
     LatestSettledEpoch,
     EpochSynchronization,
+    PausedNetworks,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub enum MetadataValue {
     LatestSettledEpoch(u64),
     EpochSynchronization(u64),
+    PausedNetworks(BTreeSet<NetworkId>),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -56,15 +65,67 @@ pub enum SmtValue {
     Leaf(Hash),
 }
 
+/// The stored layout of a [`CertificateHeader`].
+///
+/// The settlement tx hash isn't part of it, it is stored in its own column so
+/// that the headers written before it existed can still be decoded.
+#[derive(Serialize, Deserialize)]
+struct StoredCertificateHeader {
+    network_id: NetworkId,
+    height: Height,
+    epoch_number: Option<EpochNumber>,
+    certificate_index: Option<CertificateIndex>,
+    certificate_id: CertificateId,
+    prev_local_exit_root: Hash,
+    new_local_exit_root: Hash,
+    metadata: Metadata,
+    status: CertificateStatus,
+}
+
+impl Codec for CertificateHeader {
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        let stored = StoredCertificateHeader {
+            network_id: self.network_id,
+            height: self.height,
+            epoch_number: self.epoch_number,
+            certificate_index: self.certificate_index,
+            certificate_id: self.certificate_id,
+            prev_local_exit_root: self.prev_local_exit_root,
+            new_local_exit_root: self.new_local_exit_root,
+            metadata: self.metadata,
+            status: self.status.clone(),
+        };
+
+        Ok(default_bincode_options().serialize(&stored)?)
+    }
+
+    fn decode(buf: &[u8]) -> Result<Self, Error> {
+        let stored: StoredCertificateHeader = default_bincode_options().deserialize(buf)?;
+
+        Ok(CertificateHeader {
+            network_id: stored.network_id,
+            height: stored.height,
+            epoch_number: stored.epoch_number,
+            certificate_index: stored.certificate_index,
+            certificate_id: stored.certificate_id,
+            prev_local_exit_root: stored.prev_local_exit_root,
+            new_local_exit_root: stored.new_local_exit_root,
+            metadata: stored.metadata,
+            status: stored.status,
+            settlement_tx_hash: None,
+        })
+    }
+}
+
 impl Codec for SmtKey {}
 impl Codec for SmtValue {}
+impl Codec for Vec<Hash> {}
 
 default_codec_impl!(
     u64,
     u32,
     Certificate,
     CertificateId,
-    CertificateHeader,
     MetadataKey,
     MetadataValue,
     NetworkId,
@@ -1,3 +1,5 @@
 pub(crate) const AGGLAYER_RPC_OTEL_SCOPE_NAME: &str = "rpc";
 pub(crate) const AGGLAYER_KERNEL_OTEL_SCOPE_NAME: &str = "kernel";
 pub(crate) const AGGLAYER_PROVER_RPC_OTEL_SCOPE_NAME: &str = "agglayer_prover_rpc";
+pub(crate) const AGGLAYER_CERTIFIER_OTEL_SCOPE_NAME: &str = "certifier";
+pub(crate) const AGGLAYER_SETTLEMENT_OTEL_SCOPE_NAME: &str = "settlement";
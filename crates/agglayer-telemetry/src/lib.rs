@@ -89,6 +89,53 @@ pub mod prover {
     }
 }
 
+pub mod certifier {
+    use lazy_static::lazy_static;
+    use opentelemetry::global;
+
+    use crate::constant::AGGLAYER_CERTIFIER_OTEL_SCOPE_NAME;
+
+    lazy_static! {
+        pub static ref CERTIFICATION_QUEUE_DEPTH: opentelemetry::metrics::UpDownCounter<i64> =
+            global::meter(AGGLAYER_CERTIFIER_OTEL_SCOPE_NAME)
+                .i64_up_down_counter("certification_queue_depth")
+                .with_description("Number of certifications waiting for a prover slot")
+                .init();
+        pub static ref NETWORK_TASKS: opentelemetry::metrics::UpDownCounter<i64> =
+            global::meter(AGGLAYER_CERTIFIER_OTEL_SCOPE_NAME)
+                .i64_up_down_counter("network_tasks")
+                .with_description("Number of network tasks in each supervision state")
+                .init();
+        pub static ref NETWORK_TASK_RESTARTS: opentelemetry::metrics::Counter<u64> =
+            global::meter(AGGLAYER_CERTIFIER_OTEL_SCOPE_NAME)
+                .u64_counter("network_task_restarts")
+                .with_description("Number of restarts of the network tasks after a failure")
+                .init();
+    }
+}
+
+pub mod settlement {
+    use lazy_static::lazy_static;
+    use opentelemetry::global;
+
+    use crate::constant::AGGLAYER_SETTLEMENT_OTEL_SCOPE_NAME;
+
+    lazy_static! {
+        pub static ref L1_REORGS: opentelemetry::metrics::Counter<u64> =
+            global::meter(AGGLAYER_SETTLEMENT_OTEL_SCOPE_NAME)
+                .u64_counter("l1_reorgs")
+                .with_description("Number of L1 reorgs detected")
+                .init();
+        pub static ref SETTLEMENTS_REORGED: opentelemetry::metrics::Counter<u64> =
+            global::meter(AGGLAYER_SETTLEMENT_OTEL_SCOPE_NAME)
+                .u64_counter("settlements_reorged")
+                .with_description(
+                    "Number of certificate settlements removed from the L1 by a reorg"
+                )
+                .init();
+    }
+}
+
 pub struct ServerBuilder {}
 
 #[buildstructor::buildstructor]
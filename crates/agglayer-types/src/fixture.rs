@@ -0,0 +1,53 @@
+use pessimistic_proof::{bridge_exit::NetworkId, PessimisticProofOutput};
+use reth_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+use crate::Certificate;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct VerifierInputs {
+    /// The previous local exit root.
+    pub prev_local_exit_root: String,
+    /// The previous pessimistic root.
+    pub prev_pessimistic_root: String,
+    /// The l1 info root against which we prove the inclusion of the
+    /// imported bridge exits.
+    pub l1_info_root: String,
+    /// The origin network of the pessimistic proof.
+    pub origin_network: NetworkId,
+    /// The consensus hash.
+    pub consensus_hash: String,
+    /// The new local exit root.
+    pub new_local_exit_root: String,
+    /// The new pessimistic root which commits to the balance and nullifier
+    /// tree.
+    pub new_pessimistic_root: String,
+}
+
+impl From<PessimisticProofOutput> for VerifierInputs {
+    fn from(v: PessimisticProofOutput) -> Self {
+        Self {
+            prev_local_exit_root: format!("0x{}", hex::encode(v.prev_local_exit_root)),
+            prev_pessimistic_root: format!("0x{}", hex::encode(v.prev_pessimistic_root)),
+            l1_info_root: format!("0x{}", hex::encode(v.l1_info_root)),
+            origin_network: v.origin_network,
+            consensus_hash: format!("0x{}", hex::encode(v.consensus_hash)),
+            new_local_exit_root: format!("0x{}", hex::encode(v.new_local_exit_root)),
+            new_pessimistic_root: format!("0x{}", hex::encode(v.new_pessimistic_root)),
+        }
+    }
+}
+
+/// A fixture that can be used to test the verification of SP1 zkVM proofs
+/// inside Solidity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PessimisticProofFixture {
+    pub certificate: Certificate,
+    pub pp_inputs: VerifierInputs,
+    pub signer: Address,
+    pub vkey: String,
+    pub public_values: String,
+    pub proof: String,
+}
@@ -1,13 +1,14 @@
 use std::collections::{BTreeMap, BTreeSet};
 
+use bincode::Options as _;
 use pessimistic_proof::global_index::GlobalIndex;
 use pessimistic_proof::local_balance_tree::{LocalBalanceTree, LOCAL_BALANCE_TREE_DEPTH};
 pub use pessimistic_proof::local_exit_tree::hasher::Keccak256Hasher;
-use pessimistic_proof::local_exit_tree::{LocalExitTree, LocalExitTreeError};
+use pessimistic_proof::local_exit_tree::{data::LETMerkleProof, LocalExitTree, LocalExitTreeError};
 use pessimistic_proof::local_state::StateCommitment;
 use pessimistic_proof::multi_batch_header::signature_commitment;
 use pessimistic_proof::nullifier_tree::{FromBool, NullifierTree, NULLIFIER_TREE_DEPTH};
-use pessimistic_proof::utils::smt::{Smt, SmtError};
+use pessimistic_proof::utils::smt::{Smt, SmtError, SmtMerkleProof};
 use pessimistic_proof::LocalNetworkState;
 use pessimistic_proof::{
     bridge_exit::{BridgeExit, TokenInfo},
@@ -16,7 +17,7 @@ use pessimistic_proof::{
     local_balance_tree::LocalBalancePath,
     multi_batch_header::MultiBatchHeader,
     nullifier_tree::{NullifierKey, NullifierPath},
-    ProofError,
+    PessimisticProofOutput, ProofError,
 };
 pub use reth_primitives::address;
 use reth_primitives::B256;
@@ -30,7 +31,9 @@ pub type CertificateId = Hash;
 pub type Height = u64;
 pub type Metadata = Hash;
 
+mod fixture;
 mod hash;
+pub use fixture::{PessimisticProofFixture, VerifierInputs};
 pub use hash::Hash;
 pub use pessimistic_proof::bridge_exit::NetworkId;
 use sp1_sdk::SP1VerificationError;
@@ -54,6 +57,9 @@ pub struct CertificateHeader {
     pub new_local_exit_root: Hash,
     pub metadata: Metadata,
     pub status: CertificateStatus,
+    /// Hash of the L1 transaction settling the certificate, the latest one sent
+    /// until it is settled.
+    pub settlement_tx_hash: Option<Hash>,
 }
 
 #[derive(Debug, thiserror::Error, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -131,6 +137,14 @@ pub enum CertificateStatusError {
     SettlementError(String),
     #[error("L1 Info root not found for l1 leaf count: {0}")]
     L1InfoRootNotFound(u32),
+    /// The certificate was in error and has been replaced by another
+    /// certificate submitted for the same network and height.
+    #[error("Superseded by certificate {0}")]
+    Superseded(CertificateId),
+    /// The certificate stayed pending for the given number of epochs without
+    /// being certified, and has been removed from the pending queue.
+    #[error("Expired after {0} epochs pending")]
+    Expired(u64),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, thiserror::Error, PartialEq, Eq)]
@@ -222,6 +236,56 @@ impl Proof {
             sp1_version: String::new(),
         })
     }
+
+    /// Create a test proof committing to the given public values.
+    pub fn new_for_test_with_public_values(output: &PessimisticProofOutput) -> Self {
+        let mut public_values = SP1PublicValues::new();
+        public_values.write_slice(
+            &PessimisticProofOutput::bincode_options()
+                .serialize(output)
+                .expect("Unable to serialize the pessimistic proof output"),
+        );
+
+        Proof::SP1(sp1_sdk::SP1ProofWithPublicValues {
+            proof: sp1_sdk::SP1Proof::Core(Vec::new()),
+            stdin: sp1_sdk::SP1Stdin::new(),
+            public_values,
+            sp1_version: String::new(),
+        })
+    }
+
+    /// Decode the [`PessimisticProofOutput`] committed in the public values.
+    pub fn pessimistic_proof_output(&self) -> Result<PessimisticProofOutput, bincode::Error> {
+        let Proof::SP1(proof) = self;
+
+        PessimisticProofOutput::bincode_options().deserialize(proof.public_values.as_slice())
+    }
+
+    /// The raw public values of the proof.
+    pub fn public_values(&self) -> &[u8] {
+        let Proof::SP1(proof) = self;
+
+        proof.public_values.as_slice()
+    }
+
+    /// The proof bytes as expected by the L1 verifier, or `None` if the proof
+    /// can't be verified onchain.
+    pub fn onchain_bytes(&self) -> Option<Vec<u8>> {
+        let Proof::SP1(proof) = self;
+
+        matches!(
+            proof.proof,
+            sp1_sdk::SP1Proof::Plonk(_) | sp1_sdk::SP1Proof::Groth16(_)
+        )
+        .then(|| proof.bytes())
+    }
+
+    /// The SP1 version used to generate the proof.
+    pub fn sp1_version(&self) -> &str {
+        let Proof::SP1(proof) = self;
+
+        &proof.sp1_version
+    }
 }
 
 /// Represents the data submitted by the chains to the AggLayer.
@@ -264,8 +328,12 @@ impl Certificate {
     const DEFAULT_L1_INFO_LEAF_INDEX: u32 = 1;
 }
 
+#[cfg(any(test, feature = "testutils"))]
 impl Certificate {
-    #[cfg(any(test, feature = "testutils"))]
+    /// Private key of the wallet signing the test certificates.
+    const TEST_SIGNER_KEY: [u8; 32] = [1; 32];
+
+    /// Builds a test certificate signed by [`Certificate::test_signer`].
     pub fn new_for_test(network_id: NetworkId, height: Height) -> Self {
         Certificate {
             network_id,
@@ -277,8 +345,43 @@ impl Certificate {
             signature: Signature::default(),
             metadata: Default::default(),
         }
+        .with_test_signature()
     }
 
+    /// Address of the wallet signing the test certificates.
+    pub fn test_signer() -> Address {
+        use ethers_signers::Signer as _;
+
+        Address::new(Self::test_wallet().address().0)
+    }
+
+    /// Signs the certificate with the test wallet.
+    pub fn with_test_signature(mut self) -> Self {
+        let combined_hash =
+            signature_commitment(self.new_local_exit_root, &self.imported_bridge_exits);
+        let signature = Self::test_wallet()
+            .sign_hash(combined_hash.into())
+            .expect("Unable to sign the test certificate");
+
+        self.signature = Signature {
+            r: U256::from_limbs(signature.r.0),
+            s: U256::from_limbs(signature.s.0),
+            odd_y_parity: signature
+                .recovery_id()
+                .expect("Invalid test signature")
+                .is_y_odd(),
+        };
+
+        self
+    }
+
+    fn test_wallet() -> ethers_signers::LocalWallet {
+        ethers_signers::LocalWallet::from_bytes(&Self::TEST_SIGNER_KEY)
+            .expect("Invalid test signer key")
+    }
+}
+
+impl Certificate {
     pub fn hash(&self) -> CertificateId {
         let commit_bridge_exits =
             keccak256_combine(self.bridge_exits.iter().map(|exit| exit.hash()));
@@ -341,6 +444,38 @@ impl Certificate {
     }
 }
 
+/// Inclusion proof of a leaf of the local exit tree of a network.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LocalExitTreeProof {
+    /// The index of the leaf in the local exit tree.
+    pub leaf_index: u32,
+    /// The leaf, i.e. the hash of the bridge exit.
+    pub leaf: Hash,
+    /// The number of leaves of the tree the proof is computed against.
+    pub leaf_count: u32,
+    /// The root of the tree with `leaf_count` leaves.
+    pub root: Hash,
+    /// The Merkle proof of the leaf against `root`.
+    pub proof: LETMerkleProof<Keccak256Hasher>,
+}
+
+/// Proof of the presence or absence of a [`GlobalIndex`] in the nullifier
+/// tree.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum NullifierProof {
+    /// The global index is claimed, along with its inclusion proof.
+    Inclusion(SmtMerkleProof<Keccak256Hasher, NULLIFIER_TREE_DEPTH>),
+    /// The global index is not claimed, along with its non-inclusion proof.
+    NonInclusion(NullifierPath<Keccak256Hasher>),
+}
+
+impl NullifierProof {
+    /// Returns whether the global index is claimed.
+    pub fn is_claimed(&self) -> bool {
+        matches!(self, Self::Inclusion(_))
+    }
+}
+
 /// Local state data of one network.
 /// The AggLayer tracks the [`LocalNetworkStateData`] for all networks.
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
@@ -528,6 +663,51 @@ impl LocalNetworkStateData {
             .apply_certificate(certificate, signer, l1_info_root)
     }
 
+    /// Returns the balance of the given token along with its inclusion proof
+    /// against the current balance root.
+    ///
+    /// Proving a zero balance inserts the nodes leading to the empty leaf,
+    /// which leaves the balance root unchanged.
+    pub fn get_balance_proof(
+        &mut self,
+        token: TokenInfo,
+    ) -> Result<(U256, LocalBalancePath<Keccak256Hasher>), Error> {
+        let balance_proof_error = |source| Error::BalanceProofGenerationFailed { source, token };
+        let balance = U256::from_be_bytes(self.balance_tree.get(token).unwrap_or_default());
+
+        let path = if balance.is_zero() {
+            self.balance_tree
+                .get_inclusion_proof_zero(token)
+                .map_err(balance_proof_error)?
+        } else {
+            self.balance_tree
+                .get_inclusion_proof(token)
+                .map_err(balance_proof_error)?
+        };
+
+        Ok((balance, path))
+    }
+
+    /// Returns the [`NullifierProof`] of the given [`GlobalIndex`] against the
+    /// current nullifier root.
+    pub fn get_nullifier_proof(&self, global_index: GlobalIndex) -> Result<NullifierProof, Error> {
+        let nullifier_key: NullifierKey = global_index.into();
+        let nullifier_error = |source| Error::NullifierPathGenerationFailed {
+            source,
+            global_index,
+        };
+
+        match self.nullifier_tree.get_non_inclusion_proof(nullifier_key) {
+            Ok(path) => Ok(NullifierProof::NonInclusion(path)),
+            Err(SmtError::KeyPresent) => self
+                .nullifier_tree
+                .get_inclusion_proof(nullifier_key)
+                .map(NullifierProof::Inclusion)
+                .map_err(nullifier_error),
+            Err(source) => Err(nullifier_error(source)),
+        }
+    }
+
     pub fn get_roots(&self) -> StateCommitment {
         StateCommitment {
             exit_root: self.exit_tree.get_root(),
@@ -41,6 +41,9 @@ pub(crate) enum Commands {
 
     ProverConfig,
 
+    /// Print the OpenRPC document describing the RPC API.
+    Openrpc,
+
     Prover {
         /// The path to the configuration file.
         #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "agglayer-prover.toml", env = "PROVER_CONFIG_PATH")]
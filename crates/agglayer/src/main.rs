@@ -15,6 +15,10 @@ fn main() -> anyhow::Result<()> {
             "{}",
             toml::to_string_pretty(&agglayer_config::prover::ProverConfig::default()).unwrap()
         ),
+        cli::Commands::Openrpc => println!(
+            "{}",
+            serde_json::to_string_pretty(&agglayer_node::openrpc_document()).unwrap()
+        ),
         cli::Commands::Config { base_dir } => println!(
             "{}",
             toml::to_string_pretty(&agglayer_config::Config::new(&base_dir)).unwrap()
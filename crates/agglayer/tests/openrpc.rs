@@ -0,0 +1,20 @@
+use assert_cmd::Command;
+
+#[test]
+fn openrpc_display() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("agglayer")?;
+    cmd.args(["openrpc"]);
+
+    let output = cmd.assert().success();
+
+    let document: serde_json::Value = serde_json::from_slice(&output.get_output().stdout)?;
+
+    assert_eq!(document, agglayer_node::openrpc_document());
+    assert!(document["methods"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|method| method["name"] == "interop_sendCertificate"));
+
+    Ok(())
+}
@@ -1,17 +1,12 @@
 use std::{path::PathBuf, time::Instant};
 
-use agglayer_types::{Certificate, U256};
+use agglayer_types::{PessimisticProofFixture, U256};
 use clap::Parser;
-use pessimistic_proof::{
-    bridge_exit::{NetworkId, TokenInfo},
-    PessimisticProofOutput,
-};
+use pessimistic_proof::bridge_exit::TokenInfo;
 use pessimistic_proof_test_suite::{
     runner::Runner,
     sample_data::{self as data},
 };
-use reth_primitives::Address;
-use serde::{Deserialize, Serialize};
 use sp1_sdk::HashableKey;
 use tracing::{info, warn};
 use uuid::Uuid;
@@ -125,51 +120,3 @@ pub fn main() {
         info!("Proof: {:?}", fixture);
     }
 }
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub struct VerifierInputs {
-    /// The previous local exit root.
-    pub prev_local_exit_root: String,
-    /// The previous pessimistic root.
-    pub prev_pessimistic_root: String,
-    /// The l1 info root against which we prove the inclusion of the
-    /// imported bridge exits.
-    pub l1_info_root: String,
-    /// The origin network of the pessimistic proof.
-    pub origin_network: NetworkId,
-    /// The consensus hash.
-    pub consensus_hash: String,
-    /// The new local exit root.
-    pub new_local_exit_root: String,
-    /// The new pessimistic root which commits to the balance and nullifier
-    /// tree.
-    pub new_pessimistic_root: String,
-}
-
-impl From<PessimisticProofOutput> for VerifierInputs {
-    fn from(v: PessimisticProofOutput) -> Self {
-        Self {
-            prev_local_exit_root: format!("0x{}", hex::encode(v.prev_local_exit_root)),
-            prev_pessimistic_root: format!("0x{}", hex::encode(v.prev_pessimistic_root)),
-            l1_info_root: format!("0x{}", hex::encode(v.l1_info_root)),
-            origin_network: v.origin_network,
-            consensus_hash: format!("0x{}", hex::encode(v.consensus_hash)),
-            new_local_exit_root: format!("0x{}", hex::encode(v.new_local_exit_root)),
-            new_pessimistic_root: format!("0x{}", hex::encode(v.new_pessimistic_root)),
-        }
-    }
-}
-
-/// A fixture that can be used to test the verification of SP1 zkVM proofs
-/// inside Solidity.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-struct PessimisticProofFixture {
-    certificate: Certificate,
-    pp_inputs: VerifierInputs,
-    signer: Address,
-    vkey: String,
-    public_values: String,
-    proof: String,
-}
@@ -148,6 +148,79 @@ where
 
         Ok(LETMerkleProof { siblings })
     }
+
+    /// Returns the root of the tree made of the first `leaf_count` leaves.
+    pub fn get_root_at(&self, leaf_count: u32) -> Result<H::Digest, LocalExitTreeError> {
+        let leaf_count = self.check_leaf_count(leaf_count)?;
+
+        Ok(H::merge(
+            &self.get_at(TREE_DEPTH - 1, 0, leaf_count)?,
+            &self.get_at(TREE_DEPTH - 1, 1, leaf_count)?,
+        ))
+    }
+
+    /// Returns the Merkle proof of a leaf against the tree made of the first
+    /// `leaf_count` leaves.
+    pub fn get_proof_at(
+        &self,
+        leaf_index: u32,
+        leaf_count: u32,
+    ) -> Result<LETMerkleProof<H, TREE_DEPTH>, LocalExitTreeError> {
+        let leaf_count = self.check_leaf_count(leaf_count)?;
+        let leaf_index: usize = leaf_index
+            .try_into()
+            .map_err(|_| LocalExitTreeError::LeafIndexOverflow)?;
+        if leaf_index >= leaf_count {
+            return Err(LocalExitTreeError::IndexOutOfBounds);
+        }
+        let mut siblings = [Default::default(); TREE_DEPTH];
+        let mut index = leaf_index;
+        for height in 0..TREE_DEPTH {
+            siblings[height] = self.get_at(height, index ^ 1, leaf_count)?;
+            index >>= 1;
+        }
+
+        Ok(LETMerkleProof { siblings })
+    }
+
+    fn check_leaf_count(&self, leaf_count: u32) -> Result<usize, LocalExitTreeError> {
+        let leaf_count: usize = leaf_count
+            .try_into()
+            .map_err(|_| LocalExitTreeError::LeafIndexOverflow)?;
+        if leaf_count > self.layers[0].len() {
+            return Err(LocalExitTreeError::IndexOutOfBounds);
+        }
+
+        Ok(leaf_count)
+    }
+
+    /// Returns the node of the tree made of the first `leaf_count` leaves.
+    ///
+    /// Only the nodes covering the last of these leaves differ from the ones
+    /// of the whole tree, so at most one node per height is hashed again.
+    fn get_at(
+        &self,
+        height: usize,
+        index: usize,
+        leaf_count: usize,
+    ) -> Result<H::Digest, LocalExitTreeError> {
+        if index >= 1 << (TREE_DEPTH - height) {
+            return Err(LocalExitTreeError::IndexOutOfBounds);
+        }
+        let first_leaf = (index as u64) << height;
+        let end_leaf = (index as u64 + 1) << height;
+
+        if end_leaf <= leaf_count as u64 {
+            self.get(height, index)
+        } else if first_leaf >= leaf_count as u64 {
+            Ok(self.empty_hash_at_height[height])
+        } else {
+            Ok(H::merge(
+                &self.get_at(height - 1, 2 * index, leaf_count)?,
+                &self.get_at(height - 1, 2 * index + 1, leaf_count)?,
+            ))
+        }
+    }
 }
 
 impl<H, const TREE_DEPTH: usize> LETMerkleProof<H, TREE_DEPTH>
@@ -242,4 +315,35 @@ mod tests {
         let proof = local_exit_tree_data.get_proof(leaf_index as u32).unwrap();
         assert!(proof.verify(leaf, leaf_index as u32, root));
     }
+
+    #[test]
+    fn test_merkle_proofs_at_leaf_count() {
+        let num_leaves = thread_rng().gen_range(1..=100.min(1 << TREE_DEPTH));
+        let leaves = (0..num_leaves).map(|_| random()).collect::<Vec<_>>();
+        let leaf_count = thread_rng().gen_range(1..=num_leaves);
+        let leaf_index = thread_rng().gen_range(0..leaf_count);
+        let local_exit_tree_data: LocalExitTreeData<H, TREE_DEPTH> =
+            LocalExitTreeData::from_leaves(leaves.iter().copied()).unwrap();
+        let prefix_tree: LocalExitTreeData<H, TREE_DEPTH> =
+            LocalExitTreeData::from_leaves(leaves[..leaf_count].iter().copied()).unwrap();
+
+        let root = local_exit_tree_data.get_root_at(leaf_count as u32).unwrap();
+        assert_eq!(root, prefix_tree.get_root());
+
+        let proof = local_exit_tree_data
+            .get_proof_at(leaf_index as u32, leaf_count as u32)
+            .unwrap();
+        assert_eq!(
+            proof.siblings,
+            prefix_tree.get_proof(leaf_index as u32).unwrap().siblings
+        );
+        assert!(proof.verify(leaves[leaf_index], leaf_index as u32, root));
+
+        assert!(local_exit_tree_data
+            .get_proof_at(leaf_count as u32, leaf_count as u32)
+            .is_err());
+        assert!(local_exit_tree_data
+            .get_root_at(num_leaves as u32 + 1)
+            .is_err());
+    }
 }